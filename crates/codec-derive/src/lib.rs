@@ -1,7 +1,7 @@
 use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
 use quote::{__private::Span, format_ident, quote};
-use syn::{self, Data, Fields, Ident};
+use syn::{self, Data, DataEnum, DataStruct, Fields, Ident, Index, Member, Type};
 
 #[proc_macro]
 pub fn derive_keccak256_id(token: TokenStream) -> TokenStream {
@@ -30,6 +30,36 @@ pub fn path_to_test_name(token: TokenStream) -> TokenStream {
     })
 }
 
+/// Field of the struct or enum variant with its member (`a` or `0`) and an associated type name
+/// used in the generated `I{Name}` trait.
+struct CodecField<'a> {
+    member: Member,
+    ty: &'a Type,
+    type_name: Ident,
+}
+
+fn codec_fields(fields: &Fields) -> Vec<CodecField<'_>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => {
+                let type_name = ident.to_string().to_case(Case::Pascal);
+                CodecField {
+                    member: Member::Named(ident.clone()),
+                    ty: &field.ty,
+                    type_name: Ident::new(type_name.as_str(), ident.span()),
+                }
+            }
+            None => CodecField {
+                member: Member::Unnamed(Index::from(i)),
+                ty: &field.ty,
+                type_name: format_ident!("Field{}", i),
+            },
+        })
+        .collect()
+}
+
 fn impl_derive_codec(ast: &syn::DeriveInput) -> TokenStream {
     let crate_name = std::env::var("CARGO_PKG_NAME").unwrap();
     let crate_name = if crate_name == "fluentbase-codec" {
@@ -37,61 +67,60 @@ fn impl_derive_codec(ast: &syn::DeriveInput) -> TokenStream {
     } else {
         quote! { fluentbase_codec }
     };
-    let data_struct = match &ast.data {
-        Data::Struct(data_struct) => data_struct,
-        _ => panic!("only structs are supported"),
-    };
-    let named_fields = match &data_struct.fields {
-        Fields::Named(named_fields) => named_fields,
-        _ => panic!("only named fields are supported"),
-    };
-    let header_sizes = named_fields.named.iter().map(|field| {
-        let ty = &field.ty;
+    match &ast.data {
+        Data::Struct(data_struct) => impl_derive_codec_struct(ast, data_struct, &crate_name),
+        Data::Enum(data_enum) => impl_derive_codec_enum(ast, data_enum, &crate_name),
+        Data::Union(_) => syn::Error::new(ast.ident.span(), "unions are not supported")
+            .to_compile_error()
+            .into(),
+    }
+}
+
+fn impl_derive_codec_struct(
+    ast: &syn::DeriveInput,
+    data_struct: &DataStruct,
+    crate_name: &proc_macro2::TokenStream,
+) -> TokenStream {
+    let fields = codec_fields(&data_struct.fields);
+    let header_sizes = fields.iter().map(|field| {
+        let ty = field.ty;
         quote! {
             <#ty as #crate_name::Encoder<#ty>>::HEADER_SIZE
         }
     });
-    let encode_types = named_fields.named.iter().map(|field| {
-        let ident = field.ident.as_ref().unwrap();
-        let ty = &field.ty;
+    let encode_types = fields.iter().map(|field| {
+        let member = &field.member;
+        let ty = field.ty;
         quote! {
-            self.#ident.encode(encoder, field_offset);
+            self.#member.encode(encoder, field_offset);
             field_offset += <#ty as #crate_name::Encoder<#ty>>::HEADER_SIZE;
         }
     });
-    let decode_types = named_fields.named.iter().map(|field| {
-        let ident = field.ident.as_ref().unwrap();
-        let ty = &field.ty;
+    let decode_types = fields.iter().map(|field| {
+        let member = &field.member;
+        let ty = field.ty;
         quote! {
-            <#ty as #crate_name::Encoder<#ty>>::decode_body(decoder, field_offset, &mut result.#ident);
+            <#ty as #crate_name::Encoder<#ty>>::decode_body(decoder, field_offset, &mut result.#member);
             field_offset += <#ty as #crate_name::Encoder<#ty>>::HEADER_SIZE;
         }
     });
-    let impl_types = named_fields.named.iter().map(|field| {
-        let ident = field.ident.as_ref().unwrap();
-        let span = ident.span();
-        let ident = ident.to_string().to_case(Case::Pascal);
-        let ident = Ident::new(ident.as_str(), span);
+    let impl_types = fields.iter().map(|field| {
+        let type_name = &field.type_name;
         quote! {
-            type #ident;
+            type #type_name;
         }
     });
-    let field_names = named_fields.named.clone();
-    let impl_defs = named_fields.named.iter().enumerate().map(|(i, field)| {
-        let ident = field.ident.as_ref().unwrap();
-        let span = ident.span();
-        let ident = ident.to_string().to_case(Case::Pascal);
-        let ident = ident.to_string().to_case(Case::Pascal);
-        let ident = Ident::new(ident.as_str(), span);
-        let sum_of_field_offsets = field_names.iter().take(i).map(|field| {
-            let ty = &field.ty;
+    let impl_defs = fields.iter().enumerate().map(|(i, field)| {
+        let type_name = &field.type_name;
+        let sum_of_field_offsets = fields.iter().take(i).map(|field| {
+            let ty = field.ty;
             quote! {
                 <#ty as #crate_name::Encoder<#ty>>::HEADER_SIZE
             }
         });
-        let ty = &field.ty;
+        let ty = field.ty;
         quote! {
-            type #ident = #crate_name::FieldEncoder<#ty, { 0 #( +#sum_of_field_offsets )* }>;
+            type #type_name = #crate_name::FieldEncoder<#ty, { 0 #( +#sum_of_field_offsets )* }>;
         }
     });
     let struct_name = &ast.ident;
@@ -118,6 +147,141 @@ fn impl_derive_codec(ast: &syn::DeriveInput) -> TokenStream {
     TokenStream::from(output)
 }
 
+/// Returns offsets of the variant fields, the first byte of the header is a variant index.
+fn variant_field_offsets(
+    fields: &[CodecField<'_>],
+    crate_name: &proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    (0..fields.len())
+        .map(|i| {
+            let sum_of_field_offsets = fields.iter().take(i).map(|field| {
+                let ty = field.ty;
+                quote! {
+                    <#ty as #crate_name::Encoder<#ty>>::HEADER_SIZE
+                }
+            });
+            quote! { 1 #( +#sum_of_field_offsets )* }
+        })
+        .collect()
+}
+
+/// Enums are encoded like `Option`, the header contains a one byte variant index followed by
+/// fields of the variant. The header size is the same for all variants (the largest one), so
+/// enums can be embedded into other structures without breaking random access.
+fn impl_derive_codec_enum(
+    ast: &syn::DeriveInput,
+    data_enum: &DataEnum,
+    crate_name: &proc_macro2::TokenStream,
+) -> TokenStream {
+    if data_enum.variants.len() > u8::MAX as usize + 1 {
+        return syn::Error::new(ast.ident.span(), "only 256 enum variants are supported")
+            .to_compile_error()
+            .into();
+    }
+    let variants = data_enum
+        .variants
+        .iter()
+        .map(|variant| (&variant.ident, codec_fields(&variant.fields)))
+        .collect::<Vec<_>>();
+    let variant_sizes = variants.iter().map(|(_, fields)| {
+        let header_sizes = fields.iter().map(|field| {
+            let ty = field.ty;
+            quote! {
+                <#ty as #crate_name::Encoder<#ty>>::HEADER_SIZE
+            }
+        });
+        quote! {
+            let variant_size = 0 #( + #header_sizes)*;
+            if variant_size > header_size {
+                header_size = variant_size;
+            }
+        }
+    });
+    let encode_variants = variants.iter().enumerate().map(|(i, (ident, fields))| {
+        let index = i as u8;
+        let members = fields.iter().map(|field| &field.member);
+        let values = (0..fields.len())
+            .map(|i| format_ident!("value{}", i))
+            .collect::<Vec<_>>();
+        let types = fields.iter().map(|field| field.ty);
+        let offsets = variant_field_offsets(fields, crate_name);
+        quote! {
+            Self::#ident { #( #members: #values ),* } => {
+                <u8 as #crate_name::Encoder<u8>>::encode(&#index, encoder, field_offset);
+                #(
+                    <#types as #crate_name::Encoder<#types>>::encode(#values, encoder, field_offset + #offsets);
+                )*
+            }
+        }
+    });
+    let decode_variants = variants.iter().enumerate().map(|(i, (ident, fields))| {
+        let index = i as u8;
+        let members = fields.iter().map(|field| &field.member);
+        let values = (0..fields.len())
+            .map(|i| format_ident!("value{}", i))
+            .collect::<Vec<_>>();
+        let types = fields.iter().map(|field| field.ty).collect::<Vec<_>>();
+        let offsets = variant_field_offsets(fields, crate_name);
+        quote! {
+            #index => {
+                #(
+                    let mut #values: #types = Default::default();
+                    <#types as #crate_name::Encoder<#types>>::decode_body(decoder, field_offset + #offsets, &mut #values);
+                )*
+                *result = Self::#ident { #( #members: #values ),* };
+            }
+        }
+    });
+    let mut impl_types = Vec::new();
+    let mut impl_defs = Vec::new();
+    for (ident, fields) in variants.iter() {
+        let offsets = variant_field_offsets(fields, crate_name);
+        for (field, offset) in fields.iter().zip(offsets) {
+            let type_name = format_ident!("{}{}", ident, field.type_name);
+            let ty = field.ty;
+            impl_types.push(quote! {
+                type #type_name;
+            });
+            impl_defs.push(quote! {
+                type #type_name = #crate_name::FieldEncoder<#ty, { #offset }>;
+            });
+        }
+    }
+    let enum_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+    let i_enum_name = format_ident!("I{}", ast.ident);
+    let output = quote! {
+        impl #impl_generics #crate_name::Encoder<#enum_name #type_generics> for #enum_name #type_generics #where_clause {
+            const HEADER_SIZE: usize = 1 + {
+                let mut header_size = 0;
+                #( #variant_sizes )*
+                header_size
+            };
+            fn encode<W: #crate_name::WritableBuffer>(&self, encoder: &mut W, field_offset: usize) {
+                match self {
+                    #( #encode_variants )*
+                }
+            }
+            fn decode_header(decoder: &mut #crate_name::BufferDecoder, field_offset: usize, result: &mut #enum_name #type_generics) -> (usize, usize) {
+                let mut variant_index: u8 = 0;
+                <u8 as #crate_name::Encoder<u8>>::decode_header(decoder, field_offset, &mut variant_index);
+                match variant_index {
+                    #( #decode_variants )*
+                    _ => decoder.set_error(#crate_name::CodecError::InvalidVariant),
+                }
+                (0, 0)
+            }
+        }
+        pub trait #i_enum_name {
+            #( #impl_types )*
+        }
+        impl #impl_generics #i_enum_name for #enum_name #type_generics {
+            #( #impl_defs )*
+        }
+    };
+    TokenStream::from(output)
+}
+
 #[proc_macro_derive(Codec)]
 pub fn codec_macro_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
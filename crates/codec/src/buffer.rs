@@ -1,5 +1,7 @@
+use crate::{CodecError, Encoder};
 use alloc::vec::Vec;
 use byteorder::{ByteOrder, LittleEndian};
+use core::cell::Cell;
 use paste::paste;
 
 pub trait WritableBuffer {
@@ -132,16 +134,26 @@ impl WritableBuffer for BufferEncoder {
     }
 }
 
+/// Decoder of the encoded buffer.
+///
+/// All reads are bounds-checked, malformed offsets or lengths never panic, instead the decoder
+/// returns zeroed values and remembers the first error, that can be checked using `result`
+/// (or use `try_decode` that does it for you).
 #[derive(Default)]
 pub struct BufferDecoder<'a> {
     buffer: &'a [u8],
+    header_length: usize,
+    error: Cell<Option<CodecError>>,
 }
 
 macro_rules! decode_le_int {
     ($typ:ty) => {
         paste! {
             pub fn [<read_ $typ>](&self, field_offset: usize) -> $typ {
-                LittleEndian::[<read_ $typ>](&self.buffer[field_offset..])
+                match self.read_slice(field_offset, core::mem::size_of::<$typ>()) {
+                    Some(bytes) => LittleEndian::[<read_ $typ>](bytes),
+                    None => 0,
+                }
             }
         }
     };
@@ -149,14 +161,56 @@ macro_rules! decode_le_int {
 
 impl<'a> BufferDecoder<'a> {
     pub fn new(input: &'a [u8]) -> Self {
-        Self { buffer: input }
+        Self {
+            buffer: input,
+            header_length: 0,
+            error: Cell::new(None),
+        }
+    }
+
+    /// Sets length of the header, bodies can't be located inside the header.
+    pub fn with_header_length(mut self, header_length: usize) -> Self {
+        self.header_length = header_length;
+        self
+    }
+
+    /// Remembers decoding error, only the first error is kept.
+    pub fn set_error(&self, error: CodecError) {
+        if self.error.get().is_none() {
+            self.error.set(Some(error));
+        }
+    }
+
+    /// Copies error from the decoder used for nested bodies.
+    pub fn propagate_error(&self, nested: &BufferDecoder) {
+        if let Some(error) = nested.error.get() {
+            self.set_error(error);
+        }
+    }
+
+    /// Returns the first error occurred during decoding.
+    pub fn result(&self) -> Result<(), CodecError> {
+        match self.error.get() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn read_slice(&self, offset: usize, length: usize) -> Option<&'a [u8]> {
+        let slice = offset
+            .checked_add(length)
+            .and_then(|end| self.buffer.get(offset..end));
+        if slice.is_none() {
+            self.set_error(CodecError::OutOfBounds);
+        }
+        slice
     }
 
     pub fn read_i8(&mut self, field_offset: usize) -> i8 {
-        self.buffer[field_offset] as i8
+        self.read_u8(field_offset) as i8
     }
     pub fn read_u8(&mut self, field_offset: usize) -> u8 {
-        self.buffer[field_offset]
+        self.read_slice(field_offset, 1).map(|v| v[0]).unwrap_or(0)
     }
 
     decode_le_int!(i16);
@@ -169,20 +223,50 @@ impl<'a> BufferDecoder<'a> {
     pub fn read_bytes_header(&self, field_offset: usize) -> (usize, usize) {
         let bytes_offset = self.read_u32(field_offset + 0) as usize;
         let bytes_length = self.read_u32(field_offset + 4) as usize;
+        if bytes_length > 0 && bytes_offset < self.header_length {
+            self.set_error(CodecError::OverlappingBody);
+        }
         (bytes_offset, bytes_length)
     }
 
-    pub fn read_bytes(&self, field_offset: usize) -> &[u8] {
+    pub fn read_bytes(&self, field_offset: usize) -> &'a [u8] {
         let (bytes_offset, bytes_length) = self.read_bytes_header(field_offset);
-        &self.buffer[bytes_offset..(bytes_offset + bytes_length)]
+        self.read_slice(bytes_offset, bytes_length).unwrap_or(&[])
     }
 
-    pub fn read_bytes2(&self, field1_offset: usize, field2_offset: usize) -> (&[u8], &[u8]) {
+    pub fn read_bytes2(&self, field1_offset: usize, field2_offset: usize) -> (&'a [u8], &'a [u8]) {
         (
             self.read_bytes(field1_offset),
             self.read_bytes(field2_offset),
         )
     }
+
+    /// Decodes value of type `T` from the beginning of the buffer, returns an error instead of
+    /// panic if the buffer is malformed.
+    pub fn try_decode<T: Default + Encoder<T>>(&mut self) -> Result<T, CodecError> {
+        self.header_length = self.header_length.max(T::HEADER_SIZE);
+        let mut result = T::default();
+        T::try_decode_body(self, 0, &mut result)?;
+        Ok(result)
+    }
+
+    /// Decodes value from the buffer and makes sure that the buffer is in the canonical form,
+    /// i.e. it's equal to the `encode_canonical` result of the decoded value.
+    ///
+    /// Buffers with reordered, overlapping or unused bodies, trailing bytes, unsorted map keys or
+    /// non-zero padding are rejected, so two valid buffers are equal iff decoded values are equal.
+    pub fn decode_canonical<T: Default + Encoder<T>>(&mut self) -> Result<T, CodecError> {
+        let result = self.try_decode::<T>()?;
+        if result.encode_canonical() != self.buffer {
+            return Err(CodecError::NonCanonical);
+        }
+        Ok(result)
+    }
+
+    /// Checks that the buffer is a canonical encoding of the value of type `T`.
+    pub fn is_canonical<T: Default + Encoder<T>>(&mut self) -> bool {
+        self.decode_canonical::<T>().is_ok()
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::{BufferDecoder, Encoder, WritableBuffer};
+use crate::{BufferDecoder, CodecError, Encoder, WritableBuffer};
 use fluentbase_codec_derive::Codec;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
@@ -20,7 +20,9 @@ impl Encoder<EmptyVec> for EmptyVec {
         _result: &mut EmptyVec,
     ) -> (usize, usize) {
         let count = decoder.read_u32(field_offset);
-        debug_assert_eq!(count, 0);
+        if count != 0 {
+            decoder.set_error(CodecError::LengthOverflow);
+        }
         decoder.read_bytes_header(field_offset + 4)
     }
 }
@@ -1,4 +1,7 @@
-use crate::buffer::{BufferDecoder, BufferEncoder, FixedEncoder, WritableBuffer};
+use crate::{
+    buffer::{BufferDecoder, BufferEncoder, FixedEncoder, WritableBuffer},
+    CodecError,
+};
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
@@ -20,6 +23,13 @@ pub trait Encoder<T: Sized> {
         buffer_encoder.finalize()
     }
 
+    /// Encodes value in the canonical form. Bodies of non-primitive fields are placed right after
+    /// the header in the order of fields, maps and sets are sorted by keys and all unused bytes
+    /// are zeroed, so the result is uniquely determined by the value and can be hashed or signed.
+    fn encode_canonical(&self) -> Vec<u8> {
+        self.encode_to_vec(0)
+    }
+
     fn encode<W: WritableBuffer>(&self, encoder: &mut W, field_offset: usize);
 
     fn decode_header(
@@ -31,6 +41,16 @@ pub trait Encoder<T: Sized> {
     fn decode_body(decoder: &mut BufferDecoder, field_offset: usize, result: &mut T) {
         Self::decode_header(decoder, field_offset, result);
     }
+
+    /// Same as `decode_body`, but returns an error if the buffer is malformed.
+    fn try_decode_body(
+        decoder: &mut BufferDecoder,
+        field_offset: usize,
+        result: &mut T,
+    ) -> Result<(), CodecError> {
+        Self::decode_body(decoder, field_offset, result);
+        decoder.result()
+    }
 }
 
 pub struct FieldEncoder<T: Sized + Encoder<T>, const FIELD_OFFSET: usize>(PhantomData<T>);
@@ -60,4 +80,19 @@ impl<T: Sized + Encoder<T>, const FIELD_OFFSET: usize> FieldEncoder<T, FIELD_OFF
         let mut buffer_decoder = BufferDecoder::new(buffer);
         T::decode_body(&mut buffer_decoder, field_offset, result)
     }
+
+    pub fn try_decode_field_body(buffer: &[u8], result: &mut T) -> Result<(), CodecError> {
+        Self::try_decode_field_body_at(buffer, Self::FIELD_OFFSET, result)
+    }
+
+    /// Decodes field body, returns an error instead of panic if the buffer is malformed.
+    pub fn try_decode_field_body_at(
+        buffer: &[u8],
+        field_offset: usize,
+        result: &mut T,
+    ) -> Result<(), CodecError> {
+        let mut buffer_decoder =
+            BufferDecoder::new(buffer).with_header_length(field_offset + T::HEADER_SIZE);
+        T::try_decode_body(&mut buffer_decoder, field_offset, result)
+    }
 }
@@ -0,0 +1,28 @@
+use core::fmt;
+
+/// Error returned by fallible decoding when the input buffer is malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodecError {
+    /// Field or body is located outside of the buffer
+    OutOfBounds,
+    /// Body overlaps with the header of the structure
+    OverlappingBody,
+    /// Number of elements doesn't fit into the body length
+    LengthOverflow,
+    /// Unknown enum variant index
+    InvalidVariant,
+    /// Buffer is valid, but it's not a canonical encoding of the value
+    NonCanonical,
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::OutOfBounds => write!(f, "out of bounds"),
+            CodecError::OverlappingBody => write!(f, "body overlaps with the header"),
+            CodecError::LengthOverflow => write!(f, "length overflow"),
+            CodecError::InvalidVariant => write!(f, "invalid enum variant"),
+            CodecError::NonCanonical => write!(f, "non-canonical encoding"),
+        }
+    }
+}
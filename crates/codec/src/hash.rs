@@ -1,4 +1,10 @@
-use crate::{buffer::WritableBuffer, BufferDecoder, BufferEncoder, Encoder};
+use crate::{
+    buffer::WritableBuffer,
+    vec::check_body_length,
+    BufferDecoder,
+    BufferEncoder,
+    Encoder,
+};
 use alloc::vec::Vec;
 use core::hash::Hash;
 use hashbrown::{HashMap, HashSet};
@@ -34,12 +40,17 @@ impl<K: Default + Sized + Encoder<K> + Eq + Hash + Ord, V: Default + Sized + Enc
         field_offset: usize,
         result: &mut HashMap<K, V>,
     ) -> (usize, usize) {
-        // read length and reserve required capacity in hashmap
+        // read length and bytes header to calculate hint
         let length = decoder.read_u32(field_offset) as usize;
-        result.reserve(length);
-        // read bytes header to calculate hint
         let (keys_offset, keys_length) = decoder.read_bytes_header(field_offset + 4);
         let (_, values_length) = decoder.read_bytes_header(field_offset + 12);
+        if !check_body_length(decoder, length, K::HEADER_SIZE, keys_length)
+            || !check_body_length(decoder, length, V::HEADER_SIZE, values_length)
+        {
+            return (0, 0);
+        }
+        // reserve required capacity in hashmap
+        result.reserve(length);
         // sum of keys and values are total body length
         (keys_offset, keys_length + values_length)
     }
@@ -48,22 +59,32 @@ impl<K: Default + Sized + Encoder<K> + Eq + Hash + Ord, V: Default + Sized + Enc
         // decode length, keys and values
         let length = decoder.read_u32(field_offset) as usize;
         let (key_bytes, value_bytes) = decoder.read_bytes2(field_offset + 4, field_offset + 12);
+        if !check_body_length(decoder, length, K::HEADER_SIZE, key_bytes.len())
+            || !check_body_length(decoder, length, V::HEADER_SIZE, value_bytes.len())
+        {
+            result.clear();
+            return;
+        }
         // decode keys
-        let mut key_decoder = BufferDecoder::new(key_bytes);
+        let mut key_decoder =
+            BufferDecoder::new(key_bytes).with_header_length(K::HEADER_SIZE * length);
         let keys = (0..length).map(|i| {
             let mut result = Default::default();
             K::decode_body(&mut key_decoder, K::HEADER_SIZE * i, &mut result);
             result
         });
         // decode values
-        let mut value_decoder = BufferDecoder::new(value_bytes);
+        let mut value_decoder =
+            BufferDecoder::new(value_bytes).with_header_length(V::HEADER_SIZE * length);
         let values = (0..length).map(|i| {
             let mut result = Default::default();
             V::decode_body(&mut value_decoder, V::HEADER_SIZE * i, &mut result);
             result
         });
         // zip into map
-        *result = keys.zip(values).collect()
+        *result = keys.zip(values).collect();
+        decoder.propagate_error(&key_decoder);
+        decoder.propagate_error(&value_decoder);
     }
 }
 
@@ -90,11 +111,14 @@ impl<T: Default + Sized + Encoder<T> + Eq + Hash + Ord> Encoder<HashSet<T>> for
         field_offset: usize,
         result: &mut HashSet<T>,
     ) -> (usize, usize) {
-        // read set size and reserve required memory
+        // read set size and bytes header
         let length = decoder.read_u32(field_offset) as usize;
-        result.reserve(length);
-        // read bytes header
         let (value_offset, value_length) = decoder.read_bytes_header(field_offset + 4);
+        if !check_body_length(decoder, length, T::HEADER_SIZE, value_length) {
+            return (0, 0);
+        }
+        // reserve required memory
+        result.reserve(length);
         (value_offset, value_length)
     }
 
@@ -102,14 +126,20 @@ impl<T: Default + Sized + Encoder<T> + Eq + Hash + Ord> Encoder<HashSet<T>> for
         // decode length, keys and values
         let length = decoder.read_u32(field_offset) as usize;
         let value_bytes = decoder.read_bytes(field_offset + 4);
+        if !check_body_length(decoder, length, T::HEADER_SIZE, value_bytes.len()) {
+            result.clear();
+            return;
+        }
         // decode values
-        let mut value_decoder = BufferDecoder::new(value_bytes);
+        let mut value_decoder =
+            BufferDecoder::new(value_bytes).with_header_length(T::HEADER_SIZE * length);
         let values = (0..length).map(|i| {
             let mut result = Default::default();
             T::decode_body(&mut value_decoder, T::HEADER_SIZE * i, &mut result);
             result
         });
         // zip into map
-        *result = values.collect()
+        *result = values.collect();
+        decoder.propagate_error(&value_decoder);
     }
 }
@@ -7,11 +7,13 @@ pub use crate::{
     buffer::{BufferDecoder, BufferEncoder, WritableBuffer},
     empty::EmptyVec,
     encoder::{Encoder, FieldEncoder},
+    error::CodecError,
 };
 
 mod buffer;
 mod empty;
 mod encoder;
+mod error;
 mod evm;
 mod hash;
 mod macros;
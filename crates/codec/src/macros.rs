@@ -137,4 +137,71 @@ mod tests {
         ComplicatedType::decode_body(&mut buffer_decoder, 0, &mut value1);
         assert_eq!(value0, value1);
     }
+
+    #[derive(Default, Debug, Codec, PartialEq)]
+    pub struct NewType(u64);
+
+    #[derive(Default, Debug, Codec, PartialEq)]
+    pub struct TupleType(u32, Vec<u16>, NewType);
+
+    #[test]
+    fn test_tuple_type() {
+        let value0 = TupleType(7, vec![1, 2, 3], NewType(u64::MAX));
+        assert_eq!(NewType::HEADER_SIZE, 8);
+        assert_eq!(TupleType::HEADER_SIZE, 4 + 12 + 8);
+        assert_eq!(<TupleType as ITupleType>::Field1::FIELD_OFFSET, 4);
+        assert_eq!(<TupleType as ITupleType>::Field2::FIELD_OFFSET, 4 + 12);
+        let encoded_value = value0.encode_to_vec(0);
+        let mut buffer_decoder = BufferDecoder::new(encoded_value.as_slice());
+        let mut value1 = Default::default();
+        TupleType::decode_body(&mut buffer_decoder, 0, &mut value1);
+        assert_eq!(value0, value1);
+    }
+
+    #[derive(Default, Debug, Codec, PartialEq)]
+    pub enum ResultType {
+        #[default]
+        Empty,
+        Ok(Vec<u8>),
+        Err {
+            code: i32,
+            reason: Vec<u8>,
+        },
+    }
+
+    #[derive(Default, Debug, Codec, PartialEq)]
+    pub struct EnumContainer {
+        a: u16,
+        result: ResultType,
+        b: u32,
+    }
+
+    #[test]
+    fn test_enum_type() {
+        assert_eq!(ResultType::HEADER_SIZE, 1 + 4 + 12);
+        assert_eq!(<ResultType as IResultType>::OkField0::FIELD_OFFSET, 1);
+        assert_eq!(<ResultType as IResultType>::ErrCode::FIELD_OFFSET, 1);
+        assert_eq!(<ResultType as IResultType>::ErrReason::FIELD_OFFSET, 1 + 4);
+        for value0 in [
+            ResultType::Empty,
+            ResultType::Ok(vec![1, 2, 3]),
+            ResultType::Err {
+                code: -1,
+                reason: "error".as_bytes().to_vec(),
+            },
+        ] {
+            let container0 = EnumContainer {
+                a: 100,
+                result: value0,
+                b: 20,
+            };
+            let encoded_value = container0.encode_to_vec(0);
+            // header has the same size for all variants
+            assert_eq!(<EnumContainer as IEnumContainer>::B::FIELD_OFFSET, 2 + 17);
+            let mut buffer_decoder = BufferDecoder::new(encoded_value.as_slice());
+            let mut container1 = Default::default();
+            EnumContainer::decode_body(&mut buffer_decoder, 0, &mut container1);
+            assert_eq!(container0, container1);
+        }
+    }
 }
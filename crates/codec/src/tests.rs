@@ -1,4 +1,4 @@
-use super::{BufferDecoder, BufferEncoder, Encoder};
+use super::{BufferDecoder, BufferEncoder, CodecError, Encoder};
 use alloy_primitives::Bytes;
 use hashbrown::{HashMap, HashSet};
 
@@ -275,3 +275,91 @@ fn test_complex_tuple() {
     Tuple::decode_body(&mut decoder, 0, &mut result);
     assert_eq!(result, original_data)
 }
+
+#[test]
+fn test_canonical_encoding() {
+    type Tuple = (Vec<u32>, HashMap<u32, Bytes>);
+    let value: Tuple = (
+        vec![1, 2, 3],
+        HashMap::from([
+            (7, Bytes::from_static("Hello".as_bytes())),
+            (3, Bytes::from_static("World".as_bytes())),
+        ]),
+    );
+    let canonical = value.encode_canonical();
+    assert_eq!(canonical, value.clone().encode_canonical());
+    let mut decoder = BufferDecoder::new(&canonical);
+    assert_eq!(decoder.decode_canonical::<Tuple>(), Ok(value.clone()));
+    // trailing bytes are not allowed
+    let mut buffer = canonical.clone();
+    buffer.push(0);
+    assert!(!BufferDecoder::new(&buffer).is_canonical::<Tuple>());
+    // body of the second field is encoded before the first one
+    let buffer = {
+        let mut buffer_encoder = BufferEncoder::new(Tuple::HEADER_SIZE, None);
+        value.1.encode(&mut buffer_encoder, Vec::<u32>::HEADER_SIZE);
+        value.0.encode(&mut buffer_encoder, 0);
+        buffer_encoder.finalize()
+    };
+    let mut decoded_value: Tuple = Default::default();
+    Tuple::decode_body(&mut BufferDecoder::new(&buffer), 0, &mut decoded_value);
+    assert_eq!(decoded_value, value);
+    assert!(!BufferDecoder::new(&buffer).is_canonical::<Tuple>());
+}
+
+#[test]
+fn test_non_canonical_primitives() {
+    assert!(BufferDecoder::new(&[1]).is_canonical::<bool>());
+    assert!(!BufferDecoder::new(&[2]).is_canonical::<bool>());
+    // none value must have zero payload
+    assert!(BufferDecoder::new(&[0, 0, 0, 0, 0]).is_canonical::<Option<u32>>());
+    assert!(!BufferDecoder::new(&[0, 1, 0, 0, 0]).is_canonical::<Option<u32>>());
+    // buffer is too short
+    assert!(!BufferDecoder::new(&[0, 0]).is_canonical::<u32>());
+}
+
+#[test]
+fn test_try_decode() {
+    let values = vec![Bytes::from_static("Hello".as_bytes()), Bytes::new()];
+    let encoded = values.encode_to_vec(0);
+    let mut decoder = BufferDecoder::new(&encoded);
+    assert_eq!(decoder.try_decode::<Vec<Bytes>>(), Ok(values));
+    // truncated buffer
+    let mut decoder = BufferDecoder::new(&encoded[..encoded.len() - 1]);
+    assert_eq!(
+        decoder.try_decode::<Vec<Bytes>>(),
+        Err(CodecError::OutOfBounds)
+    );
+    // header is out of bounds
+    let mut decoder = BufferDecoder::new(&encoded[..6]);
+    assert_eq!(
+        decoder.try_decode::<Vec<Bytes>>(),
+        Err(CodecError::OutOfBounds)
+    );
+}
+
+#[test]
+fn test_try_decode_malformed_header() {
+    // vector of 2^32-1 elements with 4 bytes body
+    let mut encoded = vec![0xff, 0xff, 0xff, 0xff, 12, 0, 0, 0, 4, 0, 0, 0];
+    encoded.extend([1, 2, 3, 4]);
+    let mut decoder = BufferDecoder::new(&encoded);
+    assert_eq!(
+        decoder.try_decode::<Vec<u32>>(),
+        Err(CodecError::LengthOverflow)
+    );
+    // body of the vector overlaps with the header
+    let encoded = [1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0];
+    let mut decoder = BufferDecoder::new(&encoded);
+    assert_eq!(
+        decoder.try_decode::<Vec<u32>>(),
+        Err(CodecError::OverlappingBody)
+    );
+    // body offset overflows
+    let encoded = [1, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 4, 0, 0, 0];
+    let mut decoder = BufferDecoder::new(&encoded);
+    assert_eq!(
+        decoder.try_decode::<Vec<u32>>(),
+        Err(CodecError::OutOfBounds)
+    );
+}
@@ -1,4 +1,4 @@
-use crate::{buffer::WritableBuffer, BufferDecoder, BufferEncoder, Encoder};
+use crate::{buffer::WritableBuffer, BufferDecoder, BufferEncoder, CodecError, Encoder};
 use alloc::vec::Vec;
 
 ///
@@ -31,10 +31,13 @@ impl<T: Default + Sized + Encoder<T>> Encoder<Vec<T>> for Vec<T> {
         result: &mut Vec<T>,
     ) -> (usize, usize) {
         let count = decoder.read_u32(field_offset) as usize;
+        let (offset, length) = decoder.read_bytes_header(field_offset + 4);
+        if !check_body_length(decoder, count, T::HEADER_SIZE, length) {
+            return (0, 0);
+        }
         if count > result.capacity() {
             result.reserve(count - result.capacity());
         }
-        let (offset, length) = decoder.read_bytes_header(field_offset + 4);
         (offset, length)
     }
 
@@ -45,13 +48,36 @@ impl<T: Default + Sized + Encoder<T>> Encoder<Vec<T>> for Vec<T> {
             return;
         }
         let input_bytes = decoder.read_bytes(field_offset + 4);
-        let mut value_decoder = BufferDecoder::new(input_bytes);
+        if !check_body_length(decoder, input_len, T::HEADER_SIZE, input_bytes.len()) {
+            result.clear();
+            return;
+        }
+        let mut value_decoder =
+            BufferDecoder::new(input_bytes).with_header_length(T::HEADER_SIZE * input_len);
         *result = (0..input_len)
             .map(|i| {
                 let mut result = T::default();
                 T::decode_body(&mut value_decoder, T::HEADER_SIZE * i, &mut result);
                 result
             })
-            .collect()
+            .collect();
+        decoder.propagate_error(&value_decoder);
+    }
+}
+
+/// Checks that `count` elements with the header of `header_size` bytes fit into the body of
+/// `length` bytes, so we never allocate memory for elements that can't be decoded.
+pub(crate) fn check_body_length(
+    decoder: &BufferDecoder,
+    count: usize,
+    header_size: usize,
+    length: usize,
+) -> bool {
+    match count.checked_mul(header_size) {
+        Some(size) if size <= length => true,
+        _ => {
+            decoder.set_error(CodecError::LengthOverflow);
+            false
+        }
     }
 }
@@ -3,13 +3,23 @@ use fluentbase_types::{address, Address};
 pub const ECL_CONTRACT_ADDRESS: Address = address!("5200000000000000000000000000000000000001");
 pub const WCL_CONTRACT_ADDRESS: Address = address!("5200000000000000000000000000000000000002");
 
+/// Base cost of the WASM contract deployment charged on top of the EVM `CREATE` cost, it covers
+/// module validation and state router setup
+pub const WASM_CREATE_BASE_GAS: u64 = 10_000;
+/// Cost of the WASM to rWASM translation for each byte of the WASM binary
+pub const WASM_COMPILATION_GAS_PER_BYTE: u64 = 3;
+
 // precompiles
-pub const PRECOMPILE_BLAKE2_ADDRESS: Address = address!("0000000000000000000000000000000000000001");
-pub const PRECOMPILE_BN128_ADDRESS: Address = address!("0000000000000000000000000000000000000002");
-pub const PRECOMPILE_IDENTITY_ADDRESS: Address =
-    address!("0000000000000000000000000000000000000003");
-pub const PRECOMPILE_KZG_POINT_EVALUATION_ADDRESS: Address =
-    address!("0000000000000000000000000000000000000004");
-pub const PRECOMPILE_MODEXP_ADDRESS: Address = address!("0000000000000000000000000000000000000005");
-pub const PRECOMPILE_SECP256K1_ADDRESS: Address =
-    address!("0000000000000000000000000000000000000006");
+pub use fluentbase_types::{
+    PRECOMPILE_ADDRESSES,
+    PRECOMPILE_BLAKE2_ADDRESS,
+    PRECOMPILE_BN128_ADD_ADDRESS,
+    PRECOMPILE_BN128_MUL_ADDRESS,
+    PRECOMPILE_BN128_PAIR_ADDRESS,
+    PRECOMPILE_IDENTITY_ADDRESS,
+    PRECOMPILE_KZG_POINT_EVALUATION_ADDRESS,
+    PRECOMPILE_MODEXP_ADDRESS,
+    PRECOMPILE_RIPEMD160_ADDRESS,
+    PRECOMPILE_SECP256K1_ADDRESS,
+    PRECOMPILE_SHA256_ADDRESS,
+};
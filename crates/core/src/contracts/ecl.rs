@@ -26,12 +26,24 @@ pub fn main() {
     let method_id = input_helper.decode_method_id();
     match method_id {
         EVM_CREATE_METHOD_ID => {
-            let method_input = input_helper.decode_method_input::<EvmCreateMethodInput>();
+            let method_input = match input_helper.decode_method_input::<EvmCreateMethodInput>() {
+                Ok(method_input) => method_input,
+                Err(exit_code) => {
+                    LowLevelSDK::sys_halt(exit_code.into_i32());
+                    return;
+                }
+            };
             let method_output = _evm_create(&cr, &am, method_input);
             LowLevelSDK::sys_write(&method_output.encode_to_vec(0));
         }
         EVM_CALL_METHOD_ID => {
-            let method_input = input_helper.decode_method_input::<EvmCallMethodInput>();
+            let method_input = match input_helper.decode_method_input::<EvmCallMethodInput>() {
+                Ok(method_input) => method_input,
+                Err(exit_code) => {
+                    LowLevelSDK::sys_halt(exit_code.into_i32());
+                    return;
+                }
+            };
             let method_output = _evm_call(&cr, &am, method_input);
             LowLevelSDK::sys_write(&method_output.encode_to_vec(0));
             debug_log!("ecl(main): return exit_code={}", method_output.exit_code);
@@ -4,6 +4,7 @@ use fluentbase_sdk::{
     AccountManager,
     CoreInput,
     EvmCallMethodInput,
+    EvmCallScheme,
     ExecutionContext,
     JzktAccountManager,
     LowLevelAPI,
@@ -18,13 +19,20 @@ pub fn main() {
     debug_log!("evm loader: started");
     let mut contract_input_data = ExecutionContext::contract_input_full();
     let am = JzktAccountManager::default();
-    let mut gas_limit = contract_input_data.contract_gas_limit as u32;
+    let gas_limit = contract_input_data.contract_gas_limit;
+    // ECL interprets EVM bytecode with its own gas meter, but it still needs enough fuel to run
+    let mut fuel_limit = am.fuel_conversion().gas_to_fuel(gas_limit);
     let method_data = EvmCallMethodInput {
         callee: contract_input_data.contract_address,
         value: contract_input_data.contract_value,
         input: contract_input_data.contract_input,
-        gas_limit: gas_limit as u64,
+        gas_limit,
         depth: 0,
+        scheme: if contract_input_data.contract_is_static {
+            EvmCallScheme::StaticCall
+        } else {
+            EvmCallScheme::Call
+        },
     };
     let core_input = CoreInput::new(EVM_CALL_METHOD_ID, method_data);
     contract_input_data.contract_input = core_input.encode_to_vec(0).into();
@@ -38,7 +46,7 @@ pub fn main() {
         contract_input_data_vec.len() as u32,
         core::ptr::null_mut(),
         0,
-        &mut gas_limit as *mut u32,
+        &mut fuel_limit as *mut u64,
         STATE_MAIN,
     );
     // forward output
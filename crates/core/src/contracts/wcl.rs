@@ -24,12 +24,24 @@ pub fn main() {
     let method_id = input_helper.decode_method_id();
     match method_id {
         WASM_CREATE_METHOD_ID => {
-            let method_input = input_helper.decode_method_input::<WasmCreateMethodInput>();
+            let method_input = match input_helper.decode_method_input::<WasmCreateMethodInput>() {
+                Ok(method_input) => method_input,
+                Err(exit_code) => {
+                    LowLevelSDK::sys_halt(exit_code.into_i32());
+                    return;
+                }
+            };
             let method_output = _wasm_create(&cr, &am, method_input);
             LowLevelSDK::sys_write(&method_output.encode_to_vec(0));
         }
         WASM_CALL_METHOD_ID => {
-            let method_input = input_helper.decode_method_input::<WasmCallMethodInput>();
+            let method_input = match input_helper.decode_method_input::<WasmCallMethodInput>() {
+                Ok(method_input) => method_input,
+                Err(exit_code) => {
+                    LowLevelSDK::sys_halt(exit_code.into_i32());
+                    return;
+                }
+            };
             let method_output = _wasm_call(&cr, &am, method_input);
             LowLevelSDK::sys_write(&method_output.encode_to_vec(0));
             debug_log!(
@@ -43,16 +55,55 @@ pub fn main() {
 
 #[cfg(test)]
 mod tests {
+    use crate::wasm::create::_wasm_create;
     use fluentbase_codec::Encoder;
     use fluentbase_sdk::{
         ContractInput,
         CoreInput,
+        ExecutionContext,
+        JzktAccountManager,
         LowLevelSDK,
         WasmCreateMethodInput,
         WASM_CREATE_METHOD_ID,
     };
+    use fluentbase_types::ExitCode;
     use revm_primitives::U256;
 
+    #[test]
+    fn test_create_gas_checks() {
+        let wasm_bytecode = include_bytes!("../../../../examples/bin/greeting.wasm");
+        let (cr, am) = (ExecutionContext::default(), JzktAccountManager::default());
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        // too deep call returns all gas back
+        let method_output = _wasm_create(
+            &cr,
+            &am,
+            WasmCreateMethodInput {
+                bytecode: wasm_bytecode.into(),
+                gas_limit: 3_000_000,
+                depth: 1025,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            method_output.exit_code,
+            ExitCode::CallDepthOverflow.into_i32()
+        );
+        assert_eq!(method_output.gas, 3_000_000);
+        // not enough gas to pay for the compilation
+        let method_output = _wasm_create(
+            &cr,
+            &am,
+            WasmCreateMethodInput {
+                bytecode: wasm_bytecode.into(),
+                gas_limit: 1_000,
+                ..Default::default()
+            },
+        );
+        assert_eq!(method_output.exit_code, ExitCode::OutOfFuel.into_i32());
+        assert_eq!(method_output.gas, 0);
+    }
+
     #[test]
     fn test_greeting_deploy() {
         let wasm_bytecode = include_bytes!("../../../../examples/bin/greeting.wasm");
@@ -2,8 +2,14 @@ use crate::{
     debug_log,
     helpers::{exec_evm_bytecode, exit_code_from_evm_error},
 };
+#[cfg(feature = "evm_aot")]
+use fluentbase_codec::Encoder;
+#[cfg(feature = "evm_aot")]
+use fluentbase_sdk::{Account, AccountCheckpoint, ContractInput};
 use fluentbase_sdk::{AccountManager, ContextReader, EvmCallMethodInput, EvmCallMethodOutput};
 use fluentbase_types::ExitCode;
+#[cfg(feature = "evm_aot")]
+use fluentbase_types::{Address, STATE_MAIN};
 use revm_interpreter::{
     analysis::to_analysed,
     primitives::Bytecode,
@@ -75,21 +81,27 @@ pub fn _evm_call<CR: ContextReader, AM: AccountManager>(
     }
 
     // take right bytecode depending on context params
-    let (source_hash, source_bytecode) = if input.callee != callee_account.address {
-        let (code_account, _) = am.account(input.callee);
-        (
-            code_account.source_code_hash,
-            am.preimage(&code_account.source_code_hash),
-        )
+    let code_account = if input.callee != callee_account.address {
+        am.account(input.callee).0
     } else {
-        (
-            callee_account.source_code_hash,
-            am.preimage(&callee_account.source_code_hash),
-        )
+        callee_account
     };
-    // load bytecode and convert it to analysed (we can safely unwrap here)
-    let bytecode =
-        BytecodeLocked::try_from(to_analysed(Bytecode::new_raw(source_bytecode))).unwrap();
+
+    // bytecode translated ahead-of-time is executed natively w/o interpreter
+    #[cfg(feature = "evm_aot")]
+    if code_account.rwasm_code_size > 0 {
+        let caller = caller_account.address;
+        return exec_evm_rwasm(cr, am, &code_account, input, caller, checkpoint);
+    }
+
+    // take analysed bytecode from the cache or load and analyse it (we can safely unwrap here)
+    let source_hash = code_account.source_code_hash;
+    let analysed_bytecode = am.analysed_bytecode(&source_hash).unwrap_or_else(|| {
+        let analysed_bytecode = to_analysed(Bytecode::new_raw(am.preimage(&source_hash)));
+        am.cache_analysed_bytecode(&source_hash, &analysed_bytecode);
+        analysed_bytecode
+    });
+    let bytecode = BytecodeLocked::try_from(analysed_bytecode).unwrap();
 
     // if bytecode is empty then commit result and return empty buffer
     if bytecode.is_empty() {
@@ -138,3 +150,76 @@ pub fn _evm_call<CR: ContextReader, AM: AccountManager>(
         gas_refund: result.gas.refunded(),
     }
 }
+
+#[cfg(feature = "evm_aot")]
+fn exec_evm_rwasm<CR: ContextReader, AM: AccountManager>(
+    cr: &CR,
+    am: &AM,
+    code_account: &Account,
+    input: EvmCallMethodInput,
+    caller: Address,
+    checkpoint: AccountCheckpoint,
+) -> EvmCallMethodOutput {
+    let fuel_conversion = am.fuel_conversion();
+    let fuel_limit = fuel_conversion.gas_to_fuel(input.gas_limit);
+    let mut fuel_remaining = fuel_limit;
+
+    // translated bytecode reads the same context as the interpreter does
+    let contract_input = ContractInput {
+        journal_checkpoint: cr.journal_checkpoint(),
+        contract_gas_limit: input.gas_limit,
+        contract_address: cr.contract_address(),
+        contract_caller: caller,
+        contract_input: input.input,
+        contract_value: cr.contract_value(),
+        contract_is_static: cr.contract_is_static(),
+        block_chain_id: cr.block_chain_id(),
+        block_coinbase: cr.block_coinbase(),
+        block_timestamp: cr.block_timestamp(),
+        block_number: cr.block_number(),
+        block_difficulty: cr.block_difficulty(),
+        block_gas_limit: cr.block_gas_limit(),
+        block_base_fee: cr.block_base_fee(),
+        block_blob_base_fee: cr.block_blob_base_fee(),
+        tx_gas_limit: cr.tx_gas_limit(),
+        tx_nonce: cr.tx_nonce(),
+        tx_gas_price: cr.tx_gas_price(),
+        tx_gas_priority_fee: cr.tx_gas_priority_fee(),
+        tx_caller: cr.tx_caller(),
+        tx_access_list: cr.tx_access_list(),
+        tx_blob_hashes: cr.tx_blob_hashes(),
+        tx_max_fee_per_blob_gas: cr.tx_max_fee_per_blob_gas(),
+        contract_depth: input.depth,
+    };
+    // translated bytecode records `SSTORE` refunds into the transaction counter, so the refund of
+    // the frame is the counter's difference
+    let refund_counter = am.refund_counter();
+    let (output, exit_code) = am.exec_hash(
+        code_account.rwasm_code_hash.as_ptr(),
+        &contract_input.encode_to_vec(0),
+        &mut fuel_remaining as *mut u64,
+        STATE_MAIN,
+    );
+    let gas_remaining = fuel_conversion.remaining_gas(input.gas_limit, fuel_limit - fuel_remaining);
+
+    let gas_refund = if ExitCode::from(exit_code).is_ok() {
+        am.commit();
+        am.refund_counter() - refund_counter
+    } else {
+        am.rollback(checkpoint);
+        0
+    };
+
+    debug_log!(
+        "ecl(_evm_call): native return exit_code={} gas_remaining={} gas_refund={}",
+        exit_code,
+        gas_remaining,
+        gas_refund
+    );
+    EvmCallMethodOutput {
+        output,
+        exit_code,
+        gas_remaining,
+        gas_refund,
+    }
+}
@@ -135,10 +135,16 @@ pub fn _evm_create<CR: ContextReader, AM: AccountManager>(
             .with_gas(result.gas.remaining(), result.gas.refunded());
     }
 
-    // write callee changes to database (lets keep rWASM part empty for now since universal loader
-    // is not ready yet)
+    // in AOT mode deployed bytecode is translated into rWASM, so calls can be executed natively,
+    // otherwise (or if translation fails) rWASM part is empty and calls are interpreted
+    #[cfg(feature = "evm_aot")]
+    let rwasm_bytecode = crate::helpers::evm2rwasm(&result.output);
+    #[cfg(not(feature = "evm_aot"))]
+    let rwasm_bytecode = Bytes::new();
+
+    // write callee changes to database
     let (mut contract_account, _) = am.account(contract_account.address);
-    contract_account.update_bytecode(am, &result.output, None, &Bytes::new(), None);
+    contract_account.update_bytecode(am, &result.output, None, &rwasm_bytecode, None);
 
     debug_log!(
         "ecl(_evm_create): return: Ok: callee_account.address: {}",
@@ -8,7 +8,9 @@ use fluentbase_sdk::{
     ContractInput,
     CoreInput,
     EvmCallMethodInput,
+    EvmCallScheme,
     EvmCreateMethodInput,
+    EvmStepTrace,
     ICoreInput,
     LowLevelAPI,
 };
@@ -22,9 +24,10 @@ use fluentbase_types::{
     STATE_MAIN,
 };
 use revm_interpreter::{
-    opcode::make_instruction_table,
+    opcode::{make_instruction_table, InstructionTable},
     CallInputs,
     CallOutcome,
+    CallScheme,
     Contract,
     CreateInputs,
     CreateOutcome,
@@ -77,6 +80,51 @@ pub fn wasm2rwasm(wasm_binary: &[u8]) -> Result<Vec<u8>, ExitCode> {
     Ok(rwasm_bytecode)
 }
 
+/// Translates EVM bytecode into rWASM using the translator provided by the runtime. Empty
+/// bytecode is returned if there is no translator or the bytecode can't be translated (e.g. it
+/// has unsupported opcodes), such contracts are executed by the interpreter.
+///
+/// Translated bytecode can't do nested calls, so contracts with call and create opcodes are
+/// always interpreted too.
+#[cfg(feature = "evm_aot")]
+pub(crate) fn evm2rwasm(evm_bytecode: &[u8]) -> Bytes {
+    use fluentbase_sdk::LowLevelSDK;
+    use revm_interpreter::opcode::{CALL, CALLCODE, CREATE, CREATE2, DELEGATECALL, STATICCALL};
+    const NESTED_CALL_OPCODES: [u8; 6] =
+        [CALL, CALLCODE, DELEGATECALL, STATICCALL, CREATE, CREATE2];
+    if evm_bytecode.is_empty() || has_any_opcode(evm_bytecode, &NESTED_CALL_OPCODES) {
+        return Bytes::new();
+    }
+    let rwasm_size =
+        LowLevelSDK::evm_to_rwasm_size(evm_bytecode.as_ptr(), evm_bytecode.len() as u32);
+    if rwasm_size <= 0 {
+        return Bytes::new();
+    }
+    // translated bytecode is already stored in the return data, so there is no need to translate
+    // it again with `evm_to_rwasm`
+    let mut rwasm_bytecode = vec![0u8; rwasm_size as usize];
+    LowLevelSDK::sys_read_output(rwasm_bytecode.as_mut_ptr(), 0, rwasm_size as u32);
+    rwasm_bytecode.into()
+}
+
+/// Checks whether the bytecode has any of the opcodes, data of push instructions is skipped.
+#[cfg(feature = "evm_aot")]
+fn has_any_opcode(evm_bytecode: &[u8], ops: &[u8]) -> bool {
+    use revm_interpreter::opcode::{PUSH1, PUSH32};
+    let mut i = 0;
+    while i < evm_bytecode.len() {
+        let current_op = evm_bytecode[i];
+        if ops.contains(&current_op) {
+            return true;
+        }
+        if (PUSH1..=PUSH32).contains(&current_op) {
+            i += (current_op - PUSH1 + 1) as usize;
+        }
+        i += 1;
+    }
+    false
+}
+
 #[macro_export]
 macro_rules! result_value {
     ($result:expr) => {
@@ -110,6 +158,7 @@ fn contract_input_from_call_inputs<CR: ContextReader>(
     cr: &CR,
     call_inputs: &Box<CallInputs>,
     input: Bytes,
+    depth: u32,
 ) -> ContractInput {
     ContractInput {
         journal_checkpoint: cr.journal_checkpoint(),
@@ -126,6 +175,7 @@ fn contract_input_from_call_inputs<CR: ContextReader>(
         block_difficulty: cr.block_difficulty(),
         block_gas_limit: cr.block_gas_limit(),
         block_base_fee: cr.block_base_fee(),
+        block_blob_base_fee: cr.block_blob_base_fee(),
         tx_gas_limit: cr.tx_gas_limit(),
         tx_nonce: cr.tx_nonce(),
         tx_gas_price: cr.tx_gas_price(),
@@ -134,6 +184,7 @@ fn contract_input_from_call_inputs<CR: ContextReader>(
         tx_access_list: cr.tx_access_list(),
         tx_blob_hashes: cr.tx_blob_hashes(),
         tx_max_fee_per_blob_gas: cr.tx_max_fee_per_blob_gas(),
+        contract_depth: depth,
     }
 }
 
@@ -141,6 +192,7 @@ fn contract_input_from_create_inputs<CR: ContextReader>(
     cr: &CR,
     create_inputs: &Box<CreateInputs>,
     input: Bytes,
+    depth: u32,
 ) -> ContractInput {
     ContractInput {
         journal_checkpoint: cr.journal_checkpoint(),
@@ -157,6 +209,7 @@ fn contract_input_from_create_inputs<CR: ContextReader>(
         block_difficulty: cr.block_difficulty(),
         block_gas_limit: cr.block_gas_limit(),
         block_base_fee: cr.block_base_fee(),
+        block_blob_base_fee: cr.block_blob_base_fee(),
         tx_gas_limit: cr.tx_gas_limit(),
         tx_nonce: cr.tx_nonce(),
         tx_gas_price: cr.tx_gas_price(),
@@ -165,6 +218,7 @@ fn contract_input_from_create_inputs<CR: ContextReader>(
         tx_access_list: cr.tx_access_list(),
         tx_blob_hashes: cr.tx_blob_hashes(),
         tx_max_fee_per_blob_gas: cr.tx_max_fee_per_blob_gas(),
+        contract_depth: depth,
     }
 }
 
@@ -176,7 +230,7 @@ fn exec_evm_create<CR: ContextReader, AM: AccountManager>(
     depth: u32,
 ) -> CreateOutcome {
     // calc create input
-    let contract_input = contract_input_from_create_inputs(cr, &inputs, Bytes::new());
+    let contract_input = contract_input_from_create_inputs(cr, &inputs, Bytes::new(), depth);
     let method_data = EvmCreateMethodInput {
         value: inputs.value,
         bytecode: inputs.init_code,
@@ -211,7 +265,7 @@ fn exec_evm_call<CR: ContextReader, AM: AccountManager>(
 ) -> CallOutcome {
     let return_memory_offset = inputs.return_memory_offset.clone();
 
-    let contract_input = contract_input_from_call_inputs(cr, &inputs, Bytes::new());
+    let contract_input = contract_input_from_call_inputs(cr, &inputs, Bytes::new(), depth);
     let method_data = EvmCallMethodInput {
         callee: inputs.contract,
         // here we take transfer value, because for DELEGATECALL it's not apparent
@@ -219,6 +273,12 @@ fn exec_evm_call<CR: ContextReader, AM: AccountManager>(
         input: take(&mut inputs.input),
         gas_limit: inputs.gas_limit,
         depth,
+        scheme: match inputs.context.scheme {
+            CallScheme::Call => EvmCallScheme::Call,
+            CallScheme::CallCode => EvmCallScheme::CallCode,
+            CallScheme::DelegateCall => EvmCallScheme::DelegateCall,
+            CallScheme::StaticCall => EvmCallScheme::StaticCall,
+        },
     };
     let call_output = crate::loader::_loader_call(&contract_input, am, method_data);
 
@@ -261,6 +321,45 @@ fn exec_evm_call<CR: ContextReader, AM: AccountManager>(
     }
 }
 
+#[cfg(feature = "ecl")]
+type StepTracingInstruction<'a, H> = Box<dyn Fn(&mut Interpreter, &mut H) + 'a>;
+
+/// Wraps every instruction of the table with a closure that reports the interpreter state to
+/// [AccountManager::inspect_step] once the instruction is executed.
+#[cfg(feature = "ecl")]
+fn make_step_tracing_table<'a, 'cr, 'am, CR: ContextReader, AM: AccountManager>(
+    instruction_table: &'a InstructionTable<FluentHost<'cr, 'am, CR, AM>>,
+    depth: u32,
+) -> [StepTracingInstruction<'a, FluentHost<'cr, 'am, CR, AM>>; 256] {
+    core::array::from_fn(|opcode| {
+        let instruction = instruction_table[opcode];
+        Box::new(
+            move |interpreter: &mut Interpreter, host: &mut FluentHost<'cr, 'am, CR, AM>| {
+                // instruction pointer is already advanced by the interpreter
+                let pc = interpreter.program_counter() - 1;
+                let gas_remaining = interpreter.gas.remaining();
+                let gas_refund = interpreter.gas.refunded();
+                let stack = interpreter.stack.data().clone();
+                let memory_size = interpreter.shared_memory.len();
+                instruction(interpreter, host);
+                let step = EvmStepTrace {
+                    pc,
+                    opcode: opcode as u8,
+                    gas_remaining,
+                    gas_cost: gas_remaining.saturating_sub(interpreter.gas.remaining()),
+                    gas_refund,
+                    stack,
+                    memory_size,
+                    depth,
+                };
+                host.am
+                    .expect("account manager is not set for host")
+                    .inspect_step(&step);
+            },
+        ) as StepTracingInstruction<'a, FluentHost<'cr, 'am, CR, AM>>
+    })
+}
+
 #[cfg(feature = "ecl")]
 pub(crate) fn exec_evm_bytecode<CR: ContextReader, AM: AccountManager>(
     mut cr: &CR,
@@ -285,6 +384,13 @@ pub(crate) fn exec_evm_bytecode<CR: ContextReader, AM: AccountManager>(
     let contract_address = contract.address;
 
     let instruction_table = make_instruction_table::<FluentHost<CR, AM>, CancunSpec>();
+    // wrap instructions with step hooks only if tracing is requested, it's too expensive otherwise,
+    // EIP-3155 counts depth from 1 for the top-level frame
+    let tracing_table = if am.is_step_tracing_enabled() {
+        Some(make_step_tracing_table(&instruction_table, depth + 1))
+    } else {
+        None
+    };
 
     let mut interpreter = Interpreter::new(Box::new(contract), gas_limit, is_static);
     let mut host = FluentHost::new(cr, am);
@@ -292,7 +398,10 @@ pub(crate) fn exec_evm_bytecode<CR: ContextReader, AM: AccountManager>(
 
     loop {
         // run EVM bytecode to produce next action
-        let next_action = interpreter.run(shared_memory, &instruction_table, &mut host);
+        let next_action = match tracing_table.as_ref() {
+            Some(tracing_table) => interpreter.run(shared_memory, tracing_table, &mut host),
+            None => interpreter.run(shared_memory, &instruction_table, &mut host),
+        };
 
         // take memory and cr from interpreter and host back (return later)
         shared_memory = interpreter.take_memory();
@@ -430,9 +539,13 @@ impl<CR: ContextReader> InputHelper<CR> {
         method_id
     }
 
-    pub(crate) fn decode_method_input<T: Encoder<T> + Default>(&self) -> T {
+    pub(crate) fn decode_method_input<T: Encoder<T> + Default>(&self) -> Result<T, ExitCode> {
         let mut core_input = T::default();
-        <CoreInput<T> as ICoreInput>::MethodData::decode_field_body(&self.input, &mut core_input);
-        core_input
+        <CoreInput<T> as ICoreInput>::MethodData::try_decode_field_body(
+            &self.input,
+            &mut core_input,
+        )
+        .map_err(|_| ExitCode::InputDecodeFailure)?;
+        Ok(core_input)
     }
 }
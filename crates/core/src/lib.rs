@@ -22,7 +22,9 @@ pub mod loader;
     feature = "precompile_identity",
     feature = "precompile_kzg_point_evaluation",
     feature = "precompile_modexp",
+    feature = "precompile_ripemd160",
     feature = "precompile_secp256k1",
+    feature = "precompile_sha256",
 ))]
 pub mod precompiles;
 pub mod wasm;
@@ -38,7 +40,9 @@ macro_rules! export_and_forward {
             feature = "precompile_identity",
             feature = "precompile_kzg_point_evaluation",
             feature = "precompile_modexp",
+            feature = "precompile_ripemd160",
             feature = "precompile_secp256k1",
+            feature = "precompile_sha256",
         ))]
         #[cfg(not(feature = "std"))]
         #[no_mangle]
@@ -52,12 +56,20 @@ macro_rules! export_and_forward {
             contracts::wcl::$fn_name();
             #[cfg(feature = "precompile_blake2")]
             precompiles::blake2::$fn_name();
+            #[cfg(feature = "precompile_bn128")]
+            precompiles::bn128::$fn_name();
             #[cfg(feature = "precompile_identity")]
             precompiles::identity::$fn_name();
+            #[cfg(feature = "precompile_kzg_point_evaluation")]
+            precompiles::kzg_point_evaluation::$fn_name();
             #[cfg(feature = "precompile_modexp")]
             precompiles::modexp::$fn_name();
+            #[cfg(feature = "precompile_ripemd160")]
+            precompiles::ripemd160::$fn_name();
             #[cfg(feature = "precompile_secp256k1")]
             precompiles::secp256k1::$fn_name();
+            #[cfg(feature = "precompile_sha256")]
+            precompiles::sha256::$fn_name();
         }
     };
 }
@@ -15,23 +15,49 @@ use fluentbase_types::BytecodeType;
 pub fn _loader_call<CR: ContextReader, AM: AccountManager>(
     cr: &CR,
     am: &AM,
-    input: EvmCallMethodInput,
+    mut input: EvmCallMethodInput,
 ) -> EvmCallMethodOutput {
+    let (caller, address, is_static) = (
+        cr.contract_caller(),
+        cr.contract_address(),
+        cr.contract_is_static(),
+    );
+    // give inspector a chance to modify inputs or override the whole frame
+    if let Some(output) = am.inspect_call(caller, address, is_static, &mut input) {
+        return am.inspect_call_end(caller, address, is_static, &input, output);
+    }
     let (account, _) = am.account(input.callee);
     let source_code = am.preimage(&account.source_code_hash);
-    match BytecodeType::from_slice(source_code.as_ref()) {
+    // input is kept only if it's required by the inspector
+    let inspected_input = am.is_inspecting().then(|| input.clone());
+    let output = match BytecodeType::from_slice(source_code.as_ref()) {
         BytecodeType::EVM => _evm_call(cr, am, input),
         BytecodeType::WASM => _wasm_call(cr, am, input),
+    };
+    match inspected_input {
+        Some(input) => am.inspect_call_end(caller, address, is_static, &input, output),
+        None => output,
     }
 }
 
 pub fn _loader_create<CR: ContextReader, AM: AccountManager>(
     cr: &CR,
     am: &AM,
-    input: EvmCreateMethodInput,
+    mut input: EvmCreateMethodInput,
 ) -> EvmCreateMethodOutput {
-    match BytecodeType::from_slice(input.bytecode.as_ref()) {
+    let caller = cr.contract_caller();
+    // give inspector a chance to modify inputs or override the whole frame
+    if let Some(output) = am.inspect_create(caller, &mut input) {
+        return am.inspect_create_end(caller, &input, output);
+    }
+    // input is kept only if it's required by the inspector
+    let inspected_input = am.is_inspecting().then(|| input.clone());
+    let output = match BytecodeType::from_slice(input.bytecode.as_ref()) {
         BytecodeType::EVM => _evm_create(cr, am, input),
         BytecodeType::WASM => _wasm_create(cr, am, input),
+    };
+    match inspected_input {
+        Some(input) => am.inspect_create_end(caller, &input, output),
+        None => output,
     }
 }
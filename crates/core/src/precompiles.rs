@@ -1,6 +1,24 @@
 pub mod blake2;
 pub mod bn128;
 pub mod identity;
+#[cfg(feature = "precompile_kzg_point_evaluation")]
 pub mod kzg_point_evaluation;
 pub mod modexp;
+pub mod ripemd160;
 pub mod secp256k1;
+pub mod sha256;
+
+use fluentbase_sdk::{AccountManager, JzktAccountManager, LowLevelAPI, LowLevelSDK};
+
+/// Charges the rest of the precompile's gas cost. Precompiles get their gas limit converted into
+/// fuel (see `JzktAccountManager::precompile`), so the gas cost is converted by the same ratio and
+/// the caller pays the same gas as on Ethereum unless the execution itself consumed more.
+pub(crate) fn charge_gas_cost(gas_limit: u64, gas_used: u64) {
+    let fuel_remaining = LowLevelSDK::sys_fuel(0);
+    let fuel_left = JzktAccountManager
+        .fuel_conversion()
+        .gas_to_fuel(gas_limit.saturating_sub(gas_used));
+    if fuel_remaining > fuel_left {
+        LowLevelSDK::sys_fuel(fuel_remaining - fuel_left);
+    }
+}
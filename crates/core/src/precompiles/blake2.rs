@@ -1,3 +1,4 @@
+use crate::precompiles::charge_gas_cost;
 use fluentbase_sdk::{ContextReader, ExecutionContext, LowLevelAPI, LowLevelSDK};
 use fluentbase_types::{Bytes, ExitCode};
 use revm_interpreter::primitives::PrecompileError;
@@ -11,7 +12,10 @@ pub fn main() {
 
     let result = revm_precompile::blake2::run(&input, gas_limit);
     let result = match result {
-        Ok((_, result)) => result,
+        Ok((gas_used, result)) => {
+            charge_gas_cost(gas_limit, gas_used);
+            result
+        }
         Err(err) => match err {
             PrecompileError::OutOfGas => {
                 LowLevelSDK::sys_halt(ExitCode::OutOfFuel.into_i32());
@@ -1,11 +1,58 @@
+use crate::{
+    consts::{
+        PRECOMPILE_BN128_ADD_ADDRESS,
+        PRECOMPILE_BN128_MUL_ADDRESS,
+        PRECOMPILE_BN128_PAIR_ADDRESS,
+    },
+    precompiles::charge_gas_cost,
+};
+use fluentbase_sdk::{ContextReader, ExecutionContext, LowLevelAPI, LowLevelSDK};
+use fluentbase_types::{Bytes, ExitCode};
+use revm_interpreter::primitives::PrecompileError;
+use revm_precompile::bn128;
+
 pub fn deploy() {}
 
+/// The same contract is deployed for ecAdd, ecMul and ecPairing, the operation is selected by
+/// the address of the called contract.
 pub fn main() {
-    panic!("not implemented yet")
-    // let input = ExecutionContext::contract_input();
-    // let gas_limit = ExecutionContext::contract_gas_limit();
-    //
-    // let pr = revm_precompile::bn128::run(&input, gas_limit);
-    // let res = pr.unwrap();
-    // LowLevelSDK::sys_write(res.1.as_ref());
+    let cr = ExecutionContext::DEFAULT;
+    let input = cr.contract_input();
+    let gas_limit = cr.contract_gas_limit();
+
+    let result = match cr.contract_address() {
+        PRECOMPILE_BN128_ADD_ADDRESS => {
+            bn128::run_add(&input, bn128::add::ISTANBUL_ADD_GAS_COST, gas_limit)
+        }
+        PRECOMPILE_BN128_MUL_ADDRESS => {
+            bn128::run_mul(&input, bn128::mul::ISTANBUL_MUL_GAS_COST, gas_limit)
+        }
+        PRECOMPILE_BN128_PAIR_ADDRESS => bn128::run_pair(
+            &input,
+            bn128::pair::ISTANBUL_PAIR_PER_POINT,
+            bn128::pair::ISTANBUL_PAIR_BASE,
+            gas_limit,
+        ),
+        _ => {
+            LowLevelSDK::sys_halt(ExitCode::NotSupportedCall.into_i32());
+            return;
+        }
+    };
+    let result = match result {
+        Ok((gas_used, result)) => {
+            charge_gas_cost(gas_limit, gas_used);
+            result
+        }
+        Err(err) => match err {
+            PrecompileError::OutOfGas => {
+                LowLevelSDK::sys_halt(ExitCode::OutOfFuel.into_i32());
+                Bytes::new()
+            }
+            _ => {
+                LowLevelSDK::sys_halt(ExitCode::PrecompileError.into_i32());
+                Bytes::new()
+            }
+        },
+    };
+    LowLevelSDK::sys_write(result.as_ref());
 }
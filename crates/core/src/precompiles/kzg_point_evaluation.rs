@@ -1,11 +1,95 @@
+use crate::precompiles::charge_gas_cost;
+use fluentbase_sdk::{ContextReader, ExecutionContext, LowLevelAPI, LowLevelSDK};
+use fluentbase_types::{Bytes, ExitCode};
+use kzg_rs::{Bytes32, Bytes48, KzgProof, KzgSettings};
+use revm_interpreter::primitives::{PrecompileError, PrecompileResult};
+use revm_primitives::hex;
+use sha2::{Digest, Sha256};
+
+pub const GAS_COST: u64 = 50_000;
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// `U256(FIELD_ELEMENTS_PER_BLOB).to_be_bytes() ++ BLS_MODULUS.to_bytes32()`
+pub const RETURN_VALUE: &[u8; 64] = &hex!(
+    "0000000000000000000000000000000000000000000000000000000000001000"
+    "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+);
+
 pub fn deploy() {}
 
 pub fn main() {
-    panic!("not implemented yet");
-    // let input = ExecutionContext::contract_input();
-    // let gas_limit = ExecutionContext::contract_gas_limit();
-    //
-    // let pr = revm_precompile::kzg_point_evaluation::run(&input, gas_limit);
-    // let res = pr.unwrap();
-    // LowLevelSDK::sys_write(res.1.as_ref());
+    let cr = ExecutionContext::DEFAULT;
+    let input = cr.contract_input();
+    let gas_limit = cr.contract_gas_limit();
+
+    let result = match run(&input, gas_limit) {
+        Ok((gas_used, result)) => {
+            charge_gas_cost(gas_limit, gas_used);
+            result
+        }
+        Err(err) => match err {
+            PrecompileError::OutOfGas => {
+                LowLevelSDK::sys_halt(ExitCode::OutOfFuel.into_i32());
+                Bytes::new()
+            }
+            _ => {
+                LowLevelSDK::sys_halt(ExitCode::PrecompileError.into_i32());
+                Bytes::new()
+            }
+        },
+    };
+    LowLevelSDK::sys_write(result.as_ref());
+}
+
+/// Runs the point evaluation precompile (EIP-4844) with the pure Rust KZG backend, because
+/// `c-kzg` can't be compiled into WebAssembly. The input is
+/// `versioned_hash | z | y | commitment | proof` (32, 32, 32, 48 and 48 bytes) and the proof is
+/// verified against the trusted setup of the Ethereum mainnet like `revm` does.
+pub fn run(input: &[u8], gas_limit: u64) -> PrecompileResult {
+    if gas_limit < GAS_COST {
+        return Err(PrecompileError::OutOfGas);
+    }
+    if input.len() != 192 {
+        return Err(PrecompileError::BlobInvalidInputLength);
+    }
+
+    // verify that the commitment matches the versioned hash
+    let versioned_hash = &input[..32];
+    let commitment = &input[96..144];
+    if kzg_to_versioned_hash(commitment) != versioned_hash {
+        return Err(PrecompileError::BlobMismatchedVersion);
+    }
+
+    // verify that `p(z) = y` for the polynomial committed by the commitment
+    let z = &input[32..64];
+    let y = &input[64..96];
+    let proof = &input[144..192];
+    if !verify_kzg_proof(commitment, z, y, proof) {
+        return Err(PrecompileError::BlobVerifyKzgProofFailed);
+    }
+
+    Ok((GAS_COST, Bytes::from_static(RETURN_VALUE.as_slice())))
+}
+
+/// Returns the versioned hash of the commitment, it's `sha256(commitment)` with the first byte
+/// replaced by the version.
+pub fn kzg_to_versioned_hash(commitment: &[u8]) -> [u8; 32] {
+    let mut hash: [u8; 32] = Sha256::digest(commitment).into();
+    hash[0] = VERSIONED_HASH_VERSION_KZG;
+    hash
+}
+
+fn verify_kzg_proof(commitment: &[u8], z: &[u8], y: &[u8], proof: &[u8]) -> bool {
+    let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
+        Bytes48::from_slice(commitment),
+        Bytes32::from_slice(z),
+        Bytes32::from_slice(y),
+        Bytes48::from_slice(proof),
+    ) else {
+        return false;
+    };
+    let Ok(settings) = KzgSettings::load_trusted_setup_file() else {
+        return false;
+    };
+    KzgProof::verify_kzg_proof(&commitment, &z, &y, &proof, &settings).unwrap_or(false)
 }
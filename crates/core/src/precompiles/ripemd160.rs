@@ -0,0 +1,31 @@
+use crate::precompiles::charge_gas_cost;
+use fluentbase_sdk::{ContextReader, ExecutionContext, LowLevelAPI, LowLevelSDK};
+use fluentbase_types::{Bytes, ExitCode};
+use revm_interpreter::primitives::PrecompileError;
+
+pub fn deploy() {}
+
+pub fn main() {
+    let cr = ExecutionContext::DEFAULT;
+    let input = cr.contract_input();
+    let gas_limit = cr.contract_gas_limit();
+
+    let result = revm_precompile::hash::ripemd160_run(&input, gas_limit);
+    let result = match result {
+        Ok((gas_used, result)) => {
+            charge_gas_cost(gas_limit, gas_used);
+            result
+        }
+        Err(err) => match err {
+            PrecompileError::OutOfGas => {
+                LowLevelSDK::sys_halt(ExitCode::OutOfFuel.into_i32());
+                Bytes::new()
+            }
+            _ => {
+                LowLevelSDK::sys_halt(ExitCode::PrecompileError.into_i32());
+                Bytes::new()
+            }
+        },
+    };
+    LowLevelSDK::sys_write(result.as_ref());
+}
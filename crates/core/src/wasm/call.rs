@@ -4,7 +4,6 @@ use fluentbase_sdk::{
     AccountManager,
     ContextReader,
     ContractInput,
-    EvmCallMethodOutput,
     WasmCallMethodInput,
     WasmCallMethodOutput,
 };
@@ -24,12 +23,14 @@ pub fn _wasm_call<CR: ContextReader, AM: AccountManager>(
             "_wasm_call return: Err: exit_code: {}",
             ExitCode::WriteProtection
         );
-        return WasmCallMethodOutput::from_exit_code(ExitCode::WriteProtection);
+        return WasmCallMethodOutput::from_exit_code(ExitCode::WriteProtection)
+            .with_gas(input.gas_limit, 0);
     }
 
     // call depth check
     if input.depth > 1024 {
-        return EvmCallMethodOutput::from_exit_code(ExitCode::CallDepthOverflow);
+        return WasmCallMethodOutput::from_exit_code(ExitCode::CallDepthOverflow)
+            .with_gas(input.gas_limit, 0);
     }
 
     // create new checkpoint position in the journal
@@ -38,15 +39,18 @@ pub fn _wasm_call<CR: ContextReader, AM: AccountManager>(
     // parse callee address
     let (callee_account, _) = am.account(input.callee);
 
-    let mut gas_limit = input.gas_limit as u32;
+    let fuel_conversion = am.fuel_conversion();
+    let fuel_limit = fuel_conversion.gas_to_fuel(input.gas_limit);
+    let mut fuel_remaining = fuel_limit;
 
     let contract_input = ContractInput {
         journal_checkpoint: cr.journal_checkpoint().into(),
-        contract_gas_limit: gas_limit as u64,
+        contract_gas_limit: input.gas_limit,
         contract_address: input.callee,
         contract_caller: cr.contract_caller(),
         contract_input: input.input,
         tx_caller: cr.tx_caller(),
+        contract_depth: input.depth,
         ..Default::default()
     };
     let contract_input_vec = contract_input.encode_to_vec(0);
@@ -55,9 +59,10 @@ pub fn _wasm_call<CR: ContextReader, AM: AccountManager>(
     let (output_buffer, exit_code) = am.exec_hash(
         bytecode_hash.as_ptr(),
         &contract_input_vec,
-        &mut gas_limit as *mut u32,
+        &mut fuel_remaining as *mut u64,
         STATE_MAIN,
     );
+    let gas_remaining = fuel_conversion.remaining_gas(input.gas_limit, fuel_limit - fuel_remaining);
 
     // if exit code success then commit changes, otherwise rollback
     if ExitCode::from(exit_code).is_ok() {
@@ -70,7 +75,7 @@ pub fn _wasm_call<CR: ContextReader, AM: AccountManager>(
     WasmCallMethodOutput {
         output: output_buffer.into(),
         exit_code,
-        gas_remaining: gas_limit as u64,
+        gas_remaining,
         gas_refund: 0,
     }
 }
@@ -1,4 +1,8 @@
-use crate::{debug_log, helpers::wasm2rwasm};
+use crate::{
+    consts::{WASM_COMPILATION_GAS_PER_BYTE, WASM_CREATE_BASE_GAS},
+    debug_log,
+    helpers::wasm2rwasm,
+};
 use fluentbase_sdk::{
     Account,
     AccountManager,
@@ -8,7 +12,8 @@ use fluentbase_sdk::{
     WasmCreateMethodInput,
     WasmCreateMethodOutput,
 };
-use fluentbase_types::{Bytes, ExitCode, B256, STATE_DEPLOY};
+use fluentbase_types::{ExitCode, B256, STATE_DEPLOY};
+use revm_interpreter::{gas, Gas};
 use revm_primitives::WASM_MAX_CODE_SIZE;
 
 pub fn _wasm_create<CR: ContextReader, AM: AccountManager>(
@@ -18,8 +23,11 @@ pub fn _wasm_create<CR: ContextReader, AM: AccountManager>(
 ) -> WasmCreateMethodOutput {
     debug_log!("_wasm_create start");
 
-    // TODO: "gas calculations"
-    // TODO: "call depth stack check >= 1024"
+    // call depth check
+    if input.depth > 1024 {
+        return WasmCreateMethodOutput::from_exit_code(ExitCode::CallDepthOverflow)
+            .with_gas(input.gas_limit, 0);
+    }
 
     // check write protection
     if cr.contract_is_static() {
@@ -27,7 +35,8 @@ pub fn _wasm_create<CR: ContextReader, AM: AccountManager>(
             "_wasm_create return: Err: exit_code: {}",
             ExitCode::WriteProtection
         );
-        return WasmCreateMethodOutput::from_exit_code(ExitCode::WriteProtection);
+        return WasmCreateMethodOutput::from_exit_code(ExitCode::WriteProtection)
+            .with_gas(input.gas_limit, 0);
     }
 
     // code length can't exceed max constructor limit
@@ -36,7 +45,16 @@ pub fn _wasm_create<CR: ContextReader, AM: AccountManager>(
             "_wasm_create return: Err: exit_code: {}",
             ExitCode::ContractSizeLimit
         );
-        return WasmCreateMethodOutput::from_exit_code(ExitCode::ContractSizeLimit);
+        return WasmCreateMethodOutput::from_exit_code(ExitCode::ContractSizeLimit)
+            .with_gas(input.gas_limit, 0);
+    }
+
+    // charge intrinsic and compilation gas before doing any work
+    let mut gas = Gas::new(input.gas_limit);
+    let gas_for_compilation =
+        WASM_CREATE_BASE_GAS + input.bytecode.len() as u64 * WASM_COMPILATION_GAS_PER_BYTE;
+    if !gas.record_cost(gas_for_compilation) {
+        return WasmCreateMethodOutput::from_exit_code(ExitCode::OutOfFuel);
     }
 
     let mut source_code_hash: B256 = B256::ZERO;
@@ -60,7 +78,8 @@ pub fn _wasm_create<CR: ContextReader, AM: AccountManager>(
     ) {
         Ok(result) => result,
         Err(exit_code) => {
-            return WasmCreateMethodOutput::from_exit_code(exit_code);
+            return WasmCreateMethodOutput::from_exit_code(exit_code)
+                .with_gas(gas.remaining(), gas.refunded());
         }
     };
     if !input.value.is_zero() {
@@ -84,7 +103,8 @@ pub fn _wasm_create<CR: ContextReader, AM: AccountManager>(
         Err(exit_code) => {
             am.rollback(checkpoint);
             debug_log!("_wasm_create return: panic: exit_code: {}", exit_code);
-            return WasmCreateMethodOutput::from_exit_code(exit_code);
+            return WasmCreateMethodOutput::from_exit_code(exit_code)
+                .with_gas(gas.remaining(), gas.refunded());
         }
     };
     // let exit_code = LowLevelSDK::wasm_to_rwasm(
@@ -104,24 +124,40 @@ pub fn _wasm_create<CR: ContextReader, AM: AccountManager>(
     // let mut rwasm_bytecode = vec![0u8; rwasm_bytecode_len as usize];
     // LowLevelSDK::sys_read_output(rwasm_bytecode.as_mut_ptr(), 0, rwasm_bytecode_len);
 
+    // record gas for each deployed byte, both WASM and rWASM binaries are stored in the trie
+    let gas_for_code = (input.bytecode.len() + rwasm_bytecode.len()) as u64 * gas::CODEDEPOSIT;
+    if !gas.record_cost(gas_for_code) {
+        am.rollback(checkpoint);
+        debug_log!(
+            "_wasm_create return: Err: exit_code: {}",
+            ExitCode::OutOfFuel
+        );
+        return WasmCreateMethodOutput::from_exit_code(ExitCode::OutOfFuel);
+    }
+
     // write deployer to the trie
     am.write_account(&deployer_account);
 
     // write contract to the trie
     contract_account.update_bytecode(am, &input.bytecode, None, &rwasm_bytecode.into(), None);
 
-    let mut gas_limit = input.gas_limit as u32;
+    let fuel_conversion = am.fuel_conversion();
+    let fuel_limit = fuel_conversion.gas_to_fuel(gas.remaining());
+    let mut fuel_remaining = fuel_limit;
     let (_, exit_code) = am.exec_hash(
         contract_account.rwasm_code_hash.as_ptr(),
         &[],
-        &mut gas_limit as *mut u32,
+        &mut fuel_remaining as *mut u64,
         STATE_DEPLOY,
     );
+    // consumed fuel never exceeds the fuel given for the remaining gas, so it can't fail
+    let _ = gas.record_cost(fuel_conversion.fuel_to_gas(fuel_limit - fuel_remaining));
     // if call is not success set deployed address to zero
     if exit_code != ExitCode::Ok.into_i32() {
         am.rollback(checkpoint);
-        debug_log!("_wasm_create return: Err: ExitCode::TransactError");
-        return WasmCreateMethodOutput::from_exit_code(ExitCode::from(exit_code));
+        debug_log!("_wasm_create return: Err: exit_code: {}", exit_code);
+        return WasmCreateMethodOutput::from_exit_code(ExitCode::from(exit_code))
+            .with_gas(gas.remaining(), gas.refunded());
     }
 
     debug_log!(
@@ -132,11 +168,7 @@ pub fn _wasm_create<CR: ContextReader, AM: AccountManager>(
     // commit all changes made
     am.commit();
 
-    WasmCreateMethodOutput {
-        output: Bytes::new(),
-        address: Some(contract_account.address),
-        exit_code,
-        gas: gas_limit as u64,
-        gas_refund: 0,
-    }
+    WasmCreateMethodOutput::from_exit_code(ExitCode::Ok)
+        .with_gas(gas.remaining(), gas.refunded())
+        .with_address(contract_account.address)
 }
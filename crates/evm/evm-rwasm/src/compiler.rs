@@ -8,10 +8,11 @@ use crate::{
         translator::{contract::Contract, SubroutineData, Translator},
     },
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec, vec::Vec};
 use alloy_primitives::Bytes;
+use fluentbase_types::{create_sovereign_import_linker, ExitCode};
 use rwasm::core::ImportLinker;
-use rwasm_codegen::InstructionSet;
+use rwasm_codegen::{BinaryFormat, BinaryFormatWriter, InstructionSet};
 
 #[derive()]
 pub struct EvmCompiler<'a> {
@@ -105,3 +106,20 @@ impl<'a> EvmCompiler<'a> {
         self.inject_fuel_consumption = inject_fuel_consumption
     }
 }
+
+/// Translates EVM bytecode into rWASM binary, it matches `EvmTranslator` of the runtime, so it
+/// can be passed to the host for the ahead-of-time translation of deployed contracts.
+pub fn evm_to_rwasm(evm_bytecode: &[u8]) -> Result<Vec<u8>, ExitCode> {
+    let import_linker: ImportLinker = create_sovereign_import_linker();
+    let mut compiler = EvmCompiler::new(&import_linker, true, evm_bytecode);
+    if !compiler.run(None, None).is_ok() {
+        return Err(ExitCode::CompilationError);
+    }
+    let mut rwasm_binary = vec![0u8; compiler.instruction_set.encoded_length()];
+    let mut binary_format_writer = BinaryFormatWriter::new(&mut rwasm_binary);
+    compiler
+        .instruction_set
+        .write_binary(&mut binary_format_writer)
+        .map_err(|_| ExitCode::CompilationError)?;
+    Ok(rwasm_binary)
+}
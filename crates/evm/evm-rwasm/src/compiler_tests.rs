@@ -1,5 +1,5 @@
 use crate::{
-    compiler::EvmCompiler,
+    compiler::{evm_to_rwasm, EvmCompiler},
     consts::SP_BASE_MEM_OFFSET_DEFAULT,
     translator::{
         instruction_result::InstructionResult,
@@ -60,6 +60,8 @@ use crate::{
             POP,
             PUSH32,
             RETURN,
+            RETURNDATACOPY,
+            RETURNDATASIZE,
             SAR,
             SDIV,
             SELFBALANCE,
@@ -88,10 +90,10 @@ use core::cell::RefCell;
 use fluentbase_codec::Encoder;
 use fluentbase_runtime::{ExecutionResult, Runtime, RuntimeContext};
 use fluentbase_sdk::evm::{Address, ContractInput, U256};
-use fluentbase_types::{Account, AccountDb, InMemoryAccountDb};
+use fluentbase_types::{Account, AccountDb, FuelConversion, InMemoryAccountDb};
 use lazy_static::lazy_static;
 use log::debug;
-use rwasm::engine::bytecode::Instruction;
+use rwasm::{core::ImportLinker, engine::bytecode::Instruction};
 use rwasm_codegen::{BinaryFormat, BinaryFormatWriter, InstructionSet, ReducedModule};
 
 static CONTRACT_ADDRESS: [u8; 20] = [1; 20];
@@ -378,17 +380,11 @@ fn run_test(
         .unwrap();
     let rwasm_binary = binary_format_writer.to_vec();
 
-    let mut rmodule = ReducedModule::new(&rwasm_binary).unwrap();
-    let mut instruction_set = rmodule.bytecode().clone();
-    debug!(
-        "\nrmodule.trace_binary() (rwasm_binary.len={}): \n{}\n",
-        rwasm_binary.len(),
-        instruction_set.trace()
-    );
+    Some(run_rwasm_binary(rwasm_binary, &import_linker))
+}
 
-    let mut global_memory = vec![];
-    let mut global_memory_len: usize = 0;
-    let mut runtime_ctx = RuntimeContext::new(rwasm_binary);
+fn create_runtime_context(rwasm_binary: Vec<u8>) -> RuntimeContext<()> {
+    let runtime_ctx = RuntimeContext::new(rwasm_binary);
 
     let mut contract_input = ContractInput::default();
     contract_input.contract_address = Address::new(CONTRACT_ADDRESS);
@@ -406,26 +402,48 @@ fn run_test(
     contract_input.block_timestamp = u64::from_be_bytes(HOST_TIMESTAMP);
     contract_input.block_difficulty = u64::from_be_bytes(HOST_ENV_DIFFICULTY);
     contract_input.contract_bytecode = Bytes::copy_from_slice(CONTRACT_BYTECODE);
-    // contract_input.tx_blob_gas_price = u64::from_be_bytes(HOST_ENV_BLOBBASEFEE);
+    contract_input.block_blob_base_fee = U256::from(u64::from_be_bytes(HOST_ENV_BLOBBASEFEE));
     contract_input.tx_gas_price = U256::from_be_bytes(HOST_ENV_GASPRICE);
     contract_input.tx_caller = Address::new(HOST_ENV_ORIGIN);
-    // contract_input.tx_blob_hashes = HOST_ENV_BLOB_HASHES
-    //     .iter()
-    //     .map(|v| B256::from_slice(v))
-    //     .collect();
+    contract_input.tx_blob_hashes = HOST_ENV_BLOB_HASHES
+        .iter()
+        .map(|v| B256::from_slice(v))
+        .collect();
     let mut account_db = InMemoryAccountDb::default();
 
     account_db.update_account(&CALLER_ADDRESS, &CALLER_ACCOUNT);
     account_db.update_account(&USER1_ADDRESS, &USER1_ACCOUNT);
     account_db.update_account(&USER2_ADDRESS, &USER2_ACCOUNT);
     let ci = contract_input.encode_to_vec(0);
-    runtime_ctx = runtime_ctx
+    runtime_ctx
         .with_input(ci)
         .with_fuel_limit(10_000_000)
         .with_account_db(Rc::new(RefCell::new(account_db)))
-        .with_caller(CALLER_ADDRESS.clone());
+        .with_caller(CALLER_ADDRESS.clone())
+}
 
-    let runtime = Runtime::new(runtime_ctx, &import_linker);
+/// Translates EVM bytecode with the fuel metering and returns fuel consumed by its execution
+fn run_fuel_consumed(evm_bytecode: &[u8]) -> u64 {
+    let rwasm_binary = evm_to_rwasm(evm_bytecode).unwrap();
+    let import_linker = Runtime::<()>::new_sovereign_linker();
+    let mut runtime = Runtime::new(create_runtime_context(rwasm_binary), &import_linker).unwrap();
+    let execution_result: ExecutionResult<()> = runtime.call().unwrap();
+    assert_eq!(execution_result.data().exit_code(), 0);
+    execution_result.data().fuel_consumed
+}
+
+fn run_rwasm_binary(rwasm_binary: Vec<u8>, import_linker: &ImportLinker) -> (Vec<u8>, Vec<u8>) {
+    let mut rmodule = ReducedModule::new(&rwasm_binary).unwrap();
+    let mut instruction_set = rmodule.bytecode().clone();
+    debug!(
+        "\nrmodule.trace_binary() (rwasm_binary.len={}): \n{}\n",
+        rwasm_binary.len(),
+        instruction_set.trace()
+    );
+
+    let mut global_memory = vec![];
+    let mut global_memory_len: usize = 0;
+    let runtime = Runtime::new(create_runtime_context(rwasm_binary), import_linker);
     let mut runtime = runtime.unwrap();
     let result = runtime.call();
     assert!(result.is_ok());
@@ -472,7 +490,20 @@ fn run_test(
     //     global_memory_len, &global_memory
     // );
 
-    Some((global_memory, runtime.data().output().clone()))
+    (global_memory, runtime.data().output().clone())
+}
+
+#[test]
+fn evm_to_rwasm_deploy_and_call() {
+    // PUSH1 0x2a PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+    let evm_bytecode = x("602a60005260206000f3");
+    // translated binary is stored on deployment and executed on the call
+    let rwasm_binary = evm_to_rwasm(&evm_bytecode).unwrap();
+    let import_linker = Runtime::<()>::new_sovereign_linker();
+    let (_, output) = run_rwasm_binary(rwasm_binary, &import_linker);
+    let mut res_expected = vec![0u8; EVM_WORD_BYTES];
+    res_expected[EVM_WORD_BYTES - 1] = 0x2a;
+    assert_eq!(output, res_expected);
 }
 
 #[test]
@@ -2452,6 +2483,42 @@ fn calldatacopy() {
     );
 }
 
+#[test]
+fn returndatasize() {
+    // nothing is called before, so return data is empty
+    let cases = [Case::Args0(x(
+        "0000000000000000000000000000000000000000000000000000000000000000",
+    ))];
+
+    test_cases(
+        RETURNDATASIZE,
+        None,
+        &cases,
+        Some(EVM_WORD_BYTES as i32),
+        ResultLocation::Stack,
+        None,
+    );
+}
+
+#[test]
+fn returndatacopy() {
+    let cases = vec![Case::Args3((
+        x("0000000000000000000000000000000000000000000000000000000000000000"), // dst
+        x("0000000000000000000000000000000000000000000000000000000000000000"), // src
+        x("0000000000000000000000000000000000000000000000000000000000000000"), // size
+        vec![],
+    ))];
+
+    test_cases(
+        RETURNDATACOPY,
+        None,
+        &cases,
+        Some(0),
+        ResultLocation::Memory(0),
+        None,
+    );
+}
+
 #[test]
 fn callvalue() {
     let cases = [Case::Args0(CONTRACT_VALUE.to_vec())];
@@ -2675,7 +2742,51 @@ fn timestamp() {
     );
 }
 
-#[ignore] // TODO no implementation yet
+/// Returns EVM bytecode that executes the opcode `n` times with the same word and drops results
+fn repeat_op_bytecode(opcode: u8, word: &[u8], n: usize) -> Vec<u8> {
+    let mut evm_bytecode = vec![];
+    for _ in 0..n {
+        evm_bytecode.push(PUSH32);
+        evm_bytecode.extend(word);
+        evm_bytecode.push(opcode);
+        evm_bytecode.push(POP);
+    }
+    evm_bytecode
+}
+
+/// Checks that the first access costs the cold surcharge on top of the warm access, and the
+/// next access to the same account or slot costs the warm access only (EIP-2929)
+fn test_cold_warm_access(opcode: u8, word: &[u8], cold_surcharge_gas: u64) {
+    let fuel_none = run_fuel_consumed(&repeat_op_bytecode(opcode, word, 0));
+    let fuel_cold = run_fuel_consumed(&repeat_op_bytecode(opcode, word, 1)) - fuel_none;
+    let fuel_warm = run_fuel_consumed(&repeat_op_bytecode(opcode, word, 2)) - fuel_none - fuel_cold;
+    assert!(fuel_warm > 0);
+    assert_eq!(
+        fuel_cold - fuel_warm,
+        FuelConversion::DEFAULT.gas_to_fuel(cold_surcharge_gas)
+    );
+}
+
+#[test]
+fn balance_cold_warm_access() {
+    // COLD_ACCOUNT_ACCESS_COST - WARM_STORAGE_READ_COST
+    test_cold_warm_access(
+        BALANCE,
+        B256::left_padding_from(USER1_ADDRESS.as_slice()).as_slice(),
+        2600 - 100,
+    );
+}
+
+#[test]
+fn sload_cold_warm_access() {
+    // COLD_SLOAD_COST - WARM_STORAGE_READ_COST
+    test_cold_warm_access(
+        SLOAD,
+        &x("0000000000000000000000000000000000000000000000000000000000000001"),
+        2100 - 100,
+    );
+}
+
 #[test]
 fn sstore_sload() {
     let mut preamble = vec![];
@@ -2789,7 +2900,6 @@ fn difficulty() {
     );
 }
 
-#[ignore] // TODO no implementation yet
 #[test]
 fn blobbasefee() {
     let cases = [Case::Args0({
@@ -2808,7 +2918,6 @@ fn blobbasefee() {
     );
 }
 
-#[ignore] // TODO no implementation yet
 #[test]
 fn blobhash() {
     let cases = [
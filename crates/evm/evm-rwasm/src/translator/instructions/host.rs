@@ -14,6 +14,8 @@ pub fn balance<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     #[cfg(test)]
     debug!("op:{}", OP);
     pop_address!(translator, _address);
+    // warm access is charged here and cold access surcharge is charged by the subroutine, because
+    // accessed accounts (EIP-2929) are known only in runtime
     gas!(translator, gas::calc::account_access_gas(false));
 
     replace_with_call_to_subroutine(translator, host);
@@ -33,13 +35,8 @@ pub fn extcodesize<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     #[cfg(test)]
     debug!("op:{}", OP);
     pop_address!(translator, _address);
-    gas!(
-        translator,
-        // if is_cold {
-        //     COLD_ACCOUNT_ACCESS_COST
-        // } else {
-        gas::constants::WARM_STORAGE_READ_COST // }
-    );
+    // cold access surcharge is charged by the subroutine
+    gas!(translator, gas::calc::account_access_gas(false));
 
     replace_with_call_to_subroutine(translator, host);
 }
@@ -50,13 +47,8 @@ pub fn extcodehash<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     #[cfg(test)]
     debug!("op:{}", OP);
     pop_address!(translator, _address);
-    gas!(
-        translator,
-        // if is_cold {
-        //     COLD_ACCOUNT_ACCESS_COST
-        // } else {
-        gas::constants::WARM_STORAGE_READ_COST // }
-    );
+    // cold access surcharge is charged by the subroutine
+    gas!(translator, gas::calc::account_access_gas(false));
 
     replace_with_call_to_subroutine(translator, host);
 }
@@ -68,10 +60,8 @@ pub fn extcodecopy<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     pop_address!(translator, _address);
     pop!(translator, _memory_offset, _code_offset, len_u256);
     let len = as_usize_or_fail!(translator, len_u256);
+    // cold access surcharge is charged by the subroutine, so it's called even for the empty copy
     gas_or_fail!(translator, gas::calc::extcodecopy_cost(len as u32, false));
-    if len == 0 {
-        return;
-    }
 
     replace_with_call_to_subroutine(translator, host);
 }
@@ -90,17 +80,8 @@ pub fn sload<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     #[cfg(test)]
     debug!("op:{}", OP);
     pop!(translator, _index);
-    // const OP_PARAMS_COUNT: u64 = 1;
-    // let is = translator.result_instruction_set();
-    // sp_get_offset(is);
-    // is.op_local_get(1); // save to the same word
-    // wasm_call(translator, is, SysFuncIdx::ZKTRIE_LOAD);
-    // TODO hot/cold logic
-    // let Some((value, is_cold)) = host.sload(interpreter.contract.address, index) else {
-    //     interpreter.instruction_result = InstructionResult::FatalExternalError;
-    //     return;
-    // };
-    gas!(translator, gas::calc::sload_cost(/*is_cold*/));
+    // cold slot surcharge is charged by the subroutine
+    gas!(translator, gas::calc::sload_cost(false));
 
     replace_with_call_to_subroutine(translator, host);
 }
@@ -109,25 +90,9 @@ pub fn sstore<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     const OP: &str = "SSTORE";
     #[cfg(test)]
     debug!("op:{}", OP);
-    // const OP_PARAMS_COUNT: u64 = 2;
-    // let is = translator.result_instruction_set();
-    // sp_get_offset(is, None);
-    // sp_get_offset(is, Some(EVM_WORD_BYTES as i64));
-    // wasm_call(translator, is, SysFuncIdx::ZKTRIE_STORE);
-    // sp_drop_u256(is, OP_PARAMS_COUNT);
     pop!(translator, _index, _value);
-    // TODO hot/cold logic
-    // let Some((original, old, new, is_cold)) =
-    //     host.sstore(interpreter.contract.address, index, value)
-    //     else {
-    //         interpreter.instruction_result = InstructionResult::FatalExternalError;
-    //         return;
-    //     };
-    // gas_or_fail!(translator, {
-    //     let remaining_gas = translator.gas.remaining();
-    //     gas::calc::::sstore_cost(original, old, new, remaining_gas, false)
-    // });
-    gas::calc::sstore_cost().map(|v| gas!(translator, v));
+    // the cost depends on the original and the current values of the slot, so it's charged by
+    // the subroutine
 
     replace_with_call_to_subroutine(translator, host);
 }
@@ -196,6 +161,7 @@ pub fn call<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     as_usize_or_fail!(translator, in_len);
     as_usize_or_fail!(translator, out_offset);
     as_usize_or_fail!(translator, out_len);
+    // cold access surcharge is charged by the subroutine
     gas!(
         translator,
         gas::calc::call_cost(value, false, false, true, true,)
@@ -222,6 +188,7 @@ pub fn delegate_call<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     as_usize_or_fail!(translator, in_len);
     as_usize_or_fail!(translator, out_offset);
     as_usize_or_fail!(translator, out_len);
+    // cold access surcharge is charged by the subroutine
     gas!(
         translator,
         gas::calc::call_cost(U256::ZERO, false, false, false, false)
@@ -240,6 +207,7 @@ pub fn static_call<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     as_usize_or_fail!(translator, in_len);
     as_usize_or_fail!(translator, out_offset);
     as_usize_or_fail!(translator, out_len);
+    // cold access surcharge is charged by the subroutine
     gas!(
         translator,
         gas::calc::call_cost(U256::ZERO, false, false, false, true)
@@ -158,8 +158,8 @@ opcodes! {
     0x3A => GASPRICE       => host_env::gasprice, // tx_gas_price
     0x3B => EXTCODESIZE    => host::extcodesize::<H>, // done
     0x3C => EXTCODECOPY    => host::extcodecopy::<H>, // done
-    0x3D => RETURNDATASIZE => system::returndatasize::<H>, // TODO
-    0x3E => RETURNDATACOPY => system::returndatacopy::<H>, // TODO
+    0x3D => RETURNDATASIZE => system::returndatasize::<H>, // done
+    0x3E => RETURNDATACOPY => system::returndatacopy::<H>, // done
     0x3F => EXTCODEHASH    => host::extcodehash::<H>, // done
     0x40 => BLOCKHASH      => host::blockhash, // done
     0x41 => COINBASE       => host_env::coinbase, // done
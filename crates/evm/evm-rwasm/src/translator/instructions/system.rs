@@ -2,7 +2,6 @@ use crate::{
     translator::{
         gas,
         host::Host,
-        instruction_result::InstructionResult,
         instructions::utilities::replace_with_call_to_subroutine,
         translator::Translator,
     },
@@ -109,22 +108,25 @@ pub fn calldatacopy<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     replace_with_call_to_subroutine(translator, host);
 }
 
-pub fn returndatasize<H: Host>(translator: &mut Translator<'_>, _host: &mut H) {
+pub fn returndatasize<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     const OP: &str = "RETURNDATASIZE";
-    if cfg!(test) {
-        panic!("op:{} not implemented", OP);
-    }
-    return_with_reason!(translator, InstructionResult::OpcodeNotFound);
+    #[cfg(test)]
+    debug!("op:{}", OP);
+    gas!(translator, gas::constants::BASE);
 
-    // gas!(translator, gas::constants::BASE);
+    replace_with_call_to_subroutine(translator, host);
 }
 
-pub fn returndatacopy<H: Host>(translator: &mut Translator<'_>, _host: &mut H) {
+pub fn returndatacopy<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
     const OP: &str = "RETURNDATACOPY";
-    if cfg!(test) {
-        panic!("op:{} not implemented", OP);
-    }
-    return_with_reason!(translator, InstructionResult::OpcodeNotFound);
+    #[cfg(test)]
+    debug!("op:{}", OP);
+    pop!(translator, _memory_offset, _data_offset, len);
+    let len = as_usize_or_fail!(translator, len);
+    gas_or_fail!(translator, gas::calc::verylowcopy_cost(len as u32));
+    // empty copy isn't skipped, because out of bounds offset must halt the execution anyway
+
+    replace_with_call_to_subroutine(translator, host);
 }
 
 pub fn gas<H: Host>(translator: &mut Translator<'_>, host: &mut H) {
@@ -1,4 +1,8 @@
-use crate::translator::{host::Host, translator::Translator};
+use crate::translator::{
+    host::Host,
+    instruction_result::InstructionResult,
+    translator::Translator,
+};
 use alloc::format;
 use fluentbase_types::SysFuncIdx;
 use rwasm_codegen::InstructionSet;
@@ -38,9 +42,13 @@ pub(super) fn replace_with_call_to_subroutine(
     translator: &mut Translator<'_>,
     host: &mut dyn Host,
 ) {
+    let opcode = translator.instruction_prev();
+    if translator.subroutine_data(opcode as u32).is_none() {
+        // snippet bundle (`solid_file.rwasm`) is built without this opcode
+        return_with_reason!(translator, InstructionResult::OpcodeNotFound);
+    }
     preprocess_op_params(translator, host);
 
-    let opcode = translator.instruction_prev();
     let is_len = translator.result_instruction_set_mut().len();
 
     let sd = translator
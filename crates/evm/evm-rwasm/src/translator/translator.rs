@@ -295,7 +295,7 @@ impl<'a> Translator<'a> {
 
     fn init_code_snippets(&mut self) {
         let opcode_to_entry_to_len =
-            include!("../../../orphaned/code-snippets/bin/solid_file_source_map.rs").as_slice();
+            include!("../../../evm-snippets/bin/solid_file_source_map.rs").as_slice();
         let mut initiate_subroutines_solid_file = |rwasm_binary: &[u8]| {
             let instruction_set = ReducedModule::new(&rwasm_binary)
                 .unwrap()
@@ -327,7 +327,7 @@ impl<'a> Translator<'a> {
         };
 
         initiate_subroutines_solid_file(
-            include_bytes!("../../../orphaned/code-snippets/bin/solid_file.rwasm").as_slice(),
+            include_bytes!("../../../evm-snippets/bin/solid_file.rwasm").as_slice(),
         );
     }
 
@@ -13,5 +13,11 @@ pub const U64_BYTES_COUNT: u64 = 8;
 pub const U256_BYTES_COUNT: u64 = 32;
 pub const U64_BITS_COUNT: u64 = 64;
 pub const U64_HALF_BITS_COUNT: u64 = U64_BITS_COUNT / 2;
-// TODO fix hardcode
-pub(crate) const GAS_LIMIT_HARDCODED: u32 = 10_000_000;
+// EIP-2929 access costs, the translator charges the warm cost and snippets charge the difference
+pub(crate) const WARM_STORAGE_READ_COST: u64 = 100;
+pub(crate) const COLD_ACCOUNT_ACCESS_COST: u64 = 2600;
+pub(crate) const COLD_SLOAD_COST: u64 = 2100;
+pub(crate) const SSTORE_SET: u64 = 20000;
+pub(crate) const SSTORE_RESET: u64 = 5000;
+pub(crate) const ACCESS_LIST_STORAGE_KEY: u64 = 1900;
+pub(crate) const CALL_STIPEND: u64 = 2300;
@@ -1,18 +1,33 @@
 use crate::{
-    common::{u256_be_to_u64tuple_le, u256_from_be_slice},
+    common::u256_be_to_u64tuple_le,
     common_sp::{
         stack_peek_u256,
         stack_pop_u256,
-        stack_push_u256,
-        u256_one,
         u256_zero,
         SP_BASE_MEM_OFFSET_DEFAULT,
     },
-    consts::{GAS_LIMIT_HARDCODED, U256_BYTES_COUNT},
+    consts::{
+        ACCESS_LIST_STORAGE_KEY,
+        CALL_STIPEND,
+        COLD_ACCOUNT_ACCESS_COST,
+        COLD_SLOAD_COST,
+        SSTORE_RESET,
+        SSTORE_SET,
+        U256_BYTES_COUNT,
+        WARM_STORAGE_READ_COST,
+    },
 };
 use core::slice;
 use fluentbase_core::ExitCode;
-use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
+use fluentbase_sdk::{
+    AccountManager,
+    ContextReader,
+    ExecutionContext,
+    JzktAccountManager,
+    LowLevelAPI,
+    LowLevelSDK,
+};
+use fluentbase_types::{Address, U256};
 
 #[cfg(feature = "host_balance")]
 mod balance;
@@ -67,6 +82,117 @@ mod tload;
 #[cfg(feature = "host_tstore")]
 mod tstore;
 
+/// Charges the cold account access surcharge if the account (right aligned in the word) is
+/// accessed for the first time in the transaction
+#[inline]
+pub fn charge_account_access(address: &[u8; U256_BYTES_COUNT as usize]) {
+    let address = Address::from_slice(&address[U256_BYTES_COUNT as usize - 20..]);
+    charge_gas(account_access_surcharge(
+        JzktAccountManager.access_account(address),
+    ));
+}
+
+/// Charges the cold slot surcharge of `SLOAD`, the warm read is charged by the translator
+#[inline]
+pub fn charge_sload(is_cold: bool) {
+    charge_gas(sload_surcharge(is_cold));
+}
+
+/// Charges `SSTORE` (EIP-2200 with EIP-2929 access costs), it depends on the original and the
+/// current values of the slot, so it's fully charged in runtime
+#[inline]
+pub fn charge_sstore(original: U256, current: U256, new: U256, is_cold: bool) {
+    // EIP-1706: disallow `SSTORE` if the remaining gas isn't above the call stipend
+    let remaining_gas = JzktAccountManager
+        .fuel_conversion()
+        .fuel_to_gas(LowLevelSDK::sys_fuel(0));
+    if remaining_gas <= CALL_STIPEND {
+        LowLevelSDK::sys_halt(ExitCode::OutOfFuel.into_i32());
+        return;
+    }
+    charge_gas(sstore_cost(original, current, new, is_cold));
+}
+
+/// Gas charged on top of the warm access cost for `BALANCE`, `EXTCODESIZE` and the other
+/// account access opcodes
+#[inline]
+pub(crate) fn account_access_surcharge(is_cold: bool) -> u64 {
+    if is_cold {
+        COLD_ACCOUNT_ACCESS_COST - WARM_STORAGE_READ_COST
+    } else {
+        0
+    }
+}
+
+/// Gas charged on top of the warm storage read cost for `SLOAD`
+#[inline]
+pub(crate) fn sload_surcharge(is_cold: bool) -> u64 {
+    if is_cold {
+        COLD_SLOAD_COST - WARM_STORAGE_READ_COST
+    } else {
+        0
+    }
+}
+
+#[inline]
+pub(crate) fn sstore_cost(original: U256, current: U256, new: U256, is_cold: bool) -> u64 {
+    let gas = if new == current || original != current {
+        WARM_STORAGE_READ_COST
+    } else if original == U256::ZERO {
+        SSTORE_SET
+    } else {
+        SSTORE_RESET - COLD_SLOAD_COST
+    };
+    if is_cold {
+        gas + COLD_SLOAD_COST
+    } else {
+        gas
+    }
+}
+
+/// Returns `SSTORE` gas refund (EIP-3529), translated bytecode records it into the transaction
+/// refund counter, because the interpreter's gas isn't available there
+#[inline]
+pub(crate) fn sstore_refund(original: U256, current: U256, new: U256) -> i64 {
+    let sstore_clears_schedule = (SSTORE_RESET - COLD_SLOAD_COST + ACCESS_LIST_STORAGE_KEY) as i64;
+    if current == new {
+        return 0;
+    }
+    if original == current && new == U256::ZERO {
+        return sstore_clears_schedule;
+    }
+    let mut refund = 0;
+    if original != U256::ZERO {
+        if current == U256::ZERO {
+            refund -= sstore_clears_schedule;
+        } else if new == U256::ZERO {
+            refund += sstore_clears_schedule;
+        }
+    }
+    if original == new {
+        refund += if original == U256::ZERO {
+            (SSTORE_SET - WARM_STORAGE_READ_COST) as i64
+        } else {
+            (SSTORE_RESET - COLD_SLOAD_COST - WARM_STORAGE_READ_COST) as i64
+        };
+    }
+    refund
+}
+
+/// Returns fuel for the gas using the host's fuel conversion policy, so the gas charged by
+/// snippets matches the gas charged by revm
+#[inline]
+pub(crate) fn gas_to_fuel(gas: u64) -> u64 {
+    JzktAccountManager.fuel_conversion().gas_to_fuel(gas)
+}
+
+#[inline]
+fn charge_gas(gas: u64) {
+    if gas > 0 {
+        LowLevelSDK::sys_fuel(gas_to_fuel(gas));
+    }
+}
+
 #[deprecated]
 #[inline]
 pub fn host_call_impl<const IS_DELEGATE: bool, const IS_STATIC: bool>() {
@@ -115,47 +241,11 @@ pub fn host_call_impl<const IS_DELEGATE: bool, const IS_STATIC: bool>() {
     // }
 }
 
+/// Nested calls aren't supported by translated bytecode, `evm2rwasm` of the core never
+/// translates bytecode with call opcodes, such contracts are executed by the interpreter
 #[inline]
 pub fn host_call_impl_v2<const IS_DELEGATE: bool, const IS_STATIC: bool>() {
-    let gas = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-    let address = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-    let value = if IS_STATIC || IS_DELEGATE {
-        u256_zero()
-    } else {
-        stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT)
-    };
-    let args_offset = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-    let args_size = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-    let ret_offset = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-    let ret_size = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-
-    let gas = u256_be_to_u64tuple_le(gas);
-    let fuel: u32 = {
-        if gas.0 > u32::MAX as u64 || gas.1 > 0 || gas.2 > 0 || gas.3 > 0 {
-            u32::MAX
-        } else {
-            gas.0 as u32
-        }
-    };
-    let args_offset = u256_be_to_u64tuple_le(args_offset);
-    let args_size = u256_be_to_u64tuple_le(args_size);
-    let ret_offset = u256_be_to_u64tuple_le(ret_offset);
-    let ret_size = u256_be_to_u64tuple_le(ret_size);
-
-    let exit_code = fluentbase_core::evm::call::_evm_call(
-        fuel,
-        address[U256_BYTES_COUNT as usize - 20..].as_ptr(),
-        value.as_ptr(),
-        args_offset.0 as *const u8,
-        args_size.0 as u32,
-        ret_offset.0 as *mut u8,
-        ret_size.0 as u32,
-    );
-    if exit_code == ExitCode::Ok {
-        stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_one());
-        return;
-    }
-    stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_zero());
+    LowLevelSDK::sys_halt(ExitCode::NotSupportedCall.into_i32());
 }
 
 #[deprecated]
@@ -209,58 +299,10 @@ pub fn host_create_impl<const IS_CREATE2: bool>() {
     // );
 }
 
+/// The same as [host_call_impl_v2], bytecode with create opcodes is never translated
 #[inline]
 pub fn host_create_impl_v2<const IS_CREATE2: bool>() {
-    let value = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-    let init_bytecode_offset = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-    let mut init_bytecode_size = if IS_CREATE2 {
-        (0, stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT))
-    } else {
-        stack_peek_u256(SP_BASE_MEM_OFFSET_DEFAULT, 0)
-    };
-    let salt = if IS_CREATE2 {
-        stack_peek_u256(SP_BASE_MEM_OFFSET_DEFAULT, 0)
-    } else {
-        (init_bytecode_size.0, [0u8; U256_BYTES_COUNT as usize])
-    };
-    let deployed_contract_address20_out_offset = if IS_CREATE2 {
-        salt.0
-    } else {
-        init_bytecode_size.0
-    };
-
-    let init_bytecode_offset = u256_be_to_u64tuple_le(init_bytecode_offset).0;
-    let init_bytecode_size = u256_be_to_u64tuple_le(init_bytecode_size.1).0;
-
-    let deployed_contract_address20_out =
-        unsafe { slice::from_raw_parts_mut(deployed_contract_address20_out_offset as *mut u8, 20) };
-
-    let exit_code = if IS_CREATE2 {
-        fluentbase_core::evm::create2::_evm_create2(
-            value.as_ptr(),
-            init_bytecode_offset as *const u8,
-            init_bytecode_size as u32,
-            salt.0 as *const u8,
-            deployed_contract_address20_out.as_mut_ptr(),
-            GAS_LIMIT_HARDCODED,
-        )
-    } else {
-        fluentbase_core::evm::create::_evm_create(
-            value.as_ptr(),
-            init_bytecode_offset as *const u8,
-            init_bytecode_size as u32,
-            deployed_contract_address20_out.as_mut_ptr(),
-            GAS_LIMIT_HARDCODED,
-        )
-    };
-    if !exit_code.is_ok() {
-        stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, [0u8; U256_BYTES_COUNT as usize]);
-        return;
-    }
-    stack_push_u256(
-        SP_BASE_MEM_OFFSET_DEFAULT,
-        u256_from_be_slice(deployed_contract_address20_out),
-    );
+    LowLevelSDK::sys_halt(ExitCode::NotSupportedCall.into_i32());
 }
 
 #[inline]
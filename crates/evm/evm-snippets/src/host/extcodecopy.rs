@@ -2,6 +2,7 @@ use crate::{
     common::u256_be_to_u64tuple_le,
     common_sp::{stack_pop_u256, SP_BASE_MEM_OFFSET_DEFAULT},
     consts::U256_BYTES_COUNT,
+    host::charge_account_access,
 };
 use core::slice;
 use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
@@ -12,6 +13,7 @@ pub fn host_extcodecopy() {
     let dest_offset = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
     let offset = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
     let size = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
+    charge_account_access(&address);
 
     let address20 = &address[U256_BYTES_COUNT as usize - 20..];
 
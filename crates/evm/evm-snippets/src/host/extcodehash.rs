@@ -1,6 +1,7 @@
 use crate::{
     common_sp::{stack_peek_u256, SP_BASE_MEM_OFFSET_DEFAULT},
     consts::U256_BYTES_COUNT,
+    host::charge_account_access,
 };
 use core::slice;
 use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
@@ -8,6 +9,7 @@ use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
 #[no_mangle]
 pub fn host_extcodehash() {
     let address = stack_peek_u256(SP_BASE_MEM_OFFSET_DEFAULT, 0);
+    charge_account_access(&address.1);
 
     let address20 = &address.1[U256_BYTES_COUNT as usize - 20..];
 
@@ -1,12 +1,14 @@
 use crate::{
     common_sp::{stack_pop_u256, stack_push_u256, u256_zero, SP_BASE_MEM_OFFSET_DEFAULT},
     consts::U256_BYTES_COUNT,
+    host::charge_account_access,
 };
 use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
 
 #[no_mangle]
 pub fn host_extcodesize() {
     let address = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
+    charge_account_access(&address);
 
     let address20 = &address[U256_BYTES_COUNT as usize - 20..];
 
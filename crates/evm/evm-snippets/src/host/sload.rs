@@ -1,15 +1,17 @@
 use crate::{
-    common::u256_from_be_slice,
     common_sp::{stack_pop_u256, stack_push_u256, SP_BASE_MEM_OFFSET_DEFAULT},
-    consts::U256_BYTES_COUNT,
+    host::charge_sload,
 };
-use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
+use fluentbase_sdk::{AccountManager, ContextReader, ExecutionContext, JzktAccountManager};
+use fluentbase_types::U256;
 
 #[no_mangle]
 pub fn host_sload() {
-    let k = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-    let mut v = [0; U256_BYTES_COUNT as usize];
-    LowLevelSDK::statedb_update_storage(&k, &mut v);
+    let index = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
 
-    stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_from_be_slice(&v));
+    let address = ExecutionContext::DEFAULT.contract_address();
+    let (value, is_cold) = JzktAccountManager.storage(address, U256::from_be_bytes(index), false);
+    charge_sload(is_cold);
+
+    stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, value.to_be_bytes());
 }
@@ -1,10 +1,36 @@
-use crate::common_sp::{stack_pop_u256, SP_BASE_MEM_OFFSET_DEFAULT};
-use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
+use crate::{
+    common_sp::{stack_pop_u256, SP_BASE_MEM_OFFSET_DEFAULT},
+    host::{charge_sstore, sstore_refund},
+};
+use fluentbase_core::ExitCode;
+use fluentbase_sdk::{
+    AccountManager,
+    ContextReader,
+    ExecutionContext,
+    JzktAccountManager,
+    LowLevelAPI,
+    LowLevelSDK,
+};
+use fluentbase_types::U256;
 
 #[no_mangle]
 pub fn host_sstore() {
-    let k = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
-    let v = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
+    let index = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
+    let value = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
 
-    LowLevelSDK::statedb_update_storage(&k, &v);
+    let ctx = ExecutionContext::DEFAULT;
+    if ctx.contract_is_static() {
+        LowLevelSDK::sys_halt(ExitCode::WriteProtection.into_i32());
+        return;
+    }
+    let address = ctx.contract_address();
+    let index = U256::from_be_bytes(index);
+    let value = U256::from_be_bytes(value);
+    let am = JzktAccountManager;
+    let (original_value, _) = am.storage(address, index, true);
+    let (present_value, is_cold) = am.storage(address, index, false);
+    charge_sstore(original_value, present_value, value, is_cold);
+    am.record_refund(sstore_refund(original_value, present_value, value));
+
+    am.write_storage(address, index, value);
 }
@@ -1,8 +1,8 @@
 use crate::{
-    common::{u256_be_to_u64tuple_le, u256_from_be_slice},
-    common_sp::{stack_pop_u256, stack_push_u256, SP_BASE_MEM_OFFSET_DEFAULT},
+    common::u256_be_to_u64tuple_le,
+    common_sp::{stack_pop_u256, stack_push_u256, u256_zero, SP_BASE_MEM_OFFSET_DEFAULT},
 };
-use fluentbase_sdk::evm::ExecutionContext;
+use fluentbase_sdk::{ContextReader, ExecutionContext};
 
 #[no_mangle]
 pub fn host_env_blobhash() {
@@ -10,13 +10,10 @@ pub fn host_env_blobhash() {
 
     let idx = u256_be_to_u64tuple_le(idx);
 
-    stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_from_be_slice(&[]));
-
-    // let hashes = ExecutionContext::tx_blob_hashes();
-    // if idx.1 > 0 || idx.2 > 0 || idx.3 > 0 || idx.0 >= hashes.len() as u64 {
-    //     stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_from_be_slice(&[]));
-    //     return;
-    // }
-    // let hash = hashes[idx.0 as usize].0;
-    // stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, hash);
+    let hashes = ExecutionContext::DEFAULT.tx_blob_hashes();
+    if idx.1 > 0 || idx.2 > 0 || idx.3 > 0 || idx.0 >= hashes.len() as u64 {
+        stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_zero());
+        return;
+    }
+    stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, hashes[idx.0 as usize].0);
 }
@@ -18,3 +18,7 @@ mod codesize;
 mod gas;
 #[cfg(feature = "system_keccak256")]
 mod keccak256;
+#[cfg(feature = "system_returndatacopy")]
+mod returndatacopy;
+#[cfg(feature = "system_returndatasize")]
+mod returndatasize;
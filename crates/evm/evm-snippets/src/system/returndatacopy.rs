@@ -0,0 +1,31 @@
+use crate::{
+    common::u256_be_to_u64tuple_le,
+    common_sp::{stack_pop_u256, SP_BASE_MEM_OFFSET_DEFAULT},
+};
+use fluentbase_core::ExitCode;
+use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
+
+#[no_mangle]
+fn system_returndatacopy() {
+    let dest_offset = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
+    let offset = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
+    let size = stack_pop_u256(SP_BASE_MEM_OFFSET_DEFAULT);
+
+    let dest_offset = u256_be_to_u64tuple_le(dest_offset).0;
+    let offset = u256_be_to_u64tuple_le(offset);
+    let size = u256_be_to_u64tuple_le(size);
+
+    // unlike CALLDATACOPY reading past the end of the return data is an exceptional halt (even
+    // for the empty copy)
+    let return_data_size = LowLevelSDK::sys_output_size() as u64;
+    let is_u64_overflow = (offset.1 | offset.2 | offset.3 | size.1 | size.2 | size.3) > 0;
+    if is_u64_overflow || offset.0.saturating_add(size.0) > return_data_size {
+        LowLevelSDK::sys_halt(ExitCode::OutputOverflow.into_i32());
+        return;
+    }
+    if size.0 == 0 {
+        return;
+    }
+
+    LowLevelSDK::sys_read_output(dest_offset as *mut u8, offset.0 as u32, size.0 as u32);
+}
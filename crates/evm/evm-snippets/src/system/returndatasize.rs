@@ -0,0 +1,12 @@
+use crate::{
+    common::u256_from_be_slice,
+    common_sp::{stack_push_u256, SP_BASE_MEM_OFFSET_DEFAULT},
+};
+use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
+
+#[no_mangle]
+fn system_returndatasize() {
+    let v = LowLevelSDK::sys_output_size().to_be_bytes();
+
+    stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_from_be_slice(&v));
+}
@@ -717,4 +717,112 @@ mod all_tests {
             assert_eq!(r, res);
         }
     }
+    #[test]
+    fn test_access_gas_parity_with_revm() {
+        use crate::{
+            consts::WARM_STORAGE_READ_COST,
+            host::{account_access_surcharge, gas_to_fuel, sload_surcharge},
+        };
+        use fluentbase_codec::Encoder;
+        use fluentbase_revm::{
+            gas::{sload_cost, warm_cold_cost},
+            primitives::CancunSpec,
+        };
+        use fluentbase_sdk::{AccountManager, ContractInput, JzktAccountManager};
+        use fluentbase_types::{address, FuelConversion, FuelRounding, U256};
+
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        LowLevelSDK::with_test_fuel_conversion(FuelConversion::new(3, FuelRounding::Up));
+        let am = JzktAccountManager;
+        // the translator charges the warm cost and snippets charge the cold surcharge
+        let accounts = [
+            (
+                "BALANCE",
+                address!("1000000000000000000000000000000000000001"),
+            ),
+            (
+                "EXTCODESIZE",
+                address!("1000000000000000000000000000000000000002"),
+            ),
+        ];
+        for (opcode, address) in accounts {
+            for expected_cold in [true, false] {
+                let is_cold = am.access_account(address);
+                assert_eq!(is_cold, expected_cold, "{}", opcode);
+                assert_eq!(
+                    WARM_STORAGE_READ_COST + account_access_surcharge(is_cold),
+                    warm_cold_cost::<CancunSpec>(is_cold, 0),
+                    "{} (cold={})",
+                    opcode,
+                    is_cold
+                );
+            }
+        }
+        for expected_cold in [true, false] {
+            let (_, is_cold) = am.storage(accounts[0].1, U256::from(1), false);
+            assert_eq!(is_cold, expected_cold);
+            assert_eq!(
+                WARM_STORAGE_READ_COST + sload_surcharge(is_cold),
+                sload_cost::<CancunSpec>(is_cold),
+                "SLOAD (cold={})",
+                is_cold
+            );
+        }
+        // surcharges are charged in fuel using the host's conversion policy
+        assert_eq!(gas_to_fuel(account_access_surcharge(true)), 3 * 2500);
+        assert_eq!(gas_to_fuel(sload_surcharge(true)), 3 * 2000);
+        assert_eq!(gas_to_fuel(sload_surcharge(false)), 0);
+    }
+
+    #[test]
+    fn test_sstore_gas_parity_with_revm() {
+        use crate::host::sstore_cost;
+        use fluentbase_revm::{gas, primitives::CancunSpec};
+        use fluentbase_types::U256;
+
+        // SSTORE is fully charged by the snippet, because its cost depends on the slot values
+        let values = [U256::ZERO, U256::from(1), U256::from(2)];
+        for original in values {
+            for current in values {
+                for new in values {
+                    for is_cold in [true, false] {
+                        assert_eq!(
+                            Some(sstore_cost(original, current, new, is_cold)),
+                            gas::sstore_cost::<CancunSpec>(
+                                original, current, new, 1_000_000, is_cold
+                            ),
+                            "original={} current={} new={} cold={}",
+                            original,
+                            current,
+                            new,
+                            is_cold
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sstore_refund_parity_with_revm() {
+        use crate::host::sstore_refund;
+        use fluentbase_revm::{gas, primitives::CancunSpec};
+        use fluentbase_types::U256;
+
+        let values = [U256::ZERO, U256::from(1), U256::from(2)];
+        for original in values {
+            for current in values {
+                for new in values {
+                    assert_eq!(
+                        sstore_refund(original, current, new),
+                        gas::sstore_refund::<CancunSpec>(original, current, new),
+                        "original={} current={} new={}",
+                        original,
+                        current,
+                        new
+                    );
+                }
+            }
+        }
+    }
 }
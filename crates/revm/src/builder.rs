@@ -17,6 +17,8 @@ use crate::{
     Handler,
 };
 use core::marker::PhantomData;
+use fluentbase_runtime::EvmTranslator;
+use fluentbase_types::FuelConversion;
 use std::boxed::Box;
 
 /// Evm Builder allows building or modifying EVM.
@@ -422,6 +424,24 @@ impl<'a, BuilderStage, EXT, DB: Database> EvmBuilder<'a, BuilderStage, EXT, DB>
         self
     }
 
+    /// Sets conversion between gas and fuel used for the rWASM execution.
+    pub fn with_fuel_conversion(mut self, fuel_conversion: FuelConversion) -> Self {
+        self.context.evm.fuel_conversion = fuel_conversion;
+        self
+    }
+
+    /// Sets translator of EVM bytecode into rWASM used for the ahead-of-time translation.
+    pub fn with_evm_translator(mut self, evm_translator: EvmTranslator) -> Self {
+        self.context.evm.evm_translator = Some(evm_translator);
+        self
+    }
+
+    /// Enables reporting of the opcodes executed by the loader to the inspector's `step`.
+    pub fn with_step_tracing(mut self, step_tracing: bool) -> Self {
+        self.context.evm.step_tracing = step_tracing;
+        self
+    }
+
     /// Clears Environment of EVM.
     pub fn with_clear_env(mut self) -> Self {
         self.context.evm.env.clear();
@@ -234,6 +234,7 @@ pub(crate) mod test_utils {
         primitives::{address, AccountInfo, Address, Bytes, Env, HashSet, SpecId, B256, U256},
         InnerEvmContext,
     };
+    use fluentbase_types::FuelConversion;
     use std::boxed::Box;
 
     /// Mock caller address.
@@ -295,6 +296,9 @@ pub(crate) mod test_utils {
                 journaled_state: JournaledState::new(SpecId::CANCUN, HashSet::new()),
                 db,
                 error: Ok(()),
+                fuel_conversion: FuelConversion::DEFAULT,
+                evm_translator: None,
+                step_tracing: false,
                 #[cfg(feature = "optimism")]
                 l1_block_info: None,
             },
@@ -310,6 +314,9 @@ pub(crate) mod test_utils {
                 journaled_state: JournaledState::new(SpecId::CANCUN, HashSet::new()),
                 db,
                 error: Ok(()),
+                fuel_conversion: FuelConversion::DEFAULT,
+                evm_translator: None,
+                step_tracing: false,
                 #[cfg(feature = "optimism")]
                 l1_block_info: None,
             },
@@ -33,8 +33,9 @@ use crate::{
     CALL_STACK_LIMIT,
 };
 use fluentbase_core::debug_log;
+use fluentbase_runtime::EvmTranslator;
 use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
-use fluentbase_types::ExitCode;
+use fluentbase_types::{ExitCode, FuelConversion};
 use revm_primitives::MAX_CODE_SIZE;
 use std::boxed::Box;
 
@@ -50,6 +51,15 @@ pub struct InnerEvmContext<DB: Database> {
     pub db: DB,
     /// Error that happened during execution.
     pub error: Result<(), EVMError<ExitCode>>,
+    /// Conversion between gas and fuel used for the rWASM execution. It lives here and not in
+    /// `CfgEnv` because config environment is defined by revm primitives.
+    pub fuel_conversion: FuelConversion,
+    /// Translator of EVM bytecode into rWASM, if it's set then EVM contracts are translated
+    /// ahead-of-time on deployment (requires ECL built with `evm_aot` feature).
+    pub evm_translator: Option<EvmTranslator>,
+    /// Reports every opcode executed by the loader to the inspector's `step` (EIP-3155 tracing).
+    /// It's disabled by default, because the stack is copied for every opcode.
+    pub step_tracing: bool,
     /// Used as temporary value holder to store L1 block info.
     #[cfg(feature = "optimism")]
     pub l1_block_info: Option<crate::optimism::L1BlockInfo>,
@@ -62,6 +72,9 @@ impl<DB: Database + Clone> Clone for InnerEvmContext<DB> {
             journaled_state: self.journaled_state.clone(),
             db: self.db.clone(),
             error: self.error.clone(),
+            fuel_conversion: self.fuel_conversion,
+            evm_translator: self.evm_translator,
+            step_tracing: self.step_tracing,
             #[cfg(feature = "optimism")]
             l1_block_info: self.l1_block_info.clone(),
         }
@@ -75,6 +88,9 @@ impl<DB: Database> InnerEvmContext<DB> {
             journaled_state: JournaledState::new(SpecId::LATEST, HashSet::new()),
             db,
             error: Ok(()),
+            fuel_conversion: FuelConversion::DEFAULT,
+            evm_translator: None,
+            step_tracing: false,
             #[cfg(feature = "optimism")]
             l1_block_info: None,
         }
@@ -88,6 +104,9 @@ impl<DB: Database> InnerEvmContext<DB> {
             journaled_state: JournaledState::new(SpecId::LATEST, HashSet::new()),
             db,
             error: Ok(()),
+            fuel_conversion: FuelConversion::DEFAULT,
+            evm_translator: None,
+            step_tracing: false,
             #[cfg(feature = "optimism")]
             l1_block_info: None,
         }
@@ -103,6 +122,9 @@ impl<DB: Database> InnerEvmContext<DB> {
             journaled_state: self.journaled_state,
             db,
             error: Ok(()),
+            fuel_conversion: self.fuel_conversion,
+            evm_translator: self.evm_translator,
+            step_tracing: self.step_tracing,
             #[cfg(feature = "optimism")]
             l1_block_info: self.l1_block_info,
         }
@@ -5,6 +5,8 @@ pub mod emptydb;
 pub mod ethersdb;
 pub mod in_memory_db;
 pub mod states;
+#[cfg(feature = "std")]
+pub mod zktrie;
 
 pub use crate::primitives::db::*;
 pub use emptydb::{EmptyDB, EmptyDBTyped};
@@ -28,3 +30,5 @@ pub use states::{
     TransitionAccount,
     TransitionState,
 };
+#[cfg(feature = "std")]
+pub use zktrie::ZkTrieState;
@@ -0,0 +1,140 @@
+//! Fluent zktrie state commitment for the revm state changes.
+
+use crate::{
+    db::states::{BundleAccount, BundleState},
+    primitives::{Address, Bytecode, HashMap, B256, U256},
+};
+use fluentbase_runtime::{types::TrieDb, zktrie::ZkTrieStateDb, TrieStorage};
+use fluentbase_sdk::{
+    calc_storage_key,
+    Account,
+    JZKT_ACCOUNT_COMPRESSION_FLAGS,
+    JZKT_STORAGE_COMPRESSION_FLAGS,
+};
+use fluentbase_types::{ExitCode, BLOCK_HASH_HISTORY_ADDRESS, BLOCK_HASH_HISTORY_SIZE};
+
+/// Fluent state trie updated with the changes produced by revm.
+///
+/// Accounts are stored by the padded address using `JZKT_ACCOUNT_*_FIELD` layout and storage slots
+/// by the key from `calc_storage_key`, so the root matches the root that `JournaledTrie` computes
+/// for the same state. Storage of the destroyed account can't be enumerated in the trie, so only
+/// slots present in the bundle are cleared.
+///
+/// revm's journal returns zero root from `IJournaledTrie::compute_root`, because its database
+/// doesn't keep the trie, so it's the only way to get the state root for the revm path.
+pub struct ZkTrieState<DB: TrieDb> {
+    trie: ZkTrieStateDb<DB>,
+}
+
+impl<DB: TrieDb> ZkTrieState<DB> {
+    pub fn new(trie: ZkTrieStateDb<DB>) -> Self {
+        Self { trie }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.trie.compute_root()
+    }
+
+    /// Returns merkle proof of the account or the storage key for the current state root, the
+    /// journal used by revm can't build proofs, because it doesn't keep the trie.
+    pub fn proof(&self, key: &[u8; 32]) -> Option<Vec<Vec<u8>>> {
+        self.trie.proof(key)
+    }
+
+    pub fn into_inner(self) -> ZkTrieStateDb<DB> {
+        self.trie
+    }
+
+    /// Stores hash of the parent block in the block hash history (EIP-2935), like
+    /// `JournaledTrie::update_block_hash` does, and applies state changes of the block, so
+    /// `BLOCKHASH` executed by the next blocks can serve it. Returns the new state root.
+    pub fn apply_block(
+        &mut self,
+        parent_number: u64,
+        parent_hash: B256,
+        bundle: &BundleState,
+    ) -> Result<[u8; 32], ExitCode> {
+        let slot = U256::from(parent_number % BLOCK_HASH_HISTORY_SIZE);
+        let storage_key =
+            calc_storage_key(&BLOCK_HASH_HISTORY_ADDRESS, slot.as_le_slice().as_ptr());
+        // block hash doesn't fit into the field, so it must be compressed
+        self.trie.update(&storage_key, 1, &vec![parent_hash.0])?;
+        self.apply_bundle(bundle)
+    }
+
+    /// Applies state changes of the block and returns the new state root.
+    pub fn apply_bundle(&mut self, bundle: &BundleState) -> Result<[u8; 32], ExitCode> {
+        for (hash, bytecode) in bundle.contracts.iter() {
+            let bytecode = bytecode.original_bytes();
+            if !bytecode.is_empty() {
+                self.trie.update_preimage(&hash.0, bytecode);
+            }
+        }
+        for (address, account) in bundle.state.iter() {
+            self.apply_account(*address, account, &bundle.contracts)?;
+        }
+        Ok(self.root())
+    }
+
+    fn apply_account(
+        &mut self,
+        address: Address,
+        account: &BundleAccount,
+        contracts: &HashMap<B256, Bytecode>,
+    ) -> Result<(), ExitCode> {
+        let is_destroyed = account.status.was_destroyed();
+        for (slot, value) in account.storage.iter() {
+            if !value.is_changed() && !is_destroyed {
+                continue;
+            }
+            let storage_key = calc_storage_key(&address, slot.as_le_slice().as_ptr());
+            self.trie.update(
+                &storage_key,
+                JZKT_STORAGE_COMPRESSION_FLAGS,
+                &vec![value.present_value.to_le_bytes::<32>()],
+            )?;
+        }
+        let account_key = address.into_word().0;
+        let info = match account.info.as_ref() {
+            Some(info) => info,
+            None => return self.trie.remove(&account_key),
+        };
+        let prev_account = self
+            .trie
+            .get(&account_key)
+            .map(|(fields, _)| Account::new_from_fields(address, &fields));
+        let mut jzkt_account = Account::from(info.clone());
+        jzkt_account.address = address;
+        // bundle doesn't keep bytecode of the unchanged contracts, so code sizes are taken from the
+        // created contracts or the previous state of the account
+        if info.code.is_none() {
+            jzkt_account.source_code_size = contracts
+                .get(&info.code_hash)
+                .map(|code| code.len() as u64)
+                .or_else(|| {
+                    prev_account
+                        .as_ref()
+                        .filter(|prev| prev.source_code_hash == info.code_hash)
+                        .map(|prev| prev.source_code_size)
+                })
+                .unwrap_or_default();
+        }
+        if info.rwasm_code.is_none() {
+            jzkt_account.rwasm_code_size = contracts
+                .get(&info.rwasm_code_hash)
+                .map(|code| code.len() as u64)
+                .or_else(|| {
+                    prev_account
+                        .as_ref()
+                        .filter(|prev| prev.rwasm_code_hash == info.rwasm_code_hash)
+                        .map(|prev| prev.rwasm_code_size)
+                })
+                .unwrap_or_default();
+        }
+        self.trie.update(
+            &account_key,
+            JZKT_ACCOUNT_COMPRESSION_FLAGS,
+            &jzkt_account.get_fields().to_vec(),
+        )
+    }
+}
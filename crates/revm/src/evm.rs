@@ -3,7 +3,20 @@ use crate::{
     db::{Database, DatabaseCommit, EmptyDB},
     gas::Gas,
     handler::Handler,
-    interpreter::{CallOutcome, CreateOutcome, InstructionResult, InterpreterResult},
+    interpreter::{
+        CallContext,
+        CallInputs,
+        CallOutcome,
+        CallScheme,
+        CreateInputs,
+        CreateOutcome,
+        InstructionResult,
+        Interpreter,
+        InterpreterResult,
+        SharedMemory,
+        Stack,
+        Transfer,
+    },
     primitives::{
         specification::SpecId,
         Address,
@@ -24,11 +37,13 @@ use crate::{
     Context,
     ContextWithHandlerCfg,
     EvmContext,
+    FrameOrResult,
     FrameResult,
+    Inspector,
     JournalCheckpoint,
     JournalEntry,
 };
-use core::{cell::RefCell, fmt, fmt::Debug, str::from_utf8};
+use core::{cell::RefCell, fmt, fmt::Debug, mem, str::from_utf8};
 use fluentbase_codec::{BufferDecoder, Encoder};
 use fluentbase_core::{
     consts::{ECL_CONTRACT_ADDRESS, WCL_CONTRACT_ADDRESS},
@@ -46,8 +61,10 @@ use fluentbase_sdk::{
     CoreInput,
     EvmCallMethodInput,
     EvmCallMethodOutput,
+    EvmCallScheme,
     EvmCreateMethodInput,
     EvmCreateMethodOutput,
+    EvmStepTrace,
     LowLevelAPI,
     LowLevelSDK,
     WasmCallMethodInput,
@@ -69,6 +86,7 @@ use fluentbase_types::{
     Bytes,
     Bytes32,
     ExitCode,
+    FuelConversion,
     IJournaledTrie,
     JournalEvent,
     JournalLog,
@@ -77,7 +95,7 @@ use fluentbase_types::{
     POSEIDON_EMPTY,
     STATE_MAIN,
 };
-use revm_primitives::{hex, Bytecode, CreateScheme, Env, Log, LogData};
+use revm_primitives::{hex, Bytecode, BytecodeState, CreateScheme, Env, Log, LogData};
 use std as alloc;
 use std::vec::Vec;
 
@@ -295,26 +313,22 @@ impl<EXT, DB: Database> Evm<'_, EXT, DB> {
         evm_storage.info.nonce = 1;
         ctx.evm.touch(&EVM_STORAGE_ADDRESS);
 
-        // call inner handling of call/create
-        let mut frame_result = match ctx.evm.env.tx.transact_to {
-            TransactTo::Call(address) => {
-                let value = ctx.evm.env.tx.value;
-                let caller = ctx.evm.env.tx.caller;
-                let data = ctx.evm.env.tx.data.clone();
-                let result = self.call_inner(caller, address, value, data, gas_limit);
-                FrameResult::Call(result)
-            }
-            TransactTo::Create(scheme) => {
-                let salt = match scheme {
-                    CreateScheme::Create2 { salt } => Some(salt),
-                    CreateScheme::Create => None,
-                };
-                let value = ctx.evm.env.tx.value;
-                let caller = ctx.evm.env.tx.caller;
-                let data = ctx.evm.env.tx.data.clone();
-                let result = self.create_inner(caller, value, data, gas_limit, salt);
-                FrameResult::Create(result)
-            }
+        // call inner handling of call/create (all nested frames are executed by the loader, so
+        // we always get a result here)
+        let exec = self.handler.execution();
+        let first_frame_or_result = match ctx.evm.env.tx.transact_to {
+            TransactTo::Call(_) => exec.call(
+                ctx,
+                CallInputs::new_boxed(&ctx.evm.env.tx, gas_limit).unwrap(),
+            )?,
+            TransactTo::Create(_) => exec.create(
+                ctx,
+                CreateInputs::new_boxed(&ctx.evm.env.tx, gas_limit).unwrap(),
+            )?,
+        };
+        let mut frame_result = match first_frame_or_result {
+            FrameOrResult::Result(frame_result) => frame_result,
+            FrameOrResult::Frame(_) => unreachable!("interpreter frames are not supported"),
         };
 
         let ctx = &mut self.context;
@@ -333,237 +347,6 @@ impl<EXT, DB: Database> Evm<'_, EXT, DB> {
         post_exec.output(ctx, frame_result)
     }
 
-    /// EVM create opcode for both initial crate and CREATE and CREATE2 opcodes.
-    fn create_inner(
-        &mut self,
-        caller_address: Address,
-        value: U256,
-        input: Bytes,
-        gas_limit: u64,
-        salt: Option<U256>,
-    ) -> CreateOutcome {
-        let return_result = |instruction_result: ExitCode, gas: Gas| CreateOutcome {
-            result: InterpreterResult {
-                result: instruction_result,
-                output: Default::default(),
-                gas,
-            },
-            address: None,
-        };
-
-        let mut gas = Gas::new(gas_limit);
-
-        if self.context.evm.journaled_state.depth as u64 > CALL_STACK_LIMIT {
-            return return_result(ExitCode::CallDepthOverflow, gas);
-        }
-
-        let (caller_account, _) = self
-            .context
-            .evm
-            .load_account(caller_address)
-            .expect("external database error");
-        if caller_account.info.balance < value {
-            return return_result(ExitCode::InsufficientBalance, gas);
-        }
-
-        let method_data = EvmCreateMethodInput {
-            bytecode: input,
-            value,
-            gas_limit: gas.remaining(),
-            salt,
-            depth: 0,
-        };
-
-        let contract_input = self.input_from_env(
-            &mut gas,
-            caller_address,
-            Address::ZERO,
-            Default::default(),
-            value,
-        );
-        let am = JournalDbWrapper {
-            ctx: RefCell::new(&mut self.context.evm),
-        };
-        let create_output = _loader_create(&contract_input, &am, method_data);
-
-        // let (output_buffer, exit_code) = self.exec_rwasm_binary(
-        //     &mut gas,
-        //     caller_account,
-        //     &mut middleware_account,
-        //     None,
-        //     core_input.into(),
-        //     value,
-        // );
-
-        // let create_output = if exit_code == ExitCode::Ok {
-        //     let mut buffer_decoder = BufferDecoder::new(output_buffer.as_ref());
-        //     let mut create_output = EvmCreateMethodOutput::default();
-        //     EvmCreateMethodOutput::decode_body(&mut buffer_decoder, 0, &mut create_output);
-        //     create_output
-        // } else {
-        //     EvmCreateMethodOutput::from_exit_code(exit_code).with_gas(gas.remaining())
-        // };
-
-        // let created_address = if exit_code == ExitCode::Ok {
-        //     if output_buffer.len() != 20 {
-        //         return return_result(ExitCode::CreateError, gas);
-        //     }
-        //     assert_eq!(
-        //         output_buffer.len(),
-        //         20,
-        //         "output buffer is not 20 bytes after create/create2"
-        //     );
-        //     Some(Address::from_slice(output_buffer.as_ref()))
-        // } else {
-        //     None
-        // };
-
-        let mut gas = Gas::new(create_output.gas);
-        gas.record_refund(create_output.gas_refund);
-
-        CreateOutcome {
-            result: InterpreterResult {
-                result: ExitCode::from(create_output.exit_code),
-                output: Bytes::new(),
-                gas,
-            },
-            address: create_output.address,
-        }
-    }
-
-    /// Main contract call of the EVM.
-    fn call_inner(
-        &mut self,
-        caller_address: Address,
-        callee_address: Address,
-        value: U256,
-        input: Bytes,
-        gas_limit: u64,
-    ) -> CallOutcome {
-        let mut gas = Gas::new(gas_limit);
-
-        // Touch address. For "EIP-158 State Clear", this will erase empty accounts.
-        if value == U256::ZERO {
-            self.context
-                .evm
-                .load_account(callee_address)
-                .expect("failed to load");
-            self.context.evm.journaled_state.touch(&callee_address);
-        }
-
-        let method_input = EvmCallMethodInput {
-            callee: callee_address,
-            value,
-            input,
-            gas_limit: gas.remaining(),
-            depth: 0,
-        };
-        let contract_input = self.input_from_env(
-            &mut gas,
-            caller_address,
-            callee_address,
-            Default::default(),
-            value,
-        );
-        let am = JournalDbWrapper {
-            ctx: RefCell::new(&mut self.context.evm),
-        };
-        let call_output = _loader_call(&contract_input, &am, method_input);
-
-        // let core_input = CoreInput {
-        //     method_id,
-        //     method_data,
-        // }
-        // .encode_to_vec(0);
-        //
-        // let (output_buffer, exit_code) = self.exec_rwasm_binary(
-        //     &mut gas,
-        //     caller_account,
-        //     &mut middleware_account,
-        //     Some(callee_account.address),
-        //     core_input.into(),
-        //     value,
-        // );
-        //
-        // let call_output = if exit_code == ExitCode::Ok {
-        //     let mut buffer_decoder = BufferDecoder::new(output_buffer.as_ref());
-        //     let mut call_output = EvmCallMethodOutput::default();
-        //     EvmCallMethodOutput::decode_body(&mut buffer_decoder, 0, &mut call_output);
-        //     call_output
-        // } else {
-        //     EvmCallMethodOutput::from_exit_code(exit_code).with_gas(gas.remaining())
-        // };
-
-        {
-            println!("executed ECL call:");
-            println!(" - caller: 0x{}", hex::encode(caller_address));
-            println!(" - callee: 0x{}", hex::encode(callee_address));
-            println!(" - value: 0x{}", hex::encode(&value.to_be_bytes::<32>()));
-            println!(
-                " - fuel consumed: {}",
-                gas.remaining() as i64 - call_output.gas_remaining as i64
-            );
-            println!(" - exit code: {}", call_output.exit_code);
-            if call_output.output.iter().all(|c| c.is_ascii()) {
-                println!(
-                    " - output message: {}",
-                    from_utf8(&call_output.output).unwrap()
-                );
-            } else {
-                println!(
-                    " - output message: {}",
-                    format!("0x{}", hex::encode(&call_output.output))
-                );
-            }
-        }
-
-        let mut gas = Gas::new(call_output.gas_remaining);
-        gas.record_refund(call_output.gas_refund);
-
-        CallOutcome {
-            result: InterpreterResult {
-                result: ExitCode::from(call_output.exit_code),
-                output: call_output.output,
-                gas,
-            },
-            memory_offset: Default::default(),
-        }
-    }
-
-    fn input_from_env(
-        &self,
-        gas: &Gas,
-        caller_address: Address,
-        callee_address: Address,
-        input: Bytes,
-        value: U256,
-    ) -> ContractInput {
-        ContractInput {
-            journal_checkpoint: 0,
-            contract_gas_limit: gas.remaining(),
-            contract_address: callee_address,
-            contract_caller: caller_address,
-            contract_input: input,
-            contract_value: value,
-            contract_is_static: false,
-            block_chain_id: self.context.evm.env.cfg.chain_id,
-            block_coinbase: self.context.evm.env.block.coinbase,
-            block_timestamp: self.context.evm.env.block.timestamp.as_limbs()[0],
-            block_number: self.context.evm.env.block.number.as_limbs()[0],
-            block_difficulty: self.context.evm.env.block.difficulty.as_limbs()[0],
-            block_gas_limit: self.context.evm.env.block.gas_limit.as_limbs()[0],
-            block_base_fee: self.context.evm.env.block.basefee,
-            tx_gas_limit: self.context.evm.env.tx.gas_limit,
-            tx_nonce: self.context.evm.env.tx.nonce.unwrap_or_default(),
-            tx_gas_price: self.context.evm.env.tx.gas_price,
-            tx_gas_priority_fee: self.context.evm.env.tx.gas_priority_fee,
-            tx_caller: self.context.evm.env.tx.caller,
-            tx_access_list: self.context.evm.env.tx.access_list.clone(),
-            tx_blob_hashes: self.context.evm.env.tx.blob_hashes.clone(),
-            tx_max_fee_per_blob_gas: self.context.evm.env.tx.max_fee_per_blob_gas,
-        }
-    }
-
     // #[cfg(feature = "std")]
     // fn exec_rwasm_binary(
     //     &mut self,
@@ -623,18 +406,19 @@ impl<EXT, DB: Database> Evm<'_, EXT, DB> {
             .input_from_env(checkpoint, gas, caller, callee, input, value)
             .encode_to_vec(0);
 
-        let mut gas_limit_ref = gas.remaining() as u32;
-        let gas_limit_ref = &mut gas_limit_ref as *mut u32;
+        let fuel_conversion = self.context.evm.fuel_conversion;
+        let fuel_limit = fuel_conversion.gas_to_fuel(gas.remaining());
+        let mut fuel_remaining = fuel_limit;
         let exit_code = LowLevelSDK::sys_exec_hash(
             callee.rwasm_code_hash.as_ptr(),
             input.as_ptr(),
             input.len() as u32,
             core::ptr::null_mut(),
             0,
-            gas_limit_ref,
+            &mut fuel_remaining as *mut u64,
             state,
         );
-        let gas_used = gas.remaining() - unsafe { *gas_limit_ref } as u64;
+        let gas_used = fuel_conversion.fuel_to_gas(fuel_limit - fuel_remaining);
         gas.record_cost(gas_used);
 
         let output_size = LowLevelSDK::sys_output_size();
@@ -650,13 +434,313 @@ impl<EXT, DB: Database> Evm<'_, EXT, DB> {
     }
 }
 
-struct JournalDbWrapper<'a, DB: Database> {
-    ctx: RefCell<&'a mut EvmContext<DB>>,
+/// EVM create opcode for both initial crate and CREATE and CREATE2 opcodes.
+///
+/// If inspector is passed then it receives events for every frame executed by the loader.
+pub(crate) fn loader_create<'a, DB: Database>(
+    ctx: &'a mut EvmContext<DB>,
+    inspector: Option<&'a mut dyn Inspector<DB>>,
+    inputs: &CreateInputs,
+) -> CreateOutcome {
+    let return_result = |instruction_result: ExitCode, gas: Gas| CreateOutcome {
+        result: InterpreterResult {
+            result: instruction_result,
+            output: Default::default(),
+            gas,
+        },
+        address: None,
+    };
+
+    let mut gas = Gas::new(inputs.gas_limit);
+
+    if ctx.journaled_state.depth as u64 > CALL_STACK_LIMIT {
+        return return_result(ExitCode::CallDepthOverflow, gas);
+    }
+
+    let (caller_account, _) = ctx
+        .load_account(inputs.caller)
+        .expect("external database error");
+    if caller_account.info.balance < inputs.value {
+        return return_result(ExitCode::InsufficientBalance, gas);
+    }
+
+    let method_data = EvmCreateMethodInput {
+        bytecode: inputs.init_code.clone(),
+        value: inputs.value,
+        gas_limit: gas.remaining(),
+        salt: match inputs.scheme {
+            CreateScheme::Create2 { salt } => Some(salt),
+            CreateScheme::Create => None,
+        },
+        depth: 0,
+    };
+
+    let contract_input = input_from_env(
+        ctx,
+        &mut gas,
+        inputs.caller,
+        Address::ZERO,
+        Default::default(),
+        inputs.value,
+    );
+    let am = JournalDbWrapper {
+        ctx: RefCell::new(ctx),
+        inspector: inspector.map(RefCell::new),
+    };
+    let create_output = _loader_create(&contract_input, &am, method_data);
+
+    let mut gas = Gas::new(create_output.gas);
+    gas.record_refund(create_output.gas_refund);
+
+    CreateOutcome {
+        result: InterpreterResult {
+            result: ExitCode::from(create_output.exit_code),
+            output: Bytes::new(),
+            gas,
+        },
+        address: create_output.address,
+    }
+}
+
+/// Main contract call of the EVM.
+///
+/// If inspector is passed then it receives events for every frame executed by the loader.
+pub(crate) fn loader_call<'a, DB: Database>(
+    ctx: &'a mut EvmContext<DB>,
+    inspector: Option<&'a mut dyn Inspector<DB>>,
+    inputs: &CallInputs,
+) -> CallOutcome {
+    let mut gas = Gas::new(inputs.gas_limit);
+    let (caller_address, callee_address, value) = (
+        inputs.context.caller,
+        inputs.contract,
+        inputs.transfer.value,
+    );
+
+    // Touch address. For "EIP-158 State Clear", this will erase empty accounts.
+    if value == U256::ZERO {
+        ctx.load_account(callee_address).expect("failed to load");
+        ctx.journaled_state.touch(&callee_address);
+    }
+
+    let method_input = EvmCallMethodInput {
+        callee: callee_address,
+        value,
+        input: inputs.input.clone(),
+        gas_limit: gas.remaining(),
+        depth: 0,
+        scheme: match inputs.context.scheme {
+            CallScheme::Call => EvmCallScheme::Call,
+            CallScheme::CallCode => EvmCallScheme::CallCode,
+            CallScheme::DelegateCall => EvmCallScheme::DelegateCall,
+            CallScheme::StaticCall => EvmCallScheme::StaticCall,
+        },
+    };
+    let contract_input = input_from_env(
+        ctx,
+        &mut gas,
+        caller_address,
+        callee_address,
+        Default::default(),
+        value,
+    );
+    let am = JournalDbWrapper {
+        ctx: RefCell::new(ctx),
+        inspector: inspector.map(RefCell::new),
+    };
+    let call_output = _loader_call(&contract_input, &am, method_input);
+
+    {
+        println!("executed ECL call:");
+        println!(" - caller: 0x{}", hex::encode(caller_address));
+        println!(" - callee: 0x{}", hex::encode(callee_address));
+        println!(" - value: 0x{}", hex::encode(&value.to_be_bytes::<32>()));
+        println!(
+            " - fuel consumed: {}",
+            gas.remaining() as i64 - call_output.gas_remaining as i64
+        );
+        println!(" - exit code: {}", call_output.exit_code);
+        if call_output.output.iter().all(|c| c.is_ascii()) {
+            println!(
+                " - output message: {}",
+                from_utf8(&call_output.output).unwrap()
+            );
+        } else {
+            println!(
+                " - output message: {}",
+                format!("0x{}", hex::encode(&call_output.output))
+            );
+        }
+    }
+
+    let mut gas = Gas::new(call_output.gas_remaining);
+    gas.record_refund(call_output.gas_refund);
+
+    CallOutcome {
+        result: InterpreterResult {
+            result: ExitCode::from(call_output.exit_code),
+            output: call_output.output,
+            gas,
+        },
+        memory_offset: inputs.return_memory_offset.clone(),
+    }
+}
+
+fn input_from_env<DB: Database>(
+    ctx: &EvmContext<DB>,
+    gas: &Gas,
+    caller_address: Address,
+    callee_address: Address,
+    input: Bytes,
+    value: U256,
+) -> ContractInput {
+    ContractInput {
+        journal_checkpoint: 0,
+        contract_gas_limit: gas.remaining(),
+        contract_address: callee_address,
+        contract_caller: caller_address,
+        contract_input: input,
+        contract_value: value,
+        contract_is_static: false,
+        block_chain_id: ctx.env.cfg.chain_id,
+        block_coinbase: ctx.env.block.coinbase,
+        block_timestamp: ctx.env.block.timestamp.as_limbs()[0],
+        block_number: ctx.env.block.number.as_limbs()[0],
+        block_difficulty: ctx.env.block.difficulty.as_limbs()[0],
+        block_gas_limit: ctx.env.block.gas_limit.as_limbs()[0],
+        block_base_fee: ctx.env.block.basefee,
+        block_blob_base_fee: U256::from(ctx.env.block.get_blob_gasprice().unwrap_or_default()),
+        tx_gas_limit: ctx.env.tx.gas_limit,
+        tx_nonce: ctx.env.tx.nonce.unwrap_or_default(),
+        tx_gas_price: ctx.env.tx.gas_price,
+        tx_gas_priority_fee: ctx.env.tx.gas_priority_fee,
+        tx_caller: ctx.env.tx.caller,
+        tx_access_list: ctx.env.tx.access_list.clone(),
+        tx_blob_hashes: ctx.env.tx.blob_hashes.clone(),
+        tx_max_fee_per_blob_gas: ctx.env.tx.max_fee_per_blob_gas,
+        contract_depth: 0,
+    }
+}
+
+/// Converts loader call input into inspector's [CallInputs].
+///
+/// Call scheme is taken from the loader's input, the execution address and the caller come from
+/// the parent frame.
+fn call_inputs_from_method_input(
+    caller: Address,
+    address: Address,
+    is_static: bool,
+    input: &EvmCallMethodInput,
+) -> CallInputs {
+    let scheme = match input.scheme {
+        EvmCallScheme::Call => CallScheme::Call,
+        EvmCallScheme::CallCode => CallScheme::CallCode,
+        EvmCallScheme::DelegateCall => CallScheme::DelegateCall,
+        EvmCallScheme::StaticCall => CallScheme::StaticCall,
+    };
+    CallInputs {
+        contract: input.callee,
+        transfer: Transfer {
+            source: caller,
+            target: address,
+            value: input.value,
+        },
+        input: input.input.clone(),
+        gas_limit: input.gas_limit,
+        context: CallContext {
+            address,
+            caller,
+            code_address: input.callee,
+            apparent_value: input.value,
+            scheme,
+        },
+        is_static,
+        return_memory_offset: 0..0,
+    }
+}
+
+fn create_inputs_from_method_input(caller: Address, input: &EvmCreateMethodInput) -> CreateInputs {
+    CreateInputs {
+        caller,
+        scheme: match input.salt {
+            Some(salt) => CreateScheme::Create2 { salt },
+            None => CreateScheme::Create,
+        },
+        value: input.value,
+        init_code: input.bytecode.clone(),
+        gas_limit: input.gas_limit,
+    }
+}
+
+fn call_outcome_from_method_output(output: &EvmCallMethodOutput) -> CallOutcome {
+    let mut gas = Gas::new(output.gas_remaining);
+    gas.record_refund(output.gas_refund);
+    CallOutcome {
+        result: InterpreterResult {
+            result: ExitCode::from(output.exit_code),
+            output: output.output.clone(),
+            gas,
+        },
+        memory_offset: 0..0,
+    }
 }
 
+fn call_method_output_from_outcome(outcome: CallOutcome) -> EvmCallMethodOutput {
+    EvmCallMethodOutput {
+        exit_code: outcome.result.result.into_i32(),
+        gas_remaining: outcome.result.gas.remaining(),
+        gas_refund: outcome.result.gas.refunded(),
+        output: outcome.result.output,
+    }
+}
+
+fn create_outcome_from_method_output(output: &EvmCreateMethodOutput) -> CreateOutcome {
+    let mut gas = Gas::new(output.gas);
+    gas.record_refund(output.gas_refund);
+    CreateOutcome {
+        result: InterpreterResult {
+            result: ExitCode::from(output.exit_code),
+            output: output.output.clone(),
+            gas,
+        },
+        address: output.address,
+    }
+}
+
+fn create_method_output_from_outcome(outcome: CreateOutcome) -> EvmCreateMethodOutput {
+    EvmCreateMethodOutput {
+        exit_code: outcome.result.result.into_i32(),
+        gas: outcome.result.gas.remaining(),
+        gas_refund: outcome.result.gas.refunded(),
+        output: outcome.result.output,
+        address: outcome.address,
+    }
+}
+
+pub(crate) struct JournalDbWrapper<'a, DB: Database> {
+    pub(crate) ctx: RefCell<&'a mut EvmContext<DB>>,
+    pub(crate) inspector: Option<RefCell<&'a mut dyn Inspector<DB>>>,
+}
+
+/// Max number of bytecodes in the code state that can be added by caching analysed bytecode, the
+/// code state survives transactions, so it must not grow with every called contract
+const MAX_ANALYSED_CODE_STATE_SIZE: usize = 4096;
+
 /// A special account for storing EVM storage trie `keccak256("evm_storage_trie")[12..32]`
 pub const EVM_STORAGE_ADDRESS: Address = address!("fabefeab43f96e51d7ace194b9abd33305bb6bfb");
 
+impl<'a, DB: Database> JournalDbWrapper<'a, DB> {
+    fn is_removed(&self, address: &Address) -> bool {
+        self.ctx
+            .borrow_mut()
+            .journaled_state
+            .state
+            .get(address)
+            .map(|account| account.is_selfdestructed())
+            .unwrap_or_default()
+    }
+}
+
 impl<'a, DB: Database> IJournaledTrie for JournalDbWrapper<'a, DB> {
     fn checkpoint(&self) -> fluentbase_types::JournalCheckpoint {
         fluentbase_types::JournalCheckpoint::from_u64(AccountManager::checkpoint(self))
@@ -667,6 +751,10 @@ impl<'a, DB: Database> IJournaledTrie for JournalDbWrapper<'a, DB> {
         if key[..12] == [0u8; 12] {
             let address = Address::from_slice(&key[12..]);
             let (account, is_cold) = AccountManager::account(self, address);
+            // removed accounts are destroyed on commit, so they're not visible anymore
+            if self.is_removed(&address) {
+                return None;
+            }
             Some((
                 account.get_fields().to_vec(),
                 JZKT_ACCOUNT_COMPRESSION_FLAGS,
@@ -689,6 +777,13 @@ impl<'a, DB: Database> IJournaledTrie for JournalDbWrapper<'a, DB> {
             let address = Address::from_slice(&key[12..]);
             let jzkt_account = Account::new_from_fields(address, value.as_slice());
             AccountManager::write_account(self, &jzkt_account);
+            // account written after the removal must be visible again and not destroyed on commit
+            if self.is_removed(&address) {
+                self.ctx
+                    .borrow_mut()
+                    .journaled_state
+                    .restore_account(address);
+            }
         } else if value.len() == JZKT_STORAGE_FIELDS_COUNT as usize {
             AccountManager::write_storage(
                 self,
@@ -701,15 +796,37 @@ impl<'a, DB: Database> IJournaledTrie for JournalDbWrapper<'a, DB> {
         }
     }
 
-    fn remove(&self, _key: &[u8; 32]) {
-        // TODO: "account removal is not supported"
+    fn remove(&self, key: &[u8; 32]) {
+        if key[..12] == [0u8; 12] {
+            let address = Address::from_slice(&key[12..]);
+            let mut ctx = self.ctx.borrow_mut();
+            ctx.load_account(address).expect("database error");
+            ctx.journaled_state.remove_account(address);
+        } else {
+            // zero storage slot is removed from the database on commit
+            AccountManager::write_storage(
+                self,
+                EVM_STORAGE_ADDRESS,
+                U256::from_le_bytes(*key),
+                U256::ZERO,
+            );
+        }
     }
 
     fn compute_root(&self) -> [u8; 32] {
-        // TODO: "root is not supported"
+        // revm database serves accounts and slots by the key only and doesn't keep the trie of
+        // the parent state, so the root can't be computed without rebuilding the whole trie.
+        // Instead, the root is computed once the block is finished by applying its bundle state
+        // with `ZkTrieState`
         [0u8; 32]
     }
 
+    fn proof(&self, _root: &[u8; 32], _key: &[u8; 32]) -> Option<Vec<Vec<u8>>> {
+        // revm database doesn't keep the trie, proofs can be built from `ZkTrieState` once the
+        // bundle state of the block is applied
+        None
+    }
+
     fn emit_log(&self, address: Address, topics: Vec<B256>, data: Bytes) {
         AccountManager::log(self, address, data, &topics);
     }
@@ -740,6 +857,21 @@ impl<'a, DB: Database> IJournaledTrie for JournalDbWrapper<'a, DB> {
         // TODO: "journal is not supported here"
         vec![]
     }
+
+    fn transient(&self, key: &[u8; 32]) -> [u8; 32] {
+        let index = U256::from_le_bytes(*key);
+        AccountManager::transient_storage(self, EVM_STORAGE_ADDRESS, index).to_le_bytes::<32>()
+    }
+
+    fn update_transient(&self, key: &[u8; 32], value: &[u8; 32]) {
+        let index = U256::from_le_bytes(*key);
+        let value = U256::from_le_bytes(*value);
+        AccountManager::write_transient_storage(self, EVM_STORAGE_ADDRESS, index, value);
+    }
+
+    fn clear_transient(&self) {
+        // transient storage is cleared by revm at the end of the transaction
+    }
 }
 
 impl<'a, DB: Database> AccountManager for JournalDbWrapper<'a, DB> {
@@ -862,17 +994,21 @@ impl<'a, DB: Database> AccountManager for JournalDbWrapper<'a, DB> {
 
     fn log(&self, address: Address, data: Bytes, topics: &[B256]) {
         let mut ctx = self.ctx.borrow_mut();
-        ctx.journaled_state.log(Log {
+        let log = Log {
             address,
             data: LogData::new_unchecked(topics.into(), data),
-        });
+        };
+        if let Some(inspector) = self.inspector.as_ref() {
+            inspector.borrow_mut().log(&mut ctx, &log);
+        }
+        ctx.journaled_state.log(log);
     }
 
     fn exec_hash(
         &self,
         hash32_offset: *const u8,
         input: &[u8],
-        fuel_offset: *mut u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> (Bytes, i32) {
         use fluentbase_runtime::{Runtime, RuntimeContext};
@@ -884,12 +1020,20 @@ impl<'a, DB: Database> AccountManager for JournalDbWrapper<'a, DB> {
             return (Bytes::default(), ExitCode::Ok.into_i32());
         }
         let mut ctx = self.ctx.borrow_mut();
+        let fuel_conversion = ctx.fuel_conversion;
+        let evm_translator = ctx.evm_translator;
+        let mut inspector = self.inspector.as_ref().map(|v| v.borrow_mut());
         let jzkt = JournalDbWrapper {
             ctx: RefCell::new(&mut ctx),
+            inspector: inspector
+                .as_mut()
+                .map(|v| RefCell::new(&mut ***v as &mut dyn Inspector<DB>)),
         };
         let ctx = RuntimeContext::new(rwasm_bytecode)
             .with_input(input.into())
-            .with_fuel_limit(unsafe { *fuel_offset } as u64)
+            .with_fuel_limit(unsafe { *fuel_offset })
+            .with_fuel_conversion(fuel_conversion)
+            .with_evm_translator(evm_translator)
             .with_jzkt(jzkt)
             .with_state(state);
         let mut runtime = Runtime::new(ctx);
@@ -902,7 +1046,7 @@ impl<'a, DB: Database> AccountManager for JournalDbWrapper<'a, DB> {
             }
         };
         unsafe {
-            *fuel_offset -= result.fuel_consumed as u32;
+            *fuel_offset -= result.fuel_consumed;
         }
         (Bytes::from(result.output.clone()), result.exit_code.into())
     }
@@ -953,9 +1097,16 @@ impl<'a, DB: Database> AccountManager for JournalDbWrapper<'a, DB> {
 
     fn self_destruct(&self, address: Address, target: Address) -> [bool; 4] {
         let mut ctx = self.ctx.borrow_mut();
+        let (account, _) = ctx.load_account(address).expect("unexpected EVM error");
+        let balance = account.info.balance;
         let result = ctx
             .selfdestruct(address, target)
             .expect("unexpected EVM self destruct error");
+        if let Some(inspector) = self.inspector.as_ref() {
+            inspector
+                .borrow_mut()
+                .selfdestruct(address, target, balance);
+        }
         [
             result.had_value,
             result.target_exists,
@@ -984,4 +1135,147 @@ impl<'a, DB: Database> AccountManager for JournalDbWrapper<'a, DB> {
         let (account, _) = ctx.load_account(address).expect("unexpected EVM error");
         account.mark_created();
     }
+
+    fn fuel_conversion(&self) -> FuelConversion {
+        self.ctx.borrow().fuel_conversion
+    }
+
+    fn is_inspecting(&self) -> bool {
+        self.inspector.is_some()
+    }
+
+    fn inspect_call(
+        &self,
+        caller: Address,
+        address: Address,
+        is_static: bool,
+        input: &mut EvmCallMethodInput,
+    ) -> Option<EvmCallMethodOutput> {
+        let mut inspector = self.inspector.as_ref()?.borrow_mut();
+        let mut ctx = self.ctx.borrow_mut();
+        let mut inputs = call_inputs_from_method_input(caller, address, is_static, input);
+        let outcome = inspector.call(&mut ctx, &mut inputs);
+        // inspector is allowed to modify call inputs
+        input.callee = inputs.contract;
+        input.value = inputs.transfer.value;
+        input.input = inputs.input;
+        input.gas_limit = inputs.gas_limit;
+        outcome.map(call_method_output_from_outcome)
+    }
+
+    fn inspect_call_end(
+        &self,
+        caller: Address,
+        address: Address,
+        is_static: bool,
+        input: &EvmCallMethodInput,
+        output: EvmCallMethodOutput,
+    ) -> EvmCallMethodOutput {
+        let Some(inspector) = self.inspector.as_ref() else {
+            return output;
+        };
+        let mut ctx = self.ctx.borrow_mut();
+        let inputs = call_inputs_from_method_input(caller, address, is_static, input);
+        let outcome = inspector.borrow_mut().call_end(
+            &mut ctx,
+            &inputs,
+            call_outcome_from_method_output(&output),
+        );
+        call_method_output_from_outcome(outcome)
+    }
+
+    fn inspect_create(
+        &self,
+        caller: Address,
+        input: &mut EvmCreateMethodInput,
+    ) -> Option<EvmCreateMethodOutput> {
+        let mut inspector = self.inspector.as_ref()?.borrow_mut();
+        let mut ctx = self.ctx.borrow_mut();
+        let mut inputs = create_inputs_from_method_input(caller, input);
+        let outcome = inspector.create(&mut ctx, &mut inputs);
+        // inspector is allowed to modify create inputs
+        input.value = inputs.value;
+        input.bytecode = inputs.init_code;
+        input.gas_limit = inputs.gas_limit;
+        input.salt = match inputs.scheme {
+            CreateScheme::Create2 { salt } => Some(salt),
+            CreateScheme::Create => None,
+        };
+        outcome.map(create_method_output_from_outcome)
+    }
+
+    fn inspect_create_end(
+        &self,
+        caller: Address,
+        input: &EvmCreateMethodInput,
+        output: EvmCreateMethodOutput,
+    ) -> EvmCreateMethodOutput {
+        let Some(inspector) = self.inspector.as_ref() else {
+            return output;
+        };
+        let mut ctx = self.ctx.borrow_mut();
+        let inputs = create_inputs_from_method_input(caller, input);
+        let outcome = inspector.borrow_mut().create_end(
+            &mut ctx,
+            &inputs,
+            create_outcome_from_method_output(&output),
+        );
+        create_method_output_from_outcome(outcome)
+    }
+
+    fn is_step_tracing_enabled(&self) -> bool {
+        self.inspector.is_some() && self.ctx.borrow().step_tracing
+    }
+
+    fn inspect_step(&self, step: &EvmStepTrace) {
+        let Some(inspector) = self.inspector.as_ref() else {
+            return;
+        };
+        let mut ctx = self.ctx.borrow_mut();
+        let mut gas = Gas::new(step.gas_remaining);
+        gas.record_refund(step.gas_refund);
+        let mut interp = Interpreter {
+            gas,
+            program_counter: step.pc,
+            current_opcode: step.opcode,
+            stack: Stack::new(step.stack.clone()),
+            shared_memory: SharedMemory::with_len(step.memory_size),
+            ..Default::default()
+        };
+        // inspectors read depth of the frame from the journal, but frames executed by the
+        // interpreter inside of the loader call don't change it, so depth is taken from the step
+        let depth = mem::replace(&mut ctx.journaled_state.depth, step.depth as usize);
+        let mut inspector = inspector.borrow_mut();
+        inspector.step(&mut interp, &mut ctx);
+        // the cost can't exceed the remaining gas unless the step ran out of gas, then all the
+        // remaining gas is spent
+        if !interp.gas.record_cost(step.gas_cost) {
+            let gas_remaining = interp.gas.remaining();
+            interp.gas.record_cost(gas_remaining);
+        }
+        inspector.step_end(&mut interp, &mut ctx);
+        ctx.journaled_state.depth = depth;
+    }
+
+    fn analysed_bytecode(&self, source_code_hash: &B256) -> Option<Bytecode> {
+        let ctx = self.ctx.borrow();
+        ctx.journaled_state
+            .code_state
+            .get(source_code_hash)
+            .filter(|code| matches!(code.state, BytecodeState::Analysed { .. }))
+            .cloned()
+    }
+
+    fn cache_analysed_bytecode(&self, source_code_hash: &B256, bytecode: &Bytecode) {
+        // code state outlives the transaction, so analysis is reused by the next transactions,
+        // but new entries aren't added once it's full, only the loaded code is replaced
+        let mut ctx = self.ctx.borrow_mut();
+        let code_state = &mut ctx.journaled_state.code_state;
+        if code_state.len() >= MAX_ANALYSED_CODE_STATE_SIZE
+            && !code_state.contains_key(source_code_hash)
+        {
+            return;
+        }
+        code_state.insert(*source_code_hash, bytecode.clone());
+    }
 }
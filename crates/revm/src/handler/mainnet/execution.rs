@@ -1,5 +1,6 @@
 use crate::{
     db::Database,
+    evm::{loader_call, loader_create},
     interpreter::{
         CallInputs,
         CallOutcome,
@@ -70,12 +71,16 @@ pub fn last_frame_return<SPEC: Spec, EXT, DB: Database>(
 }
 
 /// Handle frame sub call.
+///
+/// Call is fully executed by the loader (including all nested frames), so we always return
+/// result here.
 #[inline]
 pub fn call<SPEC: Spec, EXT, DB: Database>(
     context: &mut Context<EXT, DB>,
     inputs: Box<CallInputs>,
 ) -> Result<FrameOrResult, EVMError<ExitCode>> {
-    context.evm.make_call_frame(&inputs)
+    let outcome = loader_call(&mut context.evm, None, &inputs);
+    Ok(FrameOrResult::Result(FrameResult::Call(outcome)))
 }
 
 #[inline]
@@ -109,12 +114,16 @@ pub fn insert_call_outcome<EXT, DB: Database>(
 }
 
 /// Handle frame sub create.
+///
+/// Create is fully executed by the loader (including all nested frames), so we always return
+/// result here.
 #[inline]
 pub fn create<SPEC: Spec, EXT, DB: Database>(
     context: &mut Context<EXT, DB>,
     inputs: Box<CreateInputs>,
 ) -> Result<FrameOrResult, EVMError<ExitCode>> {
-    context.evm.make_create_frame(SPEC::SPEC_ID, &inputs)
+    let outcome = loader_create(&mut context.evm, None, &inputs);
+    Ok(FrameOrResult::Result(FrameResult::Create(outcome)))
 }
 
 #[inline]
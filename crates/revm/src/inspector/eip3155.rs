@@ -128,10 +128,10 @@ impl<DB: Database> Inspector<DB> for TracerEip3155 {
 
     fn step(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
         self.gas_inspector.step(interp, context);
-        // self.stack = interp.stack.data().clone();
+        self.stack = interp.stack.data().clone();
         self.pc = interp.program_counter();
         self.opcode = interp.current_opcode();
-        // self.mem_size = interp.shared_memory.len();
+        self.mem_size = interp.shared_memory.len();
         self.gas = interp.gas.remaining();
         self.refunded = interp.gas.refunded();
     }
@@ -34,6 +34,16 @@ impl<DB: Database> Inspector<DB> for GasInspector {
         self.gas_remaining = interp.gas.limit();
     }
 
+    fn step(
+        &mut self,
+        interp: &mut crate::interpreter::Interpreter,
+        _context: &mut EvmContext<DB>,
+    ) {
+        // steps of nested frames are reported without frame initialization, so sync gas
+        // before each step to keep cost of the step correct
+        self.gas_remaining = interp.gas.remaining();
+    }
+
     fn step_end(
         &mut self,
         interp: &mut crate::interpreter::Interpreter,
@@ -430,6 +430,9 @@ impl JournaledState {
                         transient_storage.insert(tkey, had_value);
                     }
                 }
+                JournalEntry::AccountRestored { address } => {
+                    state.get_mut(&address).unwrap().mark_selfdestruct();
+                }
                 JournalEntry::CodeChange { address } => {
                     let acc = state.get_mut(&address).unwrap();
                     acc.info.code_hash = KECCAK_EMPTY;
@@ -557,6 +560,52 @@ impl JournaledState {
         })
     }
 
+    /// Removes account from the state, the account is destroyed once the transaction is
+    /// committed (like with `SELFDESTRUCT`, but w/o balance transfer).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the account has not been loaded and is missing from the state set.
+    #[inline]
+    pub fn remove_account(&mut self, address: Address) {
+        let account = self
+            .state
+            .get_mut(&address)
+            .expect("Account expected to be loaded");
+        Self::touch_account(self.journal.last_mut().unwrap(), &address, account);
+        let had_balance = account.info.balance;
+        let was_destroyed = account.is_selfdestructed();
+        account.mark_selfdestruct();
+        account.info.balance = U256::ZERO;
+        self.journal
+            .last_mut()
+            .unwrap()
+            .push(JournalEntry::AccountDestroyed {
+                address,
+                target: address,
+                was_destroyed,
+                had_balance,
+            });
+    }
+
+    /// Restores account removed by `remove_account`, so the account that is written again after
+    /// the removal isn't destroyed on commit.
+    #[inline]
+    pub fn restore_account(&mut self, address: Address) {
+        let account = self
+            .state
+            .get_mut(&address)
+            .expect("Account expected to be loaded");
+        if !account.is_selfdestructed() {
+            return;
+        }
+        account.unmark_selfdestruct();
+        self.journal
+            .last_mut()
+            .unwrap()
+            .push(JournalEntry::AccountRestored { address });
+    }
+
     /// Initial load of account. This load will not be tracked inside journal
     #[inline]
     pub fn initial_account_load<DB: Database>(
@@ -871,6 +920,10 @@ pub enum JournalEntry {
         was_destroyed: bool, // if account had already been destroyed before this journal entry
         had_balance: U256,
     },
+    /// Restore account that was removed before
+    /// Action: Unmark the account as destroyed
+    /// Revert: Mark the account as destroyed again
+    AccountRestored { address: Address },
     /// Loading account does not mean that account will need to be added to MerkleTree (touched).
     /// Only when account is called (to execute contract or transfer balance) only then account is
     /// made touched. Action: Mark account touched
@@ -1,4 +1,12 @@
-use crate::{Evm, InMemoryDB};
+use crate::{
+    inspector_handle_register,
+    interpreter::{CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter},
+    Database,
+    Evm,
+    EvmContext,
+    InMemoryDB,
+    Inspector,
+};
 use core::{mem::take, str::from_utf8};
 use fluentbase_codec::{BufferDecoder, Encoder};
 use fluentbase_genesis::{
@@ -7,7 +15,15 @@ use fluentbase_genesis::{
     EXAMPLE_GREETING_ADDRESS,
 };
 use fluentbase_poseidon::poseidon_hash;
-use fluentbase_sdk::{calc_create_address, Account, ContractInput, CoreInput, EvmCallMethodInput};
+use fluentbase_runtime::EvmTranslator;
+use fluentbase_sdk::{
+    calc_create_address,
+    Account,
+    ContractInput,
+    CoreInput,
+    EvmCallMethodInput,
+    EvmCallScheme,
+};
 use fluentbase_types::{
     address,
     bytes,
@@ -18,9 +34,15 @@ use fluentbase_types::{
     B256,
     KECCAK_EMPTY,
     POSEIDON_EMPTY,
+    PRECOMPILE_BN128_ADD_ADDRESS,
+    PRECOMPILE_BN128_MUL_ADDRESS,
+    PRECOMPILE_BN128_PAIR_ADDRESS,
+    PRECOMPILE_KZG_POINT_EVALUATION_ADDRESS,
+    STATE_MAIN,
     U256,
 };
 use lazy_static::lazy_static;
+use revm_precompile::{bn128, kzg_point_evaluation};
 use revm_primitives::{
     db::DatabaseCommit,
     hex,
@@ -33,6 +55,7 @@ use revm_primitives::{
     ExecutionResult,
     HashMap,
     Output,
+    PrecompileResult,
     TransactTo,
 };
 use rwasm::{
@@ -146,6 +169,7 @@ impl TestingContext {
 struct TxBuilder<'a> {
     pub(crate) ctx: &'a mut TestingContext,
     pub(crate) env: Env,
+    pub(crate) evm_translator: Option<EvmTranslator>,
 }
 
 #[allow(dead_code)]
@@ -165,7 +189,11 @@ impl<'a> TxBuilder<'a> {
         };
         env.tx.data = init_code;
         env.tx.gas_limit = 300_000_000;
-        Self { ctx, env }
+        Self {
+            ctx,
+            env,
+            evm_translator: None,
+        }
     }
 
     fn call(ctx: &'a mut TestingContext, caller: Address, callee: Address) -> Self {
@@ -174,7 +202,11 @@ impl<'a> TxBuilder<'a> {
         env.tx.caller = caller;
         env.tx.transact_to = TransactTo::Call(callee);
         env.tx.gas_limit = 10_000_000;
-        Self { ctx, env }
+        Self {
+            ctx,
+            env,
+            evm_translator: None,
+        }
     }
 
     fn input(mut self, input: Bytes) -> Self {
@@ -197,11 +229,19 @@ impl<'a> TxBuilder<'a> {
         self
     }
 
+    fn evm_translator(mut self, evm_translator: EvmTranslator) -> Self {
+        self.evm_translator = Some(evm_translator);
+        self
+    }
+
     fn exec(&mut self) -> Result<ExecutionResult, EVMError<ExitCode>> {
-        let mut evm = Evm::builder()
+        let mut builder = Evm::builder()
             .with_env(Box::new(take(&mut self.env)))
-            .with_db(&mut self.ctx.db)
-            .build();
+            .with_db(&mut self.ctx.db);
+        if let Some(evm_translator) = self.evm_translator {
+            builder = builder.with_evm_translator(evm_translator);
+        }
+        let mut evm = builder.build();
         evm.transact_commit()
     }
 }
@@ -462,6 +502,111 @@ fn test_evm_storage() {
     );
 }
 
+/// Runtime bytecode that returns 42 if it's interpreted (`PUSH1 0x2a PUSH1 0 MSTORE PUSH1 0x20
+/// PUSH1 0 RETURN`)
+const TRANSLATED_RUNTIME: [u8; 10] = hex!("602a60005260206000f3");
+
+/// Test translator, it replaces [TRANSLATED_RUNTIME] with rWASM that returns depth of the frame
+/// from its input, any other bytecode isn't supported, so it's interpreted
+fn translate_depth_contract(evm_bytecode: &[u8]) -> Result<Vec<u8>, ExitCode> {
+    use fluentbase_sdk::IContractInput;
+    if evm_bytecode != TRANSLATED_RUNTIME {
+        return Err(ExitCode::NotSupportedCall);
+    }
+    let depth_offset = <ContractInput as IContractInput>::ContractDepth::FIELD_OFFSET as i32;
+    let code_section = instruction_set! {
+        I32Const(1)
+        MemoryGrow
+        Drop
+        I32Const(0) // target
+        I32Const(depth_offset) // offset
+        I32Const(4) // length
+        Call(SysFuncIdx::SYS_READ)
+        I32Const(0)
+        I32Const(4)
+        Call(SysFuncIdx::SYS_WRITE)
+        I32Const(ExitCode::Ok.into_i32())
+        Call(SysFuncIdx::SYS_HALT)
+    };
+    let code_section_len = code_section.len() as u32;
+    let rwasm_module = RwasmModule {
+        code_section,
+        func_section: vec![code_section_len],
+        ..Default::default()
+    };
+    let mut rwasm_binary = Vec::new();
+    rwasm_module.write_binary_to_vec(&mut rwasm_binary).unwrap();
+    Ok(rwasm_binary)
+}
+
+/// Returns init bytecode that deploys the runtime bytecode
+fn evm_init_code(runtime: &[u8]) -> Bytes {
+    // PUSH1 len DUP1 PUSH1 0x0b PUSH1 0 CODECOPY PUSH1 0 RETURN
+    let mut init_code = vec![0x60, runtime.len() as u8];
+    init_code.extend(hex!("80600b6000396000f3"));
+    init_code.extend(runtime);
+    init_code.into()
+}
+
+#[test]
+fn test_evm_translated_contract() {
+    let mut ctx = TestingContext::default();
+    const DEPLOYER_ADDRESS: Address = Address::ZERO;
+    // the translated contract is stored with its rWASM bytecode
+    let result = TxBuilder::create(
+        &mut ctx,
+        DEPLOYER_ADDRESS,
+        evm_init_code(&TRANSLATED_RUNTIME),
+        None,
+    )
+    .evm_translator(translate_depth_contract)
+    .exec()
+    .unwrap();
+    assert!(result.is_success());
+    let callee_address = calc_create_address(&DEPLOYER_ADDRESS, 0);
+    let callee_account = ctx.db.accounts.get(&callee_address).unwrap();
+    assert_eq!(
+        callee_account.info.code_hash,
+        keccak256(&TRANSLATED_RUNTIME)
+    );
+    assert_ne!(callee_account.info.rwasm_code_hash, POSEIDON_EMPTY);
+    // the caller can't be translated, it calls the callee and returns its return data:
+    // CALL(gas, callee, 0, 0, 0, 0, 0) POP
+    // RETURNDATACOPY(0, 0, RETURNDATASIZE) RETURN(0, RETURNDATASIZE)
+    let mut caller_runtime = hex!("60006000600060006000").to_vec();
+    caller_runtime.push(0x73);
+    caller_runtime.extend(callee_address.as_slice());
+    caller_runtime.extend(hex!("5af1503d600060003e3d6000f3"));
+    let result = TxBuilder::create(
+        &mut ctx,
+        DEPLOYER_ADDRESS,
+        evm_init_code(&caller_runtime),
+        None,
+    )
+    .evm_translator(translate_depth_contract)
+    .exec()
+    .unwrap();
+    assert!(result.is_success());
+    let caller_address = calc_create_address(&DEPLOYER_ADDRESS, 1);
+    let caller_account = ctx.db.accounts.get(&caller_address).unwrap();
+    assert_eq!(caller_account.info.rwasm_code_hash, POSEIDON_EMPTY);
+    // the callee is executed natively and reads depth of its frame from the input
+    let result = TxBuilder::call(&mut ctx, DEPLOYER_ADDRESS, callee_address)
+        .gas_price(U256::ZERO)
+        .evm_translator(translate_depth_contract)
+        .exec()
+        .unwrap();
+    assert!(result.is_success());
+    assert_eq!(result.output().unwrap().as_ref(), &0u32.to_le_bytes());
+    let result = TxBuilder::call(&mut ctx, DEPLOYER_ADDRESS, caller_address)
+        .gas_price(U256::ZERO)
+        .evm_translator(translate_depth_contract)
+        .exec()
+        .unwrap();
+    assert!(result.is_success());
+    assert_eq!(result.output().unwrap().as_ref(), &1u32.to_le_bytes());
+}
+
 #[test]
 fn test_simple_send() {
     // deploy greeting EVM contract
@@ -842,12 +987,14 @@ fn test_codec_case() {
         input: Bytes::copy_from_slice(&hex::decode("").unwrap()),
         gas_limit: 9999979000,
         depth: 0,
+        scheme: EvmCallScheme::CallCode,
     };
     let call_method_input_encoded = call_method_input.encode_to_vec(0);
     let mut buffer = BufferDecoder::new(&call_method_input_encoded);
     let mut call_method_input_decoded = EvmCallMethodInput::default();
     EvmCallMethodInput::decode_body(&mut buffer, 0, &mut call_method_input_decoded);
     assert_eq!(call_method_input_decoded.callee, call_method_input.callee);
+    assert_eq!(call_method_input_decoded.scheme, EvmCallScheme::CallCode);
 }
 
 #[test]
@@ -911,3 +1058,569 @@ fn test_simple_nested_call() {
     println!("{:?}", result);
     assert!(result.is_success());
 }
+
+fn exec_nested_call_with_fuel(code_section: InstructionSet, fuel: &[u8]) -> Bytes {
+    let mut ctx = TestingContext::default();
+    let callee = ctx.add_wasm_contract(
+        address!("0000000000000000000000000000000000000001"),
+        instruction_set! {
+            I32Const(ExitCode::Ok.into_i32())
+            Call(SysFuncIdx::SYS_HALT)
+        },
+    );
+    let mut memory_section = vec![0xffu8; 32 + 8];
+    memory_section[0..32].copy_from_slice(&callee.rwasm_code_hash.0);
+    memory_section[32..32 + fuel.len()].copy_from_slice(fuel);
+    let code_section_len = code_section.len() as u32;
+    ctx.add_wasm_contract(
+        address!("0000000000000000000000000000000000000002"),
+        RwasmModule {
+            code_section,
+            memory_section,
+            func_section: vec![code_section_len],
+            ..Default::default()
+        },
+    );
+    let result = TxBuilder::call(
+        &mut ctx,
+        Address::ZERO,
+        address!("0000000000000000000000000000000000000002"),
+    )
+    .gas_price(U256::ZERO)
+    .exec()
+    .unwrap();
+    assert!(result.is_success());
+    result.output().cloned().unwrap_or_default()
+}
+
+#[test]
+fn test_nested_call_fuel_abi() {
+    // contracts deployed before fuel was passed as u64 keep calling `_sys_exec_hash` with u32
+    let output = exec_nested_call_with_fuel(
+        instruction_set! {
+            I32Const(1)
+            MemoryGrow
+            Drop
+            I32Const(0)
+            I32Const(0)
+            I32Const(40)
+            MemoryInit(0)
+            DataDrop(0)
+            I32Const(0) // bytecode_hash32_offset
+            I32Const(0) // input_offset
+            I32Const(0) // input_len
+            I32Const(0) // return_offset
+            I32Const(0) // return_len
+            I32Const(32) // fuel_offset
+            I32Const(0) // state
+            Call(SysFuncIdx::SYS_EXEC_HASH)
+            Drop
+            I32Const(32)
+            I32Const(8)
+            Call(SysFuncIdx::SYS_WRITE)
+            I32Const(ExitCode::Ok.into_i32())
+            Call(SysFuncIdx::SYS_HALT)
+        },
+        &100_000u32.to_le_bytes(),
+    );
+    let fuel_remaining = u32::from_le_bytes(output[0..4].try_into().unwrap());
+    assert!(fuel_remaining > 0 && fuel_remaining < 100_000);
+    // memory after the u32 fuel slot is untouched
+    assert_eq!(&output[4..8], &[0xff; 4]);
+    let output = exec_nested_call_with_fuel(
+        instruction_set! {
+            I32Const(1)
+            MemoryGrow
+            Drop
+            I32Const(0)
+            I32Const(0)
+            I32Const(40)
+            MemoryInit(0)
+            DataDrop(0)
+            I32Const(0) // bytecode_hash32_offset
+            I32Const(0) // input_offset
+            I32Const(0) // input_len
+            I32Const(0) // return_offset
+            I32Const(0) // return_len
+            I32Const(32) // fuel_offset
+            I32Const(0) // state
+            Call(SysFuncIdx::SYS_EXEC_HASH64)
+            Drop
+            I32Const(32)
+            I32Const(8)
+            Call(SysFuncIdx::SYS_WRITE)
+            I32Const(ExitCode::Ok.into_i32())
+            Call(SysFuncIdx::SYS_HALT)
+        },
+        &(u32::MAX as u64 + 100_000).to_le_bytes(),
+    );
+    let fuel_remaining = u64::from_le_bytes(output[0..8].try_into().unwrap());
+    assert!(fuel_remaining > u32::MAX as u64 && fuel_remaining < u32::MAX as u64 + 100_000);
+}
+
+#[derive(Default, Debug)]
+struct CountingInspector {
+    call: u32,
+    call_end: u32,
+    create: u32,
+    create_end: u32,
+    selfdestruct: u32,
+    steps: Vec<(usize, u8, u64, u64)>,
+}
+
+impl<DB: Database> Inspector<DB> for CountingInspector {
+    fn step(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        self.steps.push((
+            interp.program_counter(),
+            interp.current_opcode(),
+            interp.gas.remaining(),
+            context.journaled_state.depth(),
+        ));
+    }
+
+    fn call(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &mut CallInputs,
+    ) -> Option<CallOutcome> {
+        self.call += 1;
+        None
+    }
+
+    fn call_end(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &CallInputs,
+        outcome: CallOutcome,
+    ) -> CallOutcome {
+        self.call_end += 1;
+        outcome
+    }
+
+    fn create(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &mut CreateInputs,
+    ) -> Option<CreateOutcome> {
+        self.create += 1;
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &CreateInputs,
+        outcome: CreateOutcome,
+    ) -> CreateOutcome {
+        self.create_end += 1;
+        outcome
+    }
+
+    fn selfdestruct(&mut self, _contract: Address, _target: Address, _value: U256) {
+        self.selfdestruct += 1;
+    }
+}
+
+#[test]
+fn test_inspector_receives_loader_events() {
+    let mut ctx = TestingContext::default();
+    const SENDER_ADDRESS: Address = address!("1231238908230948230948209348203984029834");
+    ctx.add_balance(SENDER_ADDRESS, U256::from(2e18));
+    let mut inspector = CountingInspector::default();
+    // deploy self destruct contract
+    let mut tx = TxBuilder::create(
+        &mut ctx,
+        SENDER_ADDRESS,
+        hex!("6003600c60003960036000F36003ff").into(),
+        None,
+    )
+    .gas_price(U256::ZERO)
+    .value(U256::from(1e18));
+    let result = Evm::builder()
+        .with_env(Box::new(take(&mut tx.env)))
+        .with_db(&mut tx.ctx.db)
+        .with_external_context(&mut inspector)
+        .append_handler_register(inspector_handle_register)
+        .build()
+        .transact_commit()
+        .unwrap();
+    assert!(result.is_success());
+    assert_eq!(inspector.create, 1);
+    assert_eq!(inspector.create_end, 1);
+    // call self destruct contract
+    let contract_address = calc_create_address(&SENDER_ADDRESS, 0);
+    let mut tx = TxBuilder::call(&mut ctx, SENDER_ADDRESS, contract_address).gas_price(U256::ZERO);
+    let result = Evm::builder()
+        .with_env(Box::new(take(&mut tx.env)))
+        .with_db(&mut tx.ctx.db)
+        .with_external_context(&mut inspector)
+        .append_handler_register(inspector_handle_register)
+        .build()
+        .transact_commit()
+        .unwrap();
+    assert!(result.is_success());
+    assert_eq!(inspector.call, 1);
+    assert_eq!(inspector.call_end, 1);
+    assert_eq!(inspector.selfdestruct, 1);
+    // steps are reported only if step tracing is enabled
+    assert!(inspector.steps.is_empty());
+}
+
+#[test]
+fn test_inspector_receives_evm_steps() {
+    let mut ctx = TestingContext::default();
+    const SENDER_ADDRESS: Address = address!("1231238908230948230948209348203984029834");
+    ctx.add_balance(SENDER_ADDRESS, U256::from(2e18));
+    // deploy contract with `PUSH1 0x03 SELFDESTRUCT` runtime code
+    let result = TxBuilder::create(
+        &mut ctx,
+        SENDER_ADDRESS,
+        hex!("6003600c60003960036000F36003ff").into(),
+        None,
+    )
+    .gas_price(U256::ZERO)
+    .exec()
+    .unwrap();
+    assert!(result.is_success());
+    let contract_address = calc_create_address(&SENDER_ADDRESS, 0);
+    let mut inspector = CountingInspector::default();
+    let mut tx = TxBuilder::call(&mut ctx, SENDER_ADDRESS, contract_address).gas_price(U256::ZERO);
+    let result = Evm::builder()
+        .with_env(Box::new(take(&mut tx.env)))
+        .with_db(&mut tx.ctx.db)
+        .with_external_context(&mut inspector)
+        .append_handler_register(inspector_handle_register)
+        .with_step_tracing(true)
+        .build()
+        .transact_commit()
+        .unwrap();
+    assert!(result.is_success());
+    let steps = inspector
+        .steps
+        .iter()
+        .map(|(pc, opcode, _, _)| (*pc, *opcode))
+        .collect::<Vec<_>>();
+    assert_eq!(steps, vec![(0, 0x60), (2, 0xff)]);
+    // PUSH1 costs 3 gas
+    assert_eq!(inspector.steps[0].2 - inspector.steps[1].2, 3);
+}
+
+#[test]
+fn test_inspector_receives_nested_step_depth() {
+    let mut ctx = TestingContext::default();
+    const SENDER_ADDRESS: Address = address!("1231238908230948230948209348203984029834");
+    ctx.add_balance(SENDER_ADDRESS, U256::from(2e18));
+    let mut deploy = |runtime_code: Vec<u8>| {
+        // copy runtime code to the memory and return it
+        let mut init_code = vec![0x60, runtime_code.len() as u8, 0x60, 0x0c, 0x60, 0x00, 0x39];
+        init_code.extend([0x60, runtime_code.len() as u8, 0x60, 0x00, 0xf3]);
+        init_code.extend(runtime_code);
+        let result = TxBuilder::create(&mut ctx, SENDER_ADDRESS, init_code.into(), None)
+            .gas_price(U256::ZERO)
+            .exec()
+            .unwrap();
+        assert!(result.is_success());
+    };
+    // callee is `STOP`
+    deploy(vec![0x00]);
+    let callee_address = calc_create_address(&SENDER_ADDRESS, 0);
+    // caller does `CALL(GAS, callee, 0, 0, 0, 0, 0)` and stops
+    let mut caller_code = hex!("60006000600060006000").to_vec();
+    caller_code.push(0x73);
+    caller_code.extend_from_slice(callee_address.as_slice());
+    caller_code.extend([0x5a, 0xf1, 0x00]);
+    deploy(caller_code);
+    let caller_address = calc_create_address(&SENDER_ADDRESS, 1);
+    let mut inspector = CountingInspector::default();
+    let mut tx = TxBuilder::call(&mut ctx, SENDER_ADDRESS, caller_address).gas_price(U256::ZERO);
+    let result = Evm::builder()
+        .with_env(Box::new(take(&mut tx.env)))
+        .with_db(&mut tx.ctx.db)
+        .with_external_context(&mut inspector)
+        .append_handler_register(inspector_handle_register)
+        .with_step_tracing(true)
+        .build()
+        .transact_commit()
+        .unwrap();
+    assert!(result.is_success());
+    let steps = inspector
+        .steps
+        .iter()
+        .map(|(pc, opcode, _, depth)| (*pc, *opcode, *depth))
+        .collect::<Vec<_>>();
+    // first step of the caller and the only step of the callee
+    assert_eq!(steps.first(), Some(&(0, 0x60, 1)));
+    assert!(steps.contains(&(0, 0x00, 2)));
+}
+
+#[test]
+fn test_zktrie_state_root() {
+    use crate::db::{BundleState, ZkTrieState};
+    use fluentbase_runtime::{types::InMemoryTrieDb, zktrie::ZkTrieStateDb, JournaledTrie};
+    use fluentbase_sdk::{JZKT_ACCOUNT_COMPRESSION_FLAGS, JZKT_STORAGE_COMPRESSION_FLAGS};
+    use fluentbase_types::IJournaledTrie;
+    const CONTRACT_ADDRESS: Address = address!("1000000000000000000000000000000000000001");
+    let bytecode = Bytecode::new_raw(bytes!("6001600101"));
+    let info = AccountInfo {
+        balance: U256::from(100),
+        nonce: 1,
+        code_hash: keccak256(bytecode.original_bytes()),
+        rwasm_code_hash: POSEIDON_EMPTY,
+        code: None,
+        rwasm_code: None,
+    };
+    let bundle = BundleState::builder(0..=0)
+        .state_present_account_info(CONTRACT_ADDRESS, info.clone())
+        .state_storage(
+            CONTRACT_ADDRESS,
+            HashMap::from([(U256::from(1), (U256::ZERO, U256::from(2)))]),
+        )
+        .contract(info.code_hash, bytecode.clone())
+        .build();
+    let mut zktrie_state = ZkTrieState::new(ZkTrieStateDb::new_empty(InMemoryTrieDb::default()));
+    let root = zktrie_state.apply_bundle(&bundle).unwrap();
+    // the same changes applied through the journal must produce the same root
+    let jzkt = JournaledTrie::new(ZkTrieStateDb::new_empty(InMemoryTrieDb::default()));
+    let mut account = Account::from(AccountInfo {
+        code: Some(bytecode),
+        ..info
+    });
+    account.address = CONTRACT_ADDRESS;
+    jzkt.update(
+        &CONTRACT_ADDRESS.into_word().0,
+        &account.get_fields().to_vec(),
+        JZKT_ACCOUNT_COMPRESSION_FLAGS,
+    );
+    let storage_key = JournaledTrie::<ZkTrieStateDb<InMemoryTrieDb>>::storage_key(
+        &CONTRACT_ADDRESS,
+        &U256::from(1).to_le_bytes(),
+    );
+    jzkt.update(
+        &storage_key,
+        &vec![U256::from(2).to_le_bytes::<32>()],
+        JZKT_STORAGE_COMPRESSION_FLAGS,
+    );
+    let (jzkt_root, _) = jzkt.commit().unwrap();
+    assert_eq!(root, jzkt_root);
+    // proofs of the applied state match the proofs of the journal
+    let account_key = CONTRACT_ADDRESS.into_word().0;
+    assert!(zktrie_state.proof(&account_key).is_some());
+    assert_eq!(
+        zktrie_state.proof(&account_key),
+        jzkt.proof(&jzkt_root, &account_key)
+    );
+    // account removal is applied as well
+    let bundle = BundleState::builder(0..=0)
+        .state_original_account_info(CONTRACT_ADDRESS, account.into())
+        .build();
+    assert_ne!(zktrie_state.apply_bundle(&bundle).unwrap(), root);
+}
+
+#[test]
+fn test_zktrie_block_hash_history() {
+    use crate::db::{BundleState, ZkTrieState};
+    use fluentbase_runtime::{types::InMemoryTrieDb, zktrie::ZkTrieStateDb, JournaledTrie};
+    use fluentbase_types::{IJournaledTrie, BLOCK_HASH_HISTORY_ADDRESS, BLOCK_HASH_HISTORY_SIZE};
+    let parent_hash = B256::with_last_byte(7);
+    let mut zktrie_state = ZkTrieState::new(ZkTrieStateDb::new_empty(InMemoryTrieDb::default()));
+    let root = zktrie_state
+        .apply_block(
+            BLOCK_HASH_HISTORY_SIZE + 1,
+            parent_hash,
+            &BundleState::default(),
+        )
+        .unwrap();
+    // block hash is stored in the same slot the journal uses, so `BLOCKHASH` can read it
+    let jzkt = JournaledTrie::new(ZkTrieStateDb::new_empty(InMemoryTrieDb::default()));
+    jzkt.update_block_hash(BLOCK_HASH_HISTORY_SIZE + 1, &parent_hash);
+    let (jzkt_root, _) = jzkt.commit().unwrap();
+    assert_eq!(root, jzkt_root);
+    // proofs of the applied state match the proofs of the journal
+    let account_key = CONTRACT_ADDRESS.into_word().0;
+    assert!(zktrie_state.proof(&account_key).is_some());
+    assert_eq!(
+        zktrie_state.proof(&account_key),
+        jzkt.proof(&jzkt_root, &account_key)
+    );
+    let jzkt = JournaledTrie::new(zktrie_state.into_inner());
+    let storage_key = JournaledTrie::<ZkTrieStateDb<InMemoryTrieDb>>::storage_key(
+        &BLOCK_HASH_HISTORY_ADDRESS,
+        &U256::from(1).to_le_bytes(),
+    );
+    let (value, _, _) = jzkt.get(&storage_key, false).unwrap();
+    assert_eq!(value[0], parent_hash.0);
+}
+
+#[test]
+fn test_journal_bridge_remove_account() {
+    use crate::evm::JournalDbWrapper;
+    use core::cell::RefCell;
+    use fluentbase_sdk::JZKT_ACCOUNT_COMPRESSION_FLAGS;
+    use fluentbase_types::IJournaledTrie;
+    const ACCOUNT_ADDRESS: Address = address!("1000000000000000000000000000000000000001");
+    let mut ctx = EvmContext::new(InMemoryDB::default());
+    let am = JournalDbWrapper {
+        ctx: RefCell::new(&mut ctx),
+        inspector: None,
+    };
+    let key = ACCOUNT_ADDRESS.into_word().0;
+    let mut account = Account::new(ACCOUNT_ADDRESS);
+    account.balance = U256::from(100);
+    account.nonce = 1;
+    am.update(
+        &key,
+        &account.get_fields().to_vec(),
+        JZKT_ACCOUNT_COMPRESSION_FLAGS,
+    );
+    assert!(am.get(&key, false).is_some());
+    // removed account isn't visible anymore
+    am.remove(&key);
+    assert_eq!(am.get(&key, false), None);
+    // but the account written after the removal is visible again
+    account.nonce = 2;
+    am.update(
+        &key,
+        &account.get_fields().to_vec(),
+        JZKT_ACCOUNT_COMPRESSION_FLAGS,
+    );
+    let (fields, _, _) = am.get(&key, false).unwrap();
+    let account = Account::new_from_fields(ACCOUNT_ADDRESS, &fields);
+    assert_eq!(account.balance, U256::from(100));
+    assert_eq!(account.nonce, 2);
+    drop(am);
+    assert!(!ctx
+        .journaled_state
+        .state
+        .get(&ACCOUNT_ADDRESS)
+        .unwrap()
+        .is_selfdestructed());
+}
+
+/// Executes the precompile deployed in genesis like `JzktAccountManager::precompile` does and
+/// returns its exit code with the output.
+fn exec_genesis_precompile(address: Address, input: &[u8]) -> (ExitCode, Bytes) {
+    use fluentbase_runtime::{DefaultEmptyRuntimeDatabase, Runtime, RuntimeContext};
+    let ctx = TestingContext::default();
+    let rwasm_bytecode = ctx.genesis.alloc[&address].code.clone().unwrap();
+    let contract_input = ContractInput {
+        contract_gas_limit: PRECOMPILE_GAS_LIMIT,
+        contract_address: address,
+        contract_input: Bytes::copy_from_slice(input),
+        ..Default::default()
+    };
+    let ctx = RuntimeContext::<DefaultEmptyRuntimeDatabase>::new(rwasm_bytecode)
+        .with_input(contract_input.encode_to_vec(0))
+        .with_fuel_limit(PRECOMPILE_GAS_LIMIT)
+        .with_state(STATE_MAIN);
+    let result = Runtime::new(ctx).call().unwrap();
+    (ExitCode::from(result.exit_code), result.output.into())
+}
+
+/// Precompiles are interpreted, so they consume much more fuel than their gas cost, the limit is
+/// big enough to compare only results with `revm`.
+const PRECOMPILE_GAS_LIMIT: u64 = 10_000_000_000;
+
+fn assert_precompile_matches_revm(address: Address, input: &[u8], expected: PrecompileResult) {
+    let (exit_code, output) = exec_genesis_precompile(address, input);
+    match expected {
+        Ok((_, expected_output)) => {
+            assert_eq!(exit_code, ExitCode::Ok);
+            assert_eq!(output, expected_output);
+        }
+        Err(_) => assert_eq!(exit_code, ExitCode::PrecompileError),
+    }
+}
+
+// generators of G1 and G2 and the negation of the G1 generator encoded like EIP-196 and EIP-197
+const BN128_G1: [u8; 64] = hex!(
+    "0000000000000000000000000000000000000000000000000000000000000001"
+    "0000000000000000000000000000000000000000000000000000000000000002"
+);
+const BN128_G1_NEG: [u8; 64] = hex!(
+    "0000000000000000000000000000000000000000000000000000000000000001"
+    "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45"
+);
+const BN128_G2: [u8; 128] = hex!(
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+);
+
+#[test]
+fn test_precompile_bn128_add() {
+    let input = [BN128_G1, BN128_G1].concat();
+    let expected = bn128::run_add(&input, bn128::add::ISTANBUL_ADD_GAS_COST, u64::MAX);
+    assert_precompile_matches_revm(PRECOMPILE_BN128_ADD_ADDRESS, &input, expected);
+    // (1, 3) isn't on the curve
+    let mut input = [BN128_G1, BN128_G1].concat();
+    input[63] = 3;
+    let expected = bn128::run_add(&input, bn128::add::ISTANBUL_ADD_GAS_COST, u64::MAX);
+    assert!(expected.is_err());
+    assert_precompile_matches_revm(PRECOMPILE_BN128_ADD_ADDRESS, &input, expected);
+}
+
+#[test]
+fn test_precompile_bn128_mul() {
+    let input = [&BN128_G1[..], &U256::from(9).to_be_bytes::<32>()].concat();
+    let expected = bn128::run_mul(&input, bn128::mul::ISTANBUL_MUL_GAS_COST, u64::MAX);
+    assert_precompile_matches_revm(PRECOMPILE_BN128_MUL_ADDRESS, &input, expected);
+}
+
+#[test]
+fn test_precompile_bn128_pair() {
+    // e(G1, G2) * e(-G1, G2) = 1
+    let input = [&BN128_G1[..], &BN128_G2, &BN128_G1_NEG, &BN128_G2].concat();
+    let expected = bn128::run_pair(
+        &input,
+        bn128::pair::ISTANBUL_PAIR_PER_POINT,
+        bn128::pair::ISTANBUL_PAIR_BASE,
+        u64::MAX,
+    );
+    assert_eq!(expected.as_ref().unwrap().1[31], 1);
+    assert_precompile_matches_revm(PRECOMPILE_BN128_PAIR_ADDRESS, &input, expected);
+    // e(G1, G2) != 1
+    let input = [&BN128_G1[..], &BN128_G2].concat();
+    let expected = bn128::run_pair(
+        &input,
+        bn128::pair::ISTANBUL_PAIR_PER_POINT,
+        bn128::pair::ISTANBUL_PAIR_BASE,
+        u64::MAX,
+    );
+    assert_eq!(expected.as_ref().unwrap().1[31], 0);
+    assert_precompile_matches_revm(PRECOMPILE_BN128_PAIR_ADDRESS, &input, expected);
+}
+
+#[test]
+fn test_precompile_kzg_point_evaluation() {
+    // test vector of `c-kzg`
+    let commitment = hex!(
+        "8f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca2"
+        "5f26936857bc3a7c2539ea8ec3a952b7"
+    );
+    let z = hex!("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000");
+    let y = hex!("1522a4a7f34e1ea350ae07c29c96c7e79655aa926122e95fe69fcbd932ca49e9");
+    let proof = hex!(
+        "a62ad71d14c5719385c0686f1871430475bf3a00f0aa3f7b8dd99a9abc216074"
+        "4faf0070725e00b60ad9a026a15b1a8c"
+    );
+    let versioned_hash = kzg_point_evaluation::kzg_to_versioned_hash(&commitment);
+    let input = Bytes::from([&versioned_hash[..], &z, &y, &commitment, &proof].concat());
+    let expected = kzg_point_evaluation::run(&input, u64::MAX, &Env::default());
+    assert!(expected.is_ok());
+    assert_precompile_matches_revm(PRECOMPILE_KZG_POINT_EVALUATION_ADDRESS, &input, expected);
+    // `y` doesn't match the proof
+    let mut input = input.to_vec();
+    input[95] ^= 1;
+    let input = Bytes::from(input);
+    let expected = kzg_point_evaluation::run(&input, u64::MAX, &Env::default());
+    assert!(expected.is_err());
+    assert_precompile_matches_revm(PRECOMPILE_KZG_POINT_EVALUATION_ADDRESS, &input, expected);
+    // commitment doesn't match the versioned hash
+    let mut input = input.to_vec();
+    input[0] = 0x02;
+    let input = Bytes::from(input);
+    let expected = kzg_point_evaluation::run(&input, u64::MAX, &Env::default());
+    assert!(expected.is_err());
+    assert_precompile_matches_revm(PRECOMPILE_KZG_POINT_EVALUATION_ADDRESS, &input, expected);
+}
@@ -3,7 +3,7 @@ use core::ops::Range;
 use fluentbase_types::{Address, BytecodeType, Bytes, ExitCode, B256, U256};
 pub use revm_primitives::CreateScheme;
 use revm_primitives::{AccountInfo, Bytecode, Env, TransactTo, TxEnv};
-use std::boxed::Box;
+use std::{boxed::Box, vec::Vec};
 
 pub type InstructionResult = ExitCode;
 
@@ -15,6 +15,8 @@ pub struct Interpreter {
     pub gas: Gas,
     pub program_counter: usize,
     pub current_opcode: u8,
+    pub stack: Stack,
+    pub shared_memory: SharedMemory,
 }
 
 impl Interpreter {
@@ -150,13 +152,24 @@ impl OpCode {
     }
 }
 
+/// Memory of the EVM frame, only its size is tracked because memory is managed by the
+/// interpreter inside ECL contract.
 #[derive(Clone, Default, Debug)]
-pub struct SharedMemory;
+pub struct SharedMemory {
+    len: usize,
+}
 
 impl SharedMemory {
-    #[deprecated(note = "will be removed")]
+    pub fn with_len(len: usize) -> Self {
+        Self { len }
+    }
+
     pub fn len(&self) -> usize {
-        0
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
     #[deprecated(note = "will be removed")]
@@ -166,17 +179,33 @@ impl SharedMemory {
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct Stack;
+pub struct Stack {
+    data: Vec<U256>,
+}
 
 impl Stack {
-    #[deprecated(note = "will be removed")]
+    pub fn new(data: Vec<U256>) -> Self {
+        Self { data }
+    }
+
+    pub fn data(&self) -> &Vec<U256> {
+        &self.data
+    }
+
     pub fn len(&self) -> usize {
-        0
+        self.data.len()
     }
 
-    #[deprecated(note = "will be removed")]
-    pub fn peek(&self, _index: usize) -> Result<U256, ExitCode> {
-        Ok(U256::ZERO)
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn peek(&self, index: usize) -> Result<U256, ExitCode> {
+        self.data
+            .len()
+            .checked_sub(index + 1)
+            .map(|i| self.data[i])
+            .ok_or(ExitCode::StackUnderflow)
     }
 }
 
@@ -0,0 +1,294 @@
+use crate::types::TrieDb;
+use byteorder::{ByteOrder, LittleEndian};
+use fluentbase_types::Bytes;
+use hashbrown::HashMap;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+const NODES_FILE_NAME: &str = "nodes.log";
+const PREIMAGES_FILE_NAME: &str = "preimages.log";
+const ROOT_FILE_NAME: &str = "root";
+
+// key length + value length
+const RECORD_HEADER_SIZE: usize = 8;
+const RECORD_CHECKSUM_SIZE: usize = 4;
+
+fn record_checksum(header: &[u8], key: &[u8], value: &[u8]) -> [u8; RECORD_CHECKSUM_SIZE] {
+    let mut data = Vec::with_capacity(header.len() + key.len() + value.len());
+    data.extend_from_slice(header);
+    data.extend_from_slice(key);
+    data.extend_from_slice(value);
+    let mut hash = [0u8; 32];
+    keccak_hash::write_keccak(&data, &mut hash);
+    let mut checksum = [0u8; RECORD_CHECKSUM_SIZE];
+    checksum.copy_from_slice(&hash[..RECORD_CHECKSUM_SIZE]);
+    checksum
+}
+
+fn write_record<W: Write>(writer: &mut W, key: &[u8], value: &[u8]) -> io::Result<()> {
+    let mut header = [0u8; RECORD_HEADER_SIZE];
+    LittleEndian::write_u32(&mut header[0..4], key.len() as u32);
+    LittleEndian::write_u32(&mut header[4..8], value.len() as u32);
+    writer.write_all(&header)?;
+    writer.write_all(key)?;
+    writer.write_all(value)?;
+    writer.write_all(&record_checksum(&header, key, value))
+}
+
+/// Parses records from the log, returns entries and length of the valid prefix of the log.
+/// Parsing stops at the first incomplete or corrupted record, it can only be a tail of the log
+/// that wasn't fully written before crash.
+fn read_records(data: &[u8]) -> (HashMap<Bytes, Bytes>, usize) {
+    let mut values = HashMap::new();
+    let mut offset = 0;
+    while data.len() - offset >= RECORD_HEADER_SIZE {
+        let header = &data[offset..offset + RECORD_HEADER_SIZE];
+        let key_len = LittleEndian::read_u32(&header[0..4]) as usize;
+        let value_len = LittleEndian::read_u32(&header[4..8]) as usize;
+        let record_len = RECORD_HEADER_SIZE + key_len + value_len + RECORD_CHECKSUM_SIZE;
+        if data.len() - offset < record_len {
+            break;
+        }
+        let key_offset = offset + RECORD_HEADER_SIZE;
+        let key = &data[key_offset..key_offset + key_len];
+        let value = &data[key_offset + key_len..key_offset + key_len + value_len];
+        let checksum = &data[key_offset + key_len + value_len..offset + record_len];
+        if record_checksum(header, key, value) != checksum {
+            break;
+        }
+        values.insert(Bytes::copy_from_slice(key), Bytes::copy_from_slice(value));
+        offset += record_len;
+    }
+    (values, offset)
+}
+
+/// Append-only log of key-value records, the latest record for the key wins. All values are
+/// cached in memory, the log is replayed on open.
+struct LogStore {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    values: HashMap<Bytes, Bytes>,
+}
+
+impl LogStore {
+    fn open(path: PathBuf) -> io::Result<Self> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&path)?;
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+        let (values, valid_len) = read_records(&data);
+        if valid_len < data.len() {
+            // drop partially written tail of the log
+            file.set_len(valid_len as u64)?;
+            file.sync_all()?;
+        }
+        Ok(Self {
+            path,
+            writer: BufWriter::new(file),
+            values,
+        })
+    }
+
+    fn get(&self, key: &[u8]) -> Option<Bytes> {
+        self.values.get(&Bytes::copy_from_slice(key)).cloned()
+    }
+
+    fn insert(&mut self, key: &[u8], value: Bytes) -> io::Result<()> {
+        let key = Bytes::copy_from_slice(key);
+        if self.values.get(&key) == Some(&value) {
+            return Ok(());
+        }
+        write_record(&mut self.writer, &key, &value)?;
+        self.values.insert(key, value);
+        Ok(())
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        self.writer.get_ref().sync_data()
+    }
+
+    /// Rewrites the log with only the latest records, the new log replaces the old one atomically.
+    fn compact(&mut self) -> io::Result<()> {
+        self.sync()?;
+        let tmp_path = self.path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        for (key, value) in self.values.iter() {
+            write_record(&mut writer, key, value)?;
+        }
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        fs::rename(&tmp_path, &self.path)?;
+        let file = OpenOptions::new().append(true).open(&self.path)?;
+        self.writer = BufWriter::new(file);
+        Ok(())
+    }
+}
+
+/// File-backed trie database, that can be used to keep state across restarts.
+///
+/// Nodes and preimages are stored in separate append-only logs inside the directory. Every
+/// record has a checksum, so a torn write (e.g. crash in the middle of the write) is detected
+/// and dropped on open. Writes are buffered, call `commit` with the new state root to persist
+/// them, the root is replaced atomically only after all nodes are synced to the disk, so after
+/// restart the stored root always points to the fully persisted trie.
+pub struct FileTrieDb {
+    path: PathBuf,
+    nodes: LogStore,
+    preimages: LogStore,
+}
+
+impl FileTrieDb {
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        fs::create_dir_all(&path)?;
+        Ok(Self {
+            nodes: LogStore::open(path.join(NODES_FILE_NAME))?,
+            preimages: LogStore::open(path.join(PREIMAGES_FILE_NAME))?,
+            path,
+        })
+    }
+
+    /// Returns the last committed state root, if any.
+    pub fn root(&self) -> io::Result<Option<[u8; 32]>> {
+        let data = match fs::read(self.path.join(ROOT_FILE_NAME)) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        if data.len() != 32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "malformed state root file",
+            ));
+        }
+        let mut root = [0u8; 32];
+        root.copy_from_slice(&data);
+        Ok(Some(root))
+    }
+
+    /// Syncs all nodes and preimages to the disk and stores the new state root.
+    pub fn commit(&mut self, root: &[u8; 32]) -> io::Result<()> {
+        self.nodes.sync()?;
+        self.preimages.sync()?;
+        let root_path = self.path.join(ROOT_FILE_NAME);
+        let tmp_path = root_path.with_extension("tmp");
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(root)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, &root_path)?;
+        // sync directory to make rename durable
+        #[cfg(unix)]
+        File::open(&self.path)?.sync_all()?;
+        Ok(())
+    }
+
+    /// Removes outdated records from the logs.
+    pub fn compact(&mut self) -> io::Result<()> {
+        self.nodes.compact()?;
+        self.preimages.compact()
+    }
+}
+
+impl TrieDb for FileTrieDb {
+    fn get_node(&mut self, key: &[u8]) -> Option<Bytes> {
+        self.nodes.get(key)
+    }
+
+    fn update_node(&mut self, key: &[u8], value: Bytes) {
+        self.nodes
+            .insert(key, value)
+            .expect("failed to write trie node");
+    }
+
+    fn get_preimage(&mut self, key: &[u8]) -> Option<Bytes> {
+        self.preimages.get(key)
+    }
+
+    fn update_preimage(&mut self, key: &[u8], value: Bytes) {
+        self.preimages
+            .insert(key, value)
+            .expect("failed to write trie preimage");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{file_db::FileTrieDb, storage::TrieStorage, types::TrieDb, zktrie::ZkTrieStateDb};
+    use fluentbase_types::Bytes;
+    use std::{fs::OpenOptions, io::Write, path::PathBuf};
+
+    fn test_dir(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "fluentbase-file-db-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn test_resume_state() {
+        let path = test_dir("resume");
+        let root = {
+            let db = FileTrieDb::open(&path).unwrap();
+            let mut zkt = ZkTrieStateDb::new_empty(db);
+            zkt.update(&[1u8; 32], 0, &vec![[2u8; 32], [3u8; 32]])
+                .unwrap();
+            zkt.update_preimage(&[4u8; 32], Bytes::from_static(&[5, 6, 7]));
+            zkt.compute_root()
+        };
+        // state isn't committed, so root isn't stored
+        assert_eq!(FileTrieDb::open(&path).unwrap().root().unwrap(), None);
+        let mut db = FileTrieDb::open(&path).unwrap();
+        db.commit(&root).unwrap();
+        // reopen database and check the state
+        let mut db = FileTrieDb::open(&path).unwrap();
+        let stored_root = db.root().unwrap().unwrap();
+        assert_eq!(stored_root, root);
+        db.compact().unwrap();
+        let mut zkt = ZkTrieStateDb::new_opened(db, &stored_root);
+        assert_eq!(zkt.compute_root(), root);
+        assert_eq!(zkt.get(&[1u8; 32]), Some((vec![[2u8; 32], [3u8; 32]], 0)));
+        assert_eq!(
+            zkt.get_preimage(&[4u8; 32]),
+            Some(Bytes::from_static(&[5, 6, 7]))
+        );
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_torn_write() {
+        let path = test_dir("torn");
+        {
+            let mut db = FileTrieDb::open(&path).unwrap();
+            db.update_node(&[1u8; 32], Bytes::from_static(&[1, 2, 3]));
+            db.commit(&[0u8; 32]).unwrap();
+        }
+        // emulate crash in the middle of the record write
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(path.join("nodes.log"))
+            .unwrap();
+        file.write_all(&[32, 0, 0, 0, 3, 0, 0, 0, 1, 1]).unwrap();
+        drop(file);
+        let mut db = FileTrieDb::open(&path).unwrap();
+        assert_eq!(
+            db.get_node(&[1u8; 32]),
+            Some(Bytes::from_static(&[1, 2, 3]))
+        );
+        // new records are appended after the valid prefix
+        db.update_node(&[2u8; 32], Bytes::from_static(&[4, 5]));
+        db.commit(&[0u8; 32]).unwrap();
+        let mut db = FileTrieDb::open(&path).unwrap();
+        assert_eq!(db.get_node(&[2u8; 32]), Some(Bytes::from_static(&[4, 5])));
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+}
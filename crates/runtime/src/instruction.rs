@@ -3,6 +3,9 @@ pub mod crypto_keccak256;
 pub mod crypto_poseidon;
 pub mod crypto_poseidon2;
 pub mod debug_log;
+pub mod evm_jump_table;
+pub mod evm_to_rwasm;
+pub mod evm_to_rwasm_size;
 pub mod jzkt_checkpoint;
 pub mod jzkt_commit;
 pub mod jzkt_compute_root;
@@ -13,11 +16,15 @@ pub mod jzkt_preimage_copy;
 pub mod jzkt_preimage_size;
 pub mod jzkt_remove;
 pub mod jzkt_rollback;
+pub mod jzkt_tload;
+pub mod jzkt_tstore;
 pub mod jzkt_update;
 pub mod jzkt_update_preimage;
 pub mod sys_exec_hash;
+pub mod sys_exec_hash64;
 pub mod sys_forward_output;
 pub mod sys_fuel;
+pub mod sys_fuel_conversion;
 pub mod sys_halt;
 pub mod sys_input_size;
 pub mod sys_output_size;
@@ -36,6 +43,9 @@ use crate::{
         crypto_poseidon::CryptoPoseidon,
         crypto_poseidon2::CryptoPoseidon2,
         debug_log::DebugLog,
+        evm_jump_table::EvmJumpTable,
+        evm_to_rwasm::EvmToRwasm,
+        evm_to_rwasm_size::EvmToRwasmSize,
         jzkt_checkpoint::JzktCheckpoint,
         jzkt_commit::JzktCommit,
         jzkt_compute_root::JzktComputeRoot,
@@ -46,11 +56,15 @@ use crate::{
         jzkt_preimage_size::JzktPreimageSize,
         jzkt_remove::JzktRemove,
         jzkt_rollback::JzktRollback,
+        jzkt_tload::JzktTload,
+        jzkt_tstore::JzktTstore,
         jzkt_update::JzktUpdate,
         jzkt_update_preimage::JzktUpdatePreimage,
         sys_exec_hash::SysExecHash,
+        sys_exec_hash64::SysExecHash64,
         sys_forward_output::SysForwardOutput,
         sys_fuel::SysFuel,
+        sys_fuel_conversion::SysFuelConversion,
         sys_halt::SysHalt,
         sys_input_size::SysInputSize,
         sys_output_size::SysOutputSize,
@@ -63,8 +77,8 @@ use crate::{
     },
     RuntimeContext,
 };
-use fluentbase_types::{IJournaledTrie, SysFuncIdx};
-use rwasm::{Caller, Linker, Store};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
+use rwasm::{core::Trap, errors::FuelError, Caller, Linker, Store};
 
 pub trait RuntimeHandler {
     const MODULE_NAME: &'static str;
@@ -77,6 +91,25 @@ pub trait RuntimeHandler {
     );
 }
 
+/// Charges fuel for the host function call with input of `len` bytes according to the fuel
+/// schedule of the runtime context.
+pub(crate) fn charge_host_fuel<DB: IJournaledTrie>(
+    caller: &mut Caller<'_, RuntimeContext<DB>>,
+    func_idx: SysFuncIdx,
+    len: u64,
+) -> Result<(), Trap> {
+    let fuel_cost = caller
+        .data()
+        .fuel_schedule
+        .fuel_cost(func_idx, len)
+        .ok_or(ExitCode::OutOfFuel.into_trap())?;
+    match caller.consume_fuel(fuel_cost) {
+        Ok(_) => Ok(()),
+        Err(FuelError::FuelMeteringDisabled) => Ok(()),
+        Err(FuelError::OutOfFuel) => Err(ExitCode::OutOfFuel.into_trap()),
+    }
+}
+
 impl_runtime_handler!(CryptoKeccak256, CRYPTO_KECCAK256, fn fluentbase_v1alpha::_crypto_keccak256(data_offset: u32, data_len: u32, output_offset: u32) -> ());
 impl_runtime_handler!(CryptoPoseidon, CRYPTO_POSEIDON, fn fluentbase_v1alpha::_crypto_poseidon(f32s_offset: u32, f32s_len: u32, output_offset: u32) -> ());
 impl_runtime_handler!(CryptoPoseidon2, CRYPTO_POSEIDON2, fn fluentbase_v1alpha::_crypto_poseidon2(fa32_offset: u32, fb32_offset: u32, fd32_offset: u32, output_offset: u32) -> ());
@@ -90,8 +123,10 @@ impl_runtime_handler!(SysOutputSize, SYS_OUTPUT_SIZE, fn fluentbase_v1alpha::_sy
 impl_runtime_handler!(SysReadOutput, SYS_READ_OUTPUT, fn fluentbase_v1alpha::_sys_read_output(target: u32, offset: u32, length: u32) -> ());
 impl_runtime_handler!(SysState, SYS_STATE, fn fluentbase_v1alpha::_sys_state() -> u32);
 impl_runtime_handler!(SysExecHash, SYS_EXEC_HASH, fn fluentbase_v1alpha::_sys_exec_hash(code_hash32_offset: u32, input_offset: u32, input_len: u32, return_offset: u32, return_len: u32, fuel_offset: u32, state: u32) -> i32);
+impl_runtime_handler!(SysExecHash64, SYS_EXEC_HASH64, fn fluentbase_v1alpha::_sys_exec_hash64(code_hash32_offset: u32, input_offset: u32, input_len: u32, return_offset: u32, return_len: u32, fuel_offset: u32, state: u32) -> i32);
 impl_runtime_handler!(SysForwardOutput, SYS_FORWARD_OUTPUT, fn fluentbase_v1alpha::_sys_forward_output(offset: u32, len: u32) -> ());
 impl_runtime_handler!(SysFuel, SYS_FUEL, fn fluentbase_v1alpha::_sys_fuel(delta: u64) -> u64);
+impl_runtime_handler!(SysFuelConversion, SYS_FUEL_CONVERSION, fn fluentbase_v1alpha::_sys_fuel_conversion(output9_offset: u32) -> ());
 
 impl_runtime_handler!(JzktOpen, JZKT_OPEN, fn fluentbase_v1alpha::_zktrie_open(root32_offset: u32) -> ());
 impl_runtime_handler!(JzktCheckpoint, JZKT_CHECKPOINT, fn fluentbase_v1alpha::_jzkt_checkpoint() -> u64);
@@ -105,9 +140,14 @@ impl_runtime_handler!(JzktRollback, JZKT_ROLLBACK, fn fluentbase_v1alpha::_jzkt_
 impl_runtime_handler!(JzktPreimageSize, JZKT_PREIMAGE_SIZE, fn fluentbase_v1alpha::_jzkt_preimage_size(hash32_ptr: u32) -> u32);
 impl_runtime_handler!(JzktPreimageCopy, JZKT_PREIMAGE_COPY, fn fluentbase_v1alpha::_jzkt_preimage_copy(hash32_ptr: u32, preimage_ptr: u32) -> ());
 impl_runtime_handler!(JzktUpdatePreimage, JZKT_UPDATE_PREIMAGE, fn fluentbase_v1alpha::_jzkt_update_preimage(key32_ptr: u32, field: u32, preimage_ptr: u32, preimage_len: u32) -> i32);
+impl_runtime_handler!(JzktTload, JZKT_TLOAD, fn fluentbase_v1alpha::_jzkt_tload(key32_offset: u32, output32_offset: u32) -> ());
+impl_runtime_handler!(JzktTstore, JZKT_TSTORE, fn fluentbase_v1alpha::_jzkt_tstore(key32_offset: u32, value32_offset: u32) -> ());
 
 impl_runtime_handler!(WasmToRwasmSize, WASM_TO_RWASM_SIZE, fn fluentbase_v1alpha::_wasm_to_rwasm_size(input_offset: u32, input_len: u32) -> i32);
 impl_runtime_handler!(WasmToRwasm, WASM_TO_RWASM, fn fluentbase_v1alpha::_wasm_to_rwasm(input_offset: u32, input_len: u32, output_offset: u32, output_len: u32) -> i32);
+impl_runtime_handler!(EvmToRwasmSize, EVM_TO_RWASM_SIZE, fn fluentbase_v1alpha::_evm_to_rwasm_size(input_offset: u32, input_len: u32) -> i32);
+impl_runtime_handler!(EvmToRwasm, EVM_TO_RWASM, fn fluentbase_v1alpha::_evm_to_rwasm(input_offset: u32, input_len: u32, output_offset: u32, output_len: u32) -> i32);
+impl_runtime_handler!(EvmJumpTable, EVM_JUMP_TABLE, fn fluentbase_v1alpha::_evm_jump_table(hash32_offset: u32, output_offset: u32, output_len: u32) -> i32);
 
 impl_runtime_handler!(DebugLog, DEBUG_LOG, fn fluentbase_v1alpha::_debug_log(msg_offset: u32, msg_len: u32) -> ());
 
@@ -127,8 +167,10 @@ fn runtime_register_handlers<DB: IJournaledTrie, const IS_SOVEREIGN: bool>(
     SysOutputSize::register_handler(linker, store);
     SysReadOutput::register_handler(linker, store);
     SysExecHash::register_handler(linker, store);
+    SysExecHash64::register_handler(linker, store);
     SysState::register_handler(linker, store);
     SysFuel::register_handler(linker, store);
+    SysFuelConversion::register_handler(linker, store);
     if IS_SOVEREIGN {
         JzktOpen::register_handler(linker, store);
         JzktCheckpoint::register_handler(linker, store);
@@ -145,10 +187,17 @@ fn runtime_register_handlers<DB: IJournaledTrie, const IS_SOVEREIGN: bool>(
     if IS_SOVEREIGN {
         JzktPreimageSize::register_handler(linker, store);
         JzktUpdatePreimage::register_handler(linker, store);
+        JzktTload::register_handler(linker, store);
+        JzktTstore::register_handler(linker, store);
     }
     JzktPreimageCopy::register_handler(linker, store);
     WasmToRwasmSize::register_handler(linker, store);
     WasmToRwasm::register_handler(linker, store);
+    if IS_SOVEREIGN {
+        EvmToRwasmSize::register_handler(linker, store);
+        EvmToRwasm::register_handler(linker, store);
+        EvmJumpTable::register_handler(linker, store);
+    }
     DebugLog::register_handler(linker, store);
 }
 
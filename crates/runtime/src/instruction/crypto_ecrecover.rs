@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::{ExitCode, IJournaledTrie};
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
 use k256::{
     ecdsa::{RecoveryId, Signature, VerifyingKey},
     elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint},
@@ -18,6 +18,7 @@ impl CryptoEcrecover {
         output65_offset: u32,
         rec_id: u32,
     ) -> Result<(), Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::CRYPTO_ECRECOVER, 0)?;
         let digest = caller.read_memory(digest32_offset, 32)?;
         let sig = caller.read_memory(sig64_offset, 64)?;
         let public_key = Self::fn_impl(digest, sig, rec_id).map_err(|err| err.into_trap())?;
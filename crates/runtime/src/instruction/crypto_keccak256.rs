@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::IJournaledTrie;
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{IJournaledTrie, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct CryptoKeccak256;
@@ -11,6 +11,7 @@ impl CryptoKeccak256 {
         data_len: u32,
         output_offset: u32,
     ) -> Result<(), Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::CRYPTO_KECCAK256, data_len as u64)?;
         let data = caller.read_memory(data_offset, data_len)?;
         caller.write_memory(output_offset, &Self::fn_impl(data))?;
         Ok(())
@@ -1,6 +1,6 @@
-use crate::RuntimeContext;
+use crate::{instruction::charge_host_fuel, RuntimeContext};
 use fluentbase_poseidon::hash_with_domain;
-use fluentbase_types::{ExitCode, IJournaledTrie};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
 use halo2curves::{bn256::Fr, group::ff::PrimeField};
 use rwasm::{core::Trap, Caller};
 
@@ -14,6 +14,7 @@ impl CryptoPoseidon2 {
         fd_offset: u32,
         output_offset: u32,
     ) -> Result<(), Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::CRYPTO_POSEIDON2, 0)?;
         let output = Self::fn_impl(
             caller.read_memory(fa_offset, 32)?,
             caller.read_memory(fb_offset, 32)?,
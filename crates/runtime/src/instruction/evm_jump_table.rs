@@ -0,0 +1,34 @@
+use crate::{instruction::charge_host_fuel, CachingRuntime, RuntimeContext};
+use fluentbase_types::{IJournaledTrie, SysFuncIdx};
+use rwasm::{core::Trap, Caller};
+use std::sync::Arc;
+
+pub struct EvmJumpTable;
+
+impl EvmJumpTable {
+    pub fn fn_handler<DB: IJournaledTrie>(
+        mut caller: Caller<'_, RuntimeContext<DB>>,
+        hash32_offset: u32,
+        output_offset: u32,
+        output_len: u32,
+    ) -> Result<i32, Trap> {
+        let hash: [u8; 32] = caller.read_memory(hash32_offset, 32)?.try_into().unwrap();
+        let (code_len, jump_table) = Self::fn_impl(caller.data_mut(), &hash);
+        // the cost depends on the code length only, so it's the same for the cached jump tables
+        charge_host_fuel(&mut caller, SysFuncIdx::EVM_JUMP_TABLE, code_len as u64)?;
+        let len = jump_table.len().min(output_len as usize);
+        if len > 0 {
+            caller.write_memory(output_offset, &jump_table[..len])?;
+        }
+        Ok(jump_table.len() as i32)
+    }
+
+    pub fn fn_impl<DB: IJournaledTrie>(
+        ctx: &mut RuntimeContext<DB>,
+        hash: &[u8; 32],
+    ) -> (usize, Arc<[u8]>) {
+        CachingRuntime::with_shared(|caching_runtime| {
+            caching_runtime.resolve_jump_table(hash, || ctx.jzkt().preimage(hash))
+        })
+    }
+}
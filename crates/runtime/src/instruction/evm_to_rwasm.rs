@@ -0,0 +1,41 @@
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
+use rwasm::{core::Trap, Caller};
+
+pub struct EvmToRwasm;
+
+impl EvmToRwasm {
+    pub fn fn_handler<DB: IJournaledTrie>(
+        mut caller: Caller<'_, RuntimeContext<DB>>,
+        input_offset: u32,
+        input_len: u32,
+        output_offset: u32,
+        output_len: u32,
+    ) -> Result<i32, Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::EVM_TO_RWASM, input_len as u64)?;
+        let evm_bytecode = caller.read_memory(input_offset, input_len)?.to_vec();
+        // unsupported bytecode isn't a failure of the caller, it can fall back to the interpreter
+        let rwasm_binary = match Self::fn_impl(caller.data_mut(), &evm_bytecode, output_len) {
+            Ok(rwasm_binary) => rwasm_binary,
+            Err(exit_code) => return Ok(exit_code.into_i32()),
+        };
+        if output_len > 0 {
+            caller.write_memory(output_offset, &rwasm_binary[0..output_len as usize])?;
+        }
+        Ok(ExitCode::Ok.into_i32())
+    }
+
+    pub fn fn_impl<DB: IJournaledTrie>(
+        ctx: &mut RuntimeContext<DB>,
+        evm_bytecode: &[u8],
+        output_len: u32,
+    ) -> Result<Vec<u8>, ExitCode> {
+        let evm_translator = ctx.evm_translator.ok_or(ExitCode::NotSupportedCall)?;
+        let rwasm_binary = evm_translator(evm_bytecode)?;
+        if output_len > 0 && output_len < rwasm_binary.len() as u32 {
+            return Err(ExitCode::OutputOverflow);
+        }
+        ctx.execution_result.return_data = rwasm_binary.clone();
+        Ok(rwasm_binary)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    instruction::{charge_host_fuel, evm_to_rwasm::EvmToRwasm},
+    RuntimeContext,
+};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
+use rwasm::{core::Trap, Caller};
+
+pub struct EvmToRwasmSize;
+
+impl EvmToRwasmSize {
+    pub fn fn_handler<DB: IJournaledTrie>(
+        mut caller: Caller<'_, RuntimeContext<DB>>,
+        input_offset: u32,
+        input_len: u32,
+    ) -> Result<i32, Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::EVM_TO_RWASM_SIZE, input_len as u64)?;
+        let evm_bytecode = caller.read_memory(input_offset, input_len)?.to_vec();
+        // negative exit code is returned if the bytecode can't be translated
+        Ok(Self::fn_impl(caller.data_mut(), &evm_bytecode).unwrap_or_else(|err| err.into_i32()))
+    }
+
+    pub fn fn_impl<DB: IJournaledTrie>(
+        ctx: &mut RuntimeContext<DB>,
+        evm_bytecode: &[u8],
+    ) -> Result<i32, ExitCode> {
+        let size = EvmToRwasm::fn_impl(ctx, evm_bytecode, 0)?.len();
+        Ok(size as i32)
+    }
+}
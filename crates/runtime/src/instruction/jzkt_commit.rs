@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::{ExitCode, IJournaledTrie};
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktCommit;
@@ -9,6 +9,7 @@ impl JzktCommit {
         mut caller: Caller<'_, RuntimeContext<DB>>,
         root32_offset: u32,
     ) -> Result<(), Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::JZKT_COMMIT, 0)?;
         let output = Self::fn_impl(caller.data_mut()).map_err(|err| err.into_trap())?;
         let _ = caller.write_memory(root32_offset, &output)?;
         Ok(())
@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::IJournaledTrie;
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{IJournaledTrie, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktComputeRoot;
@@ -9,6 +9,7 @@ impl JzktComputeRoot {
         mut caller: Caller<'_, RuntimeContext<DB>>,
         output32_offset: u32,
     ) -> Result<(), Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::JZKT_COMPUTE_ROOT, 0)?;
         let root = Self::fn_impl(caller.data_mut());
         caller.write_memory(output32_offset, &root)?;
         Ok(())
@@ -1,17 +1,10 @@
-use crate::RuntimeContext;
-use fluentbase_types::{Address, Bytes, IJournaledTrie, B256};
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{Address, Bytes, IJournaledTrie, SysFuncIdx, B256};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktEmitLog;
 
 impl JzktEmitLog {
-    pub fn fn_fuel_cost(n: u8, len: u64) -> Option<u64> {
-        // TODO(dmitry123): "how we can replace it with constants from EVM lib? do we need?"
-        375u64
-            .checked_add(8u64.checked_mul(len)?)?
-            .checked_add(375u64 * n as u64)
-    }
-
     pub fn fn_handler<DB: IJournaledTrie>(
         mut caller: Caller<'_, RuntimeContext<DB>>,
         address20_ptr: u32,
@@ -20,15 +13,10 @@ impl JzktEmitLog {
         data_ptr: u32,
         data_len: u32,
     ) -> Result<(), Trap> {
-        // let fuel_cost = Self::fn_fuel_cost((topics32s_len / 32) as u8, data_len as u64)
-        //     .ok_or(ExitCode::OutOfFuel.into_trap())?;
-        // match caller.consume_fuel(fuel_cost) {
-        //     Ok(_) => {}
-        //     Err(err) => match err {
-        //         FuelError::OutOfFuel => return Err(ExitCode::OutOfFuel.into_trap()),
-        //         _ => {}
-        //     },
-        // }
+        // topics are charged together with the data, a topic is 32 bytes long, so it costs exactly
+        // one word on top of the base cost
+        let len = topics32s_len as u64 + data_len as u64;
+        charge_host_fuel(&mut caller, SysFuncIdx::JZKT_EMIT_LOG, len)?;
         let address = Address::from_slice(caller.read_memory(address20_ptr, 20)?);
         let topics = caller
             .read_memory(topics32s_ptr, topics32s_len)?
@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::IJournaledTrie;
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{IJournaledTrie, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktGet;
@@ -12,6 +12,7 @@ impl JzktGet {
         output32_offset: u32,
         committed: u32,
     ) -> Result<u32, Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::JZKT_GET, 0)?;
         let key = caller.read_memory(key32_offset, 32)?.to_vec();
         let is_cold = match Self::fn_impl(caller.data_mut(), &key, field, committed != 0) {
             Some((value, is_cold)) => {
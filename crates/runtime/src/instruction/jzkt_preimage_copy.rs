@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::{ExitCode, IJournaledTrie};
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktPreimageCopy;
@@ -11,6 +11,16 @@ impl JzktPreimageCopy {
         preimage_ptr: u32,
     ) -> Result<(), Trap> {
         let hash = caller.read_memory(hash32_ptr, 32)?.to_vec();
+        // size lookup is cheap, so we charge for the copy before loading the preimage
+        let preimage_size = caller
+            .data_mut()
+            .jzkt()
+            .preimage_size(hash.as_slice().try_into().unwrap());
+        charge_host_fuel(
+            &mut caller,
+            SysFuncIdx::JZKT_PREIMAGE_COPY,
+            preimage_size as u64,
+        )?;
         let preimage = Self::fn_impl(caller.data_mut(), &hash).map_err(|err| err.into_trap())?;
         caller.write_memory(preimage_ptr, &preimage)?;
         Ok(())
@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::{ExitCode, IJournaledTrie};
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktRemove;
@@ -9,6 +9,7 @@ impl JzktRemove {
         mut caller: Caller<'_, RuntimeContext<DB>>,
         key32_offset: u32,
     ) -> Result<(), Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::JZKT_REMOVE, 0)?;
         let key = caller.read_memory(key32_offset, 32)?.to_vec();
         Self::fn_impl(caller.data_mut(), &key).map_err(|err| err.into_trap())?;
         Ok(())
@@ -0,0 +1,26 @@
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
+use rwasm::{core::Trap, Caller};
+
+pub struct JzktTload;
+
+impl JzktTload {
+    pub fn fn_handler<DB: IJournaledTrie>(
+        mut caller: Caller<'_, RuntimeContext<DB>>,
+        key32_offset: u32,
+        output32_offset: u32,
+    ) -> Result<(), Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::JZKT_TLOAD, 0)?;
+        let key = caller.read_memory(key32_offset, 32)?.to_vec();
+        let value = Self::fn_impl(caller.data_mut(), &key).map_err(|err| err.into_trap())?;
+        caller.write_memory(output32_offset, &value)?;
+        Ok(())
+    }
+
+    pub fn fn_impl<DB: IJournaledTrie>(
+        ctx: &mut RuntimeContext<DB>,
+        key: &[u8],
+    ) -> Result<[u8; 32], ExitCode> {
+        Ok(ctx.jzkt().transient(key.try_into().unwrap()))
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
+use rwasm::{core::Trap, Caller};
+
+pub struct JzktTstore;
+
+impl JzktTstore {
+    pub fn fn_handler<DB: IJournaledTrie>(
+        mut caller: Caller<'_, RuntimeContext<DB>>,
+        key32_offset: u32,
+        value32_offset: u32,
+    ) -> Result<(), Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::JZKT_TSTORE, 0)?;
+        let key = caller.read_memory(key32_offset, 32)?.to_vec();
+        let value = caller.read_memory(value32_offset, 32)?.to_vec();
+        Self::fn_impl(caller.data_mut(), &key, &value).map_err(|err| err.into_trap())?;
+        Ok(())
+    }
+
+    pub fn fn_impl<DB: IJournaledTrie>(
+        ctx: &mut RuntimeContext<DB>,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<(), ExitCode> {
+        ctx.jzkt()
+            .update_transient(key.try_into().unwrap(), value.try_into().unwrap());
+        Ok(())
+    }
+}
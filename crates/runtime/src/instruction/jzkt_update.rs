@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::{ExitCode, IJournaledTrie};
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktUpdate;
@@ -12,6 +12,7 @@ impl JzktUpdate {
         vals32_offset: u32,
         vals32_len: u32,
     ) -> Result<(), Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::JZKT_UPDATE, vals32_len as u64)?;
         let key = caller.read_memory(key32_offset, 32)?.to_vec();
         let vals32 = caller
             .read_memory(vals32_offset, vals32_len)?
@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::{ExitCode, IJournaledTrie};
+use crate::{instruction::charge_host_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktUpdatePreimage;
@@ -12,6 +12,11 @@ impl JzktUpdatePreimage {
         preimage_ptr: u32,
         preimage_len: u32,
     ) -> Result<i32, Trap> {
+        charge_host_fuel(
+            &mut caller,
+            SysFuncIdx::JZKT_UPDATE_PREIMAGE,
+            preimage_len as u64,
+        )?;
         let key = caller.read_memory(key32_ptr, 32)?.to_vec();
         let preimage = caller.read_memory(preimage_ptr, preimage_len)?.to_vec();
         let res = Self::fn_impl(caller.data_mut(), &key, field, &preimage)
@@ -22,6 +22,8 @@ pub struct SysExecHashResumable {
     pub return_len: u32,
     pub fuel_offset: u32,
     pub state: u32,
+    /// Fuel is passed as `u64` (`_sys_exec_hash64`), otherwise it's `u32` (`_sys_exec_hash`)
+    pub is_fuel64: bool,
 }
 
 impl Display for SysExecHashResumable {
@@ -51,6 +53,7 @@ impl SysExecHash {
             return_len,
             fuel_offset,
             state,
+            is_fuel64: false,
         }
         .into());
     }
@@ -66,24 +69,36 @@ impl SysExecHash {
         let input = caller
             .read_memory(context.input_offset, context.input_len)?
             .to_vec();
-        let fuel_data = caller.read_memory(context.fuel_offset, 4)?;
-        let fuel_limit = LittleEndian::read_u32(fuel_data);
-        let exit_code = match Self::fn_impl(
+        let fuel_limit = if context.is_fuel64 {
+            LittleEndian::read_u64(caller.read_memory(context.fuel_offset, 8)?)
+        } else {
+            LittleEndian::read_u32(caller.read_memory(context.fuel_offset, 4)?) as u64
+        };
+        let result = Self::fn_impl(
             caller.data_mut(),
             &bytecode_hash32,
             input,
-            context.return_len,
-            fuel_limit as u64,
+            fuel_limit,
             context.state,
-        ) {
+        );
+        // output of the failed call is copied too, because it can contain revert reason
+        if context.return_len > 0 {
+            let return_data = caller.data().execution_result.return_data.clone();
+            let len = return_data.len().min(context.return_len as usize);
+            caller.write_memory(context.return_offset, &return_data[..len])?;
+        }
+        let exit_code = match result {
             Ok(remaining_fuel) => {
-                if context.return_len > 0 {
-                    let return_data = caller.data().execution_result.return_data.clone();
-                    caller.write_memory(context.return_offset, &return_data)?;
+                if context.is_fuel64 {
+                    let mut fuel_buffer = [0u8; 8];
+                    LittleEndian::write_u64(&mut fuel_buffer, remaining_fuel);
+                    caller.write_memory(context.fuel_offset, &fuel_buffer)?;
+                } else {
+                    // remaining fuel can't exceed the fuel limit, so it always fits into u32
+                    let mut fuel_buffer = [0u8; 4];
+                    LittleEndian::write_u32(&mut fuel_buffer, remaining_fuel as u32);
+                    caller.write_memory(context.fuel_offset, &fuel_buffer)?;
                 }
-                let mut fuel_buffer = [0u8; 4];
-                LittleEndian::write_u32(&mut fuel_buffer, remaining_fuel as u32);
-                caller.write_memory(context.fuel_offset, &fuel_buffer)?;
                 ExitCode::Ok.into_i32()
             }
             Err(err) => err,
@@ -95,7 +110,6 @@ impl SysExecHash {
         ctx: &mut RuntimeContext<DB>,
         bytecode_hash32: &[u8; 32],
         input: Vec<u8>,
-        return_len: u32,
         fuel_limit: u64,
         state: u32,
     ) -> Result<u64, i32> {
@@ -104,14 +118,17 @@ impl SysExecHash {
             .unwrap()
             .as_millis();
 
-        // take jzkt from the existing context (we will return it back soon)
-        let jzkt = take(&mut ctx.jzkt).expect("jzkt is not initialized");
+        // return data of the previous call is cleared on every new call
+        ctx.execution_result.return_data.clear();
 
         // check call depth overflow
         if ctx.depth + 1 >= 1024 {
             return Err(ExitCode::CallDepthOverflow.into_i32());
         }
 
+        // take jzkt from the existing context (we will return it back soon)
+        let jzkt = take(&mut ctx.jzkt).expect("jzkt is not initialized");
+
         // create new runtime instance with the context
         let ctx2 = RuntimeContext::new_with_hash(bytecode_hash32.into())
             .with_input(input)
@@ -120,7 +137,12 @@ impl SysExecHash {
             .with_fuel_limit(fuel_limit)
             .with_jzkt(jzkt)
             .with_state(state)
-            .with_depth(ctx.depth + 1);
+            .with_depth(ctx.depth + 1)
+            .with_tracing(ctx.is_tracing)
+            .with_module_cache(ctx.use_module_cache)
+            .with_fuel_schedule(ctx.fuel_schedule.clone())
+            .with_fuel_conversion(ctx.fuel_conversion)
+            .with_evm_translator(ctx.evm_translator);
         let mut runtime = Runtime::new(ctx2);
         let execution_result = runtime
             .call()
@@ -129,14 +151,17 @@ impl SysExecHash {
         // return jzkt context back
         ctx.jzkt = take(&mut runtime.store.data_mut().jzkt);
 
-        // make sure there is no return overflow
-        if return_len > 0 && execution_result.output.len() > return_len as usize {
-            return Err(ExitCode::OutputOverflow.into_i32());
+        // attach nested trace to the parent one
+        if let (Some(trace), Some(nested_trace)) =
+            (ctx.trace.as_mut(), runtime.store.data_mut().take_trace())
+        {
+            trace.nested_calls.push(nested_trace);
         }
 
         // TODO(dmitry123): "do we need to put any fuel penalties for failed calls?"
 
-        // increase total fuel consumed and remember return data
+        // increase total fuel consumed and remember return data, output that doesn't fit into
+        // the return buffer is truncated, but the whole output stays available in return data
         ctx.execution_result.fuel_consumed += execution_result.fuel_consumed;
         ctx.execution_result.return_data = execution_result.output.clone();
 
@@ -0,0 +1,32 @@
+use crate::{instruction::sys_exec_hash::SysExecHashResumable, RuntimeContext};
+use fluentbase_types::IJournaledTrie;
+use rwasm::{core::Trap, Caller};
+
+/// The same as `_sys_exec_hash`, but fuel is passed as `u64`, so the fuel limit isn't truncated.
+/// Both functions are served, because already deployed contracts pass fuel as `u32`.
+pub struct SysExecHash64;
+
+impl SysExecHash64 {
+    pub fn fn_handler<DB: IJournaledTrie>(
+        _caller: Caller<'_, RuntimeContext<DB>>,
+        bytecode_hash32_offset: u32,
+        input_offset: u32,
+        input_len: u32,
+        return_offset: u32,
+        return_len: u32,
+        fuel_offset: u32,
+        state: u32,
+    ) -> Result<i32, Trap> {
+        return Err(SysExecHashResumable {
+            bytecode_hash32_offset,
+            input_offset,
+            input_len,
+            return_offset,
+            return_len,
+            fuel_offset,
+            state,
+            is_fuel64: true,
+        }
+        .into());
+    }
+}
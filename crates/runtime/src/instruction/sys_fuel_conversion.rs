@@ -0,0 +1,20 @@
+use crate::RuntimeContext;
+use fluentbase_types::{FuelConversion, IJournaledTrie};
+use rwasm::{core::Trap, Caller};
+
+pub struct SysFuelConversion;
+
+impl SysFuelConversion {
+    pub fn fn_handler<DB: IJournaledTrie>(
+        mut caller: Caller<'_, RuntimeContext<DB>>,
+        output9_offset: u32,
+    ) -> Result<(), Trap> {
+        let fuel_conversion = Self::fn_impl(caller.data());
+        caller.write_memory(output9_offset, &fuel_conversion.to_bytes())?;
+        Ok(())
+    }
+
+    pub fn fn_impl<DB: IJournaledTrie>(ctx: &RuntimeContext<DB>) -> FuelConversion {
+        ctx.fuel_conversion
+    }
+}
@@ -21,12 +21,11 @@ impl SysReadOutput {
         offset: u32,
         length: u32,
     ) -> Result<Vec<u8>, ExitCode> {
-        if offset + length <= ctx.execution_result.return_data.len() as u32 {
-            Ok(ctx.execution_result.return_data
-                [(offset as usize)..(offset as usize + length as usize)]
-                .to_vec())
-        } else {
-            Err(ExitCode::MemoryOutOfBounds)
+        match offset.checked_add(length) {
+            Some(end) if end <= ctx.execution_result.return_data.len() as u32 => {
+                Ok(ctx.execution_result.return_data[(offset as usize)..(end as usize)].to_vec())
+            }
+            _ => Err(ExitCode::OutputOverflow),
         }
     }
 }
@@ -1,8 +1,9 @@
-use crate::RuntimeContext;
+use crate::{instruction::charge_host_fuel, RuntimeContext};
 use fluentbase_types::{
     create_sovereign_import_linker,
     ExitCode,
     IJournaledTrie,
+    SysFuncIdx,
     SysFuncIdx::SYS_STATE,
     STATE_DEPLOY,
     STATE_MAIN,
@@ -50,6 +51,7 @@ impl WasmToRwasm {
         output_offset: u32,
         output_len: u32,
     ) -> Result<i32, Trap> {
+        charge_host_fuel(&mut caller, SysFuncIdx::WASM_TO_RWASM, input_len as u64)?;
         let wasm_binary = caller.read_memory(input_offset, input_len)?.to_vec();
         let rwasm_binary = Self::fn_impl(caller.data_mut(), &wasm_binary, output_len)
             .map_err(|v| v.into_trap())?;
@@ -1,5 +1,8 @@
-use crate::{instruction::wasm_to_rwasm::WasmToRwasm, RuntimeContext};
-use fluentbase_types::{ExitCode, IJournaledTrie};
+use crate::{
+    instruction::{charge_host_fuel, wasm_to_rwasm::WasmToRwasm},
+    RuntimeContext,
+};
+use fluentbase_types::{ExitCode, IJournaledTrie, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct WasmToRwasmSize;
@@ -10,6 +13,11 @@ impl WasmToRwasmSize {
         input_offset: u32,
         input_len: u32,
     ) -> Result<i32, Trap> {
+        charge_host_fuel(
+            &mut caller,
+            SysFuncIdx::WASM_TO_RWASM_SIZE,
+            input_len as u64,
+        )?;
         let wasm_binary = caller.read_memory(input_offset, input_len)?.to_vec();
         Self::fn_impl(caller.data_mut(), &wasm_binary).map_err(|err| err.into_trap())
     }
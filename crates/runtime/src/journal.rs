@@ -10,6 +10,8 @@ use fluentbase_types::{
     JournalEvent,
     JournalLog,
     B256,
+    BLOCK_HASH_HISTORY_ADDRESS,
+    BLOCK_HASH_HISTORY_SIZE,
 };
 use halo2curves::bn256::Fr;
 use hashbrown::HashMap;
@@ -30,10 +32,18 @@ macro_rules! bytes32 {
     }};
 }
 
+/// Domain of the storage key hash, equals to the domain used by `calc_storage_key` in the SDK
+const STORAGE_KEY_DOMAIN: [u8; 32] = {
+    let mut domain = [0u8; 32];
+    domain[23] = 1;
+    domain
+};
+
 struct JournalTrieInner<DB: TrieStorage> {
     storage: DB,
     state: HashMap<[u8; 32], usize>,
     preimages: HashMap<[u8; 32], Vec<u8>>,
+    transient: HashMap<[u8; 32], [u8; 32]>,
     logs: Vec<JournalLog>,
     journal: Vec<JournalEvent>,
     root: [u8; 32],
@@ -87,6 +97,22 @@ impl<DB: TrieStorage> JournalTrieInner<DB> {
         self.storage.compute_root()
     }
 
+    fn proof(&self, root: &[u8; 32], key: &[u8; 32]) -> Option<Vec<Vec<u8>>> {
+        self.storage.proof_at(root, key)
+    }
+
+    fn transient(&self, key: &[u8; 32]) -> [u8; 32] {
+        self.transient.get(key).copied().unwrap_or_default()
+    }
+
+    fn update_transient(&mut self, key: &[u8; 32], value: &[u8; 32]) {
+        self.journal.push(JournalEvent::TransientChanged {
+            key: *key,
+            value: *value,
+            prev_value: self.transient.insert(*key, *value),
+        });
+    }
+
     fn emit_log(&mut self, address: Address, topics: Vec<B256>, data: Bytes) {
         self.logs.push(JournalLog {
             address,
@@ -104,6 +130,7 @@ impl<DB: TrieStorage> JournalTrieInner<DB> {
             .journal
             .iter()
             .skip(self.committed)
+            .filter(|v| !v.is_transient())
             .map(|v| (*v.key(), v.preimage()))
             .collect::<HashMap<_, _>>()
             .into_iter()
@@ -144,13 +171,25 @@ impl<DB: TrieStorage> JournalTrieInner<DB> {
             .iter()
             .rev()
             .take(self.journal.len() - checkpoint.state())
-            .for_each(|v| match v.prev_state() {
-                Some(prev_state) => {
-                    self.state.insert(*v.key(), prev_state);
-                }
-                None => {
-                    self.state.remove(v.key());
-                }
+            .for_each(|v| match v {
+                JournalEvent::TransientChanged {
+                    key, prev_value, ..
+                } => match prev_value {
+                    Some(prev_value) => {
+                        self.transient.insert(*key, *prev_value);
+                    }
+                    None => {
+                        self.transient.remove(key);
+                    }
+                },
+                _ => match v.prev_state() {
+                    Some(prev_state) => {
+                        self.state.insert(*v.key(), prev_state);
+                    }
+                    None => {
+                        self.state.remove(v.key());
+                    }
+                },
             });
         self.journal.truncate(checkpoint.state());
         self.logs.truncate(checkpoint.logs());
@@ -194,6 +233,24 @@ impl<DB: TrieStorage> JournalTrieInner<DB> {
     }
 }
 
+/// Merkle proof of the storage slot, `proof` is a list of encoded trie nodes from the root.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StorageProof {
+    pub slot: [u8; 32],
+    pub key: [u8; 32],
+    pub proof: Vec<Vec<u8>>,
+}
+
+/// Merkle proof of the account and its storage slots (like `eth_getProof`), the account and
+/// the storage are stored in the same trie, so all proofs are verified against the state root.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountProof {
+    pub address: Address,
+    pub root: [u8; 32],
+    pub account_proof: Vec<Vec<u8>>,
+    pub storage_proofs: Vec<StorageProof>,
+}
+
 pub struct JournaledTrie<DB: TrieStorage> {
     inner: Arc<RwLock<JournalTrieInner<DB>>>,
 }
@@ -222,6 +279,7 @@ impl<DB: TrieStorage> JournaledTrie<DB> {
                 storage,
                 state: HashMap::new(),
                 preimages: HashMap::new(),
+                transient: HashMap::new(),
                 logs: Vec::new(),
                 journal: Vec::new(),
                 root,
@@ -252,11 +310,57 @@ impl<DB: TrieStorage> JournaledTrie<DB> {
     }
 
     pub fn storage_key(address: &Address, slot: &[u8; 32]) -> [u8; 32] {
-        // storage key is `p(address, p(slot_0, slot_1, d), d)`
-        let address = Fr::from_bytes(&address.into_word()).unwrap();
-        let slot = Self::compress_value(slot);
-        let key = hash_with_domain(&[address, slot], &Self::DOMAIN);
-        key.to_bytes()
+        // storage key is `p(address, p(slot_0, slot_1, d), d)`, it must match `calc_storage_key`
+        // from the SDK, the slot is little-endian and the address is padded to fit the field
+        let domain = Fr::from_bytes(&STORAGE_KEY_DOMAIN).unwrap();
+        let mut bytes32 = [0u8; 32];
+        bytes32[0..16].copy_from_slice(&slot[0..16]);
+        let slot0 = Fr::from_bytes(&bytes32).unwrap();
+        bytes32[0..16].copy_from_slice(&slot[16..]);
+        let slot1 = Fr::from_bytes(&bytes32).unwrap();
+        let slot = hash_with_domain(&[slot0, slot1], &domain);
+        let mut address32 = [0u8; 32];
+        address32[11..31].copy_from_slice(address.as_slice());
+        let address = Fr::from_bytes(&address32).unwrap();
+        hash_with_domain(&[address, slot], &domain).to_bytes()
+    }
+
+    /// Stores hash of the block in the block hash history, so it's available for `BLOCKHASH`
+    /// opcode, must be called by the host before the execution of the next block.
+    pub fn update_block_hash(&self, number: u64, hash: &B256) {
+        let mut slot = [0u8; 32];
+        slot[..8].copy_from_slice(&(number % BLOCK_HASH_HISTORY_SIZE).to_le_bytes());
+        let key = Self::storage_key(&BLOCK_HASH_HISTORY_ADDRESS, &slot);
+        // block hash doesn't fit into the field, so it must be compressed
+        self.update(&key, &vec![hash.0], 1);
+    }
+
+    /// Returns proofs of the account and its storage slots for the committed state with the
+    /// specified root, proofs can be checked with `fluentbase_zktrie::verify_proof`.
+    pub fn account_proof(
+        &self,
+        root: &[u8; 32],
+        address: &Address,
+        slots: &[[u8; 32]],
+    ) -> Option<AccountProof> {
+        let account_proof = self.proof(root, &address.into_word().0)?;
+        let storage_proofs = slots
+            .iter()
+            .map(|slot| {
+                let key = Self::storage_key(address, slot);
+                Some(StorageProof {
+                    slot: *slot,
+                    key,
+                    proof: self.proof(root, &key)?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(AccountProof {
+            address: *address,
+            root: *root,
+            account_proof,
+            storage_proofs,
+        })
     }
 }
 
@@ -285,6 +389,10 @@ impl<DB: TrieStorage> IJournaledTrie for JournaledTrie<DB> {
         self.inner.read().unwrap().compute_root()
     }
 
+    fn proof(&self, root: &[u8; 32], key: &[u8; 32]) -> Option<Vec<Vec<u8>>> {
+        self.inner.read().unwrap().proof(root, key)
+    }
+
     fn emit_log(&self, address: Address, topics: Vec<B256>, data: Bytes) {
         self.inner.write().unwrap().emit_log(address, topics, data)
     }
@@ -315,6 +423,18 @@ impl<DB: TrieStorage> IJournaledTrie for JournaledTrie<DB> {
     fn journal(&self) -> Vec<JournalEvent> {
         self.inner.write().unwrap().journal.clone()
     }
+
+    fn transient(&self, key: &[u8; 32]) -> [u8; 32] {
+        self.inner.read().unwrap().transient(key)
+    }
+
+    fn update_transient(&self, key: &[u8; 32], value: &[u8; 32]) {
+        self.inner.write().unwrap().update_transient(key, value)
+    }
+
+    fn clear_transient(&self) {
+        self.inner.write().unwrap().transient.clear()
+    }
 }
 
 #[cfg(test)]
@@ -326,7 +446,8 @@ mod tests {
         TrieStorage,
     };
     use fluentbase_poseidon::poseidon_hash;
-    use fluentbase_types::JournalCheckpoint;
+    use fluentbase_types::{Address, JournalCheckpoint};
+    use fluentbase_zktrie::{verify_proof, Hash, PoseidonHash, TrieData};
 
     fn calc_trie_root(values: Vec<([u8; 32], Vec<[u8; 32]>, u32)>) -> [u8; 32] {
         let db = InMemoryTrieDb::default();
@@ -337,6 +458,13 @@ mod tests {
         zktrie.compute_root()
     }
 
+    fn verify_value(root: &[u8; 32], key: &[u8; 32], proof: &Vec<Vec<u8>>) -> Option<Vec<u8>> {
+        match verify_proof::<PoseidonHash>(&Hash::from_bytes(root), key, proof).unwrap() {
+            TrieData::Node(node) => Some(node.data().to_vec()),
+            TrieData::NotFound => None,
+        }
+    }
+
     #[test]
     fn test_journal_u64() {
         let test_checkpoint = |a: u32, b: u32| {
@@ -448,6 +576,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_remove() {
+        let db = InMemoryTrieDb::default();
+        let zktrie = ZkTrieStateDb::new_empty(db);
+        let journal = JournaledTrie::new(zktrie);
+        journal.update(&bytes32!("key1"), &vec![bytes32!("val1")], 0);
+        journal.update(&bytes32!("key2"), &vec![bytes32!("val2")], 1);
+        journal.commit().unwrap();
+        // removed key isn't visible before and after the commit
+        journal.remove(&bytes32!("key2"));
+        assert_eq!(journal.get(&bytes32!("key2"), false), None);
+        journal.commit().unwrap();
+        assert_eq!(journal.get(&bytes32!("key2"), true), None);
+        assert_eq!(
+            journal.compute_root(),
+            calc_trie_root(vec![(bytes32!("key1"), vec![bytes32!("val1")], 0)])
+        );
+        // removal of the missing key doesn't change the trie
+        journal.remove(&bytes32!("key3"));
+        journal.commit().unwrap();
+        assert_eq!(
+            journal.compute_root(),
+            calc_trie_root(vec![(bytes32!("key1"), vec![bytes32!("val1")], 0)])
+        );
+    }
+
+    #[test]
+    fn test_transient_storage() {
+        let db = InMemoryTrieDb::default();
+        let zktrie = ZkTrieStateDb::new_empty(db);
+        let journal = JournaledTrie::new(zktrie);
+        journal.update_transient(&bytes32!("key1"), &bytes32!("val1"));
+        // rollback restores previous value of the transient key
+        let checkpoint = journal.checkpoint();
+        journal.update_transient(&bytes32!("key1"), &bytes32!("val2"));
+        journal.update_transient(&bytes32!("key2"), &bytes32!("val3"));
+        assert_eq!(journal.transient(&bytes32!("key1")), bytes32!("val2"));
+        journal.rollback(checkpoint);
+        assert_eq!(journal.transient(&bytes32!("key1")), bytes32!("val1"));
+        assert_eq!(journal.transient(&bytes32!("key2")), [0u8; 32]);
+        // transient storage isn't committed into the trie, but survives the commit
+        journal.commit().unwrap();
+        assert_eq!(journal.compute_root(), calc_trie_root(vec![]));
+        assert_eq!(journal.transient(&bytes32!("key1")), bytes32!("val1"));
+        journal.clear_transient();
+        assert_eq!(journal.transient(&bytes32!("key1")), [0u8; 32]);
+    }
+
     #[test]
     fn test_rollback_to_empty() {
         let db = InMemoryTrieDb::default();
@@ -466,4 +642,55 @@ mod tests {
         assert_eq!(journal.compute_root(), calc_trie_root(vec![]));
         assert_eq!(journal.inner.read().unwrap().state.len(), 0);
     }
+
+    #[test]
+    fn test_account_proof() {
+        let db = InMemoryTrieDb::default();
+        let zktrie = ZkTrieStateDb::new_empty(db);
+        let journal = JournaledTrie::new(zktrie);
+        let address = Address::with_last_byte(7);
+        let account_key = address.into_word().0;
+        let slot = bytes32!("slot1");
+        let storage_key =
+            JournaledTrie::<ZkTrieStateDb<InMemoryTrieDb>>::storage_key(&address, &slot);
+        journal.update(&account_key, &vec![bytes32!("val1"), bytes32!("val2")], 0);
+        journal.update(&storage_key, &vec![bytes32!("val3")], 0);
+        let (root, _) = journal.commit().unwrap();
+        // update the slot, proofs for the previous root must remain valid
+        journal.update(&storage_key, &vec![bytes32!("val4")], 0);
+        let (new_root, _) = journal.commit().unwrap();
+        let missing_slot = bytes32!("slot2");
+        let proof = journal
+            .account_proof(&root, &address, &[slot, missing_slot])
+            .unwrap();
+        assert_eq!(
+            verify_value(&root, &account_key, &proof.account_proof),
+            Some([bytes32!("val1"), bytes32!("val2")].concat())
+        );
+        assert_eq!(proof.storage_proofs[0].key, storage_key);
+        assert_eq!(
+            verify_value(&root, &storage_key, &proof.storage_proofs[0].proof),
+            Some(bytes32!("val3").to_vec())
+        );
+        assert_eq!(
+            verify_value(
+                &root,
+                &proof.storage_proofs[1].key,
+                &proof.storage_proofs[1].proof
+            ),
+            None
+        );
+        let proof = journal.account_proof(&new_root, &address, &[slot]).unwrap();
+        assert_eq!(
+            verify_value(&new_root, &storage_key, &proof.storage_proofs[0].proof),
+            Some(bytes32!("val4").to_vec())
+        );
+        // proof of the new state doesn't match the old root
+        assert!(verify_proof::<PoseidonHash>(
+            &Hash::from_bytes(&root),
+            &storage_key,
+            &proof.storage_proofs[0].proof
+        )
+        .is_err());
+    }
 }
@@ -1,6 +1,7 @@
 #![allow(dead_code, unreachable_patterns, unused_macros)]
 #![warn(unused_crate_dependencies)]
 
+pub mod file_db;
 pub mod instruction;
 mod macros;
 mod runtime;
@@ -18,5 +19,7 @@ pub use journal::*;
 pub mod mptrie;
 #[cfg(test)]
 mod tests;
+pub mod trace;
 pub mod types;
+pub mod witness;
 pub mod zktrie;
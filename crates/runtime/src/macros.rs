@@ -110,7 +110,13 @@ macro_rules! impl_runtime_handler {
                 use rwasm::AsContextMut;
                 let func = rwasm::Func::wrap(
                     store.as_context_mut(),
-                    |caller: Caller<'_, RuntimeContext<DB>>, $($t)*| -> Result<$out, rwasm::core::Trap> {
+                    |mut caller: Caller<'_, RuntimeContext<DB>>, $($t)*| -> Result<$out, rwasm::core::Trap> {
+                        if caller.data().is_tracing {
+                            let fuel_consumed = caller.fuel_consumed().unwrap_or_default();
+                            if let Some(trace) = caller.data_mut().trace.as_mut() {
+                                trace.record_host_call(Self::FUNC_INDEX, fuel_consumed);
+                            }
+                        }
                         return $crate::forward_call_args! { Self::fn_handler, caller, [$($t)*] };
                     });
                 let wrapped_index = store.inner.wrap_stored(rwasm::engine::bytecode::FuncIdx::from(Self::FUNC_INDEX as u32));
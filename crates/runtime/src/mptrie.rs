@@ -101,6 +101,14 @@ impl<DB: eth_trie::DB + TrieDb> TrieStorage for MPTrieStateDb<DB> {
         p.map_or(None, |v| Some(v))
     }
 
+    fn proof_at(&self, root32: &[u8; 32], key: &[u8; 32]) -> Option<Vec<Vec<u8>>> {
+        let mut trie = EthTrie::new(self.storage.clone());
+        if root32 != &EMPTY_ROOT_HASH {
+            trie = trie.at_root(H256::from_slice(root32));
+        }
+        trie.get_proof(key).ok()
+    }
+
     fn get_preimage(&mut self, key: &[u8]) -> Option<Bytes> {
         let r = self.storage.get(key).unwrap_or_default();
         r.map_or(None, |v| Some(Bytes::copy_from_slice(&v)))
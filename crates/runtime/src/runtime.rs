@@ -4,6 +4,7 @@ use crate::{
         runtime_register_sovereign_handlers,
         sys_exec_hash::{SysExecHash, SysExecHashResumable},
     },
+    trace::RuntimeTrace,
     types::{InMemoryTrieDb, RuntimeError},
     zktrie::ZkTrieStateDb,
     JournaledTrie,
@@ -15,6 +16,8 @@ use fluentbase_types::{
     Bytes,
     EmptyJournalTrie,
     ExitCode,
+    FuelConversion,
+    FuelSchedule,
     IJournaledTrie,
     SysFuncIdx::SYS_STATE,
     F254,
@@ -22,7 +25,11 @@ use fluentbase_types::{
     STATE_DEPLOY,
     STATE_MAIN,
 };
-use hashbrown::{hash_map::Entry, HashMap};
+use hashbrown::HashMap;
+use revm_interpreter::{
+    analysis::to_analysed,
+    primitives::{Bytecode, BytecodeState},
+};
 use rwasm::{
     core::{ImportLinker, Trap},
     engine::{bytecode::Instruction, DropKeep, RwasmConfig, StateRouterConfig},
@@ -40,13 +47,17 @@ use rwasm::{
     Value,
 };
 use std::{
-    cell::RefCell,
+    collections::BTreeMap,
     fmt::{Debug, Formatter},
     mem::take,
+    sync::{Arc, Mutex, OnceLock},
 };
 
 pub type DefaultEmptyRuntimeDatabase = JournaledTrie<ZkTrieStateDb<InMemoryTrieDb>>;
 
+/// Translator of EVM bytecode into rWASM binary used by `_evm_to_rwasm` host functions.
+pub type EvmTranslator = fn(&[u8]) -> Result<Vec<u8>, ExitCode>;
+
 pub enum BytecodeOrHash {
     Bytecode(Bytes, Option<F254>),
     Hash(F254),
@@ -86,8 +97,14 @@ pub struct RuntimeContext<DB: IJournaledTrie> {
     pub(crate) is_shared: bool,
     pub(crate) input: Vec<u8>,
     pub(crate) depth: u32,
+    pub(crate) is_tracing: bool,
+    pub(crate) use_module_cache: bool,
+    pub(crate) fuel_schedule: FuelSchedule,
+    pub(crate) fuel_conversion: FuelConversion,
+    pub(crate) evm_translator: Option<EvmTranslator>,
     // context outputs
     pub(crate) execution_result: ExecutionResult,
+    pub(crate) trace: Option<RuntimeTrace>,
     // storage
     pub(crate) jzkt: Option<DB>,
 }
@@ -107,7 +124,13 @@ impl<DB: IJournaledTrie> Default for RuntimeContext<DB> {
             is_shared: false,
             input: vec![],
             depth: 0,
+            is_tracing: false,
+            use_module_cache: true,
+            fuel_schedule: FuelSchedule::DEFAULT,
+            fuel_conversion: FuelConversion::DEFAULT,
+            evm_translator: None,
             execution_result: Default::default(),
+            trace: None,
             jzkt: None,
         }
     }
@@ -152,6 +175,20 @@ impl<DB: IJournaledTrie> RuntimeContext<DB> {
         self
     }
 
+    pub fn with_fuel_schedule(mut self, fuel_schedule: FuelSchedule) -> Self {
+        self.fuel_schedule = fuel_schedule;
+        self
+    }
+
+    pub fn with_fuel_conversion(mut self, fuel_conversion: FuelConversion) -> Self {
+        self.fuel_conversion = fuel_conversion;
+        self
+    }
+
+    pub fn change_fuel_conversion(&mut self, fuel_conversion: FuelConversion) {
+        self.fuel_conversion = fuel_conversion;
+    }
+
     pub fn with_jzkt(mut self, jzkt: DB) -> Self {
         self.jzkt = Some(jzkt);
         self
@@ -162,6 +199,27 @@ impl<DB: IJournaledTrie> RuntimeContext<DB> {
         self
     }
 
+    /// Enables recording of every executed rWASM instruction and host call, the trace is available
+    /// with [RuntimeContext::trace] once execution is finished.
+    pub fn with_tracing(mut self, is_tracing: bool) -> Self {
+        self.is_tracing = is_tracing;
+        self
+    }
+
+    /// Disables lookup of compiled modules in the shared cache, so bytecode of every called
+    /// contract is loaded from the state (required to record a complete state witness).
+    pub fn with_module_cache(mut self, use_module_cache: bool) -> Self {
+        self.use_module_cache = use_module_cache;
+        self
+    }
+
+    /// Sets translator of EVM bytecode into rWASM, w/o translator `_evm_to_rwasm` host functions
+    /// return `NotSupportedCall` and EVM contracts are always interpreted.
+    pub fn with_evm_translator(mut self, evm_translator: Option<EvmTranslator>) -> Self {
+        self.evm_translator = evm_translator;
+        self
+    }
+
     pub fn jzkt(&mut self) -> &DB {
         self.jzkt.as_ref().expect("jzkt is not initialized")
     }
@@ -170,6 +228,26 @@ impl<DB: IJournaledTrie> RuntimeContext<DB> {
         self.depth
     }
 
+    pub fn fuel_schedule(&self) -> &FuelSchedule {
+        &self.fuel_schedule
+    }
+
+    pub fn fuel_conversion(&self) -> FuelConversion {
+        self.fuel_conversion
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.is_tracing
+    }
+
+    pub fn trace(&self) -> Option<&RuntimeTrace> {
+        self.trace.as_ref()
+    }
+
+    pub fn take_trace(&mut self) -> Option<RuntimeTrace> {
+        self.trace.take()
+    }
+
     pub fn exit_code(&self) -> i32 {
         self.execution_result.exit_code
     }
@@ -224,19 +302,93 @@ impl ExecutionResult {
     }
 }
 
+/// Limits of the compiled module cache, the least recently used modules are evicted once any of
+/// the limits is exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleCacheConfig {
+    pub max_entries: usize,
+    /// Byte budget of the cache, the size of the module is estimated by its rWASM bytecode size
+    pub max_bytes: usize,
+}
+
+impl Default for ModuleCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 1024,
+            max_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleCacheMetrics {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub entries: usize,
+    pub bytes: usize,
+    /// Lookups of EVM jump tables, they're cached separately from modules
+    pub jump_table_hits: u64,
+    pub jump_table_misses: u64,
+}
+
+struct CachedModule {
+    module: Arc<Module>,
+    size: usize,
+    last_used: u64,
+}
+
+struct CachedJumpTable {
+    code_len: usize,
+    jump_table: Arc<[u8]>,
+    last_used: u64,
+}
+
+/// Cache of compiled rWASM modules by their code hash and analysed EVM jump tables by the source
+/// code hash.
+///
+/// The process-wide instance is shared across all threads (see `CachingRuntime::with_shared`), so
+/// parallel executors compile each contract only once.
 pub struct CachingRuntime {
-    // TODO(dmitry123): "add expiration to this map to avoid memory leak"
-    modules: HashMap<F254, Module>,
+    modules: HashMap<F254, CachedModule>,
+    jump_tables: HashMap<[u8; 32], CachedJumpTable>,
+    // modules ordered by last access
+    lru: BTreeMap<u64, F254>,
+    // jump tables ordered by last access
+    jump_table_lru: BTreeMap<u64, [u8; 32]>,
+    tick: u64,
+    config: ModuleCacheConfig,
+    metrics: ModuleCacheMetrics,
 }
 
+static SHARED_CACHING_RUNTIME: OnceLock<Mutex<CachingRuntime>> = OnceLock::new();
+
 impl CachingRuntime {
     pub fn new() -> Self {
+        Self::with_config(ModuleCacheConfig::default())
+    }
+
+    pub fn with_config(config: ModuleCacheConfig) -> Self {
         Self {
             modules: HashMap::new(),
+            jump_tables: HashMap::new(),
+            lru: BTreeMap::new(),
+            jump_table_lru: BTreeMap::new(),
+            tick: 0,
+            config,
+            metrics: ModuleCacheMetrics::default(),
         }
     }
 
-    fn new_engine() -> Engine {
+    /// Runs the function with the process-wide cache.
+    pub fn with_shared<R>(f: impl FnOnce(&mut CachingRuntime) -> R) -> R {
+        let cache = SHARED_CACHING_RUNTIME.get_or_init(|| Mutex::new(CachingRuntime::new()));
+        // cache state is always consistent, so we can ignore poisoning
+        let mut cache = cache.lock().unwrap_or_else(|err| err.into_inner());
+        f(&mut cache)
+    }
+
+    pub(crate) fn new_engine() -> Engine {
         // we can safely use sovereign import linker because all protected are filtered out during
         // translation process
         let import_linker = Runtime::new_sovereign_linker();
@@ -260,16 +412,7 @@ impl CachingRuntime {
         Engine::new(&config)
     }
 
-    pub fn init_module(
-        &mut self,
-        engine: &Engine,
-        rwasm_hash: F254,
-        rwasm_bytecode: &[u8],
-    ) -> Result<&Module, RuntimeError> {
-        let entry = match self.modules.entry(rwasm_hash) {
-            Entry::Occupied(_) => return Err(RuntimeError::UnloadedModule(rwasm_hash)),
-            Entry::Vacant(entry) => entry,
-        };
+    pub fn compile_module(engine: &Engine, rwasm_bytecode: &[u8]) -> Result<Module, RuntimeError> {
         // empty bytecode we can't execute so just return Ok exit code
         let reduced_module = if !rwasm_bytecode.is_empty() {
             RwasmModule::new(rwasm_bytecode).map_err(Into::<RuntimeError>::into)?
@@ -278,24 +421,176 @@ impl CachingRuntime {
                 Return(DropKeep::none())
             })
         };
-        // let engine = Self::new_engine();
         let module_builder = reduced_module.to_module_builder(engine);
-        let module = module_builder.finish();
-        Ok(entry.insert(module))
+        Ok(module_builder.finish())
     }
 
-    pub fn resolve_module(&self, rwasm_hash: &F254) -> Option<&Module> {
-        self.modules.get(rwasm_hash)
+    pub fn init_module(
+        &mut self,
+        engine: &Engine,
+        rwasm_hash: F254,
+        rwasm_bytecode: &[u8],
+    ) -> Result<Arc<Module>, RuntimeError> {
+        if let Some(module) = self.resolve_module(&rwasm_hash) {
+            return Ok(module);
+        }
+        let module = Arc::new(Self::compile_module(engine, rwasm_bytecode)?);
+        self.insert_module(rwasm_hash, module.clone(), rwasm_bytecode.len());
+        Ok(module)
+    }
+
+    /// Puts compiled module into the cache, if module is already cached (e.g. it was compiled by
+    /// another thread) then the cached one is kept.
+    pub fn insert_module(&mut self, rwasm_hash: F254, module: Arc<Module>, size: usize) {
+        if self.touch(&rwasm_hash).is_some() {
+            return;
+        }
+        self.tick += 1;
+        self.lru.insert(self.tick, rwasm_hash);
+        self.modules.insert(
+            rwasm_hash,
+            CachedModule {
+                module,
+                size,
+                last_used: self.tick,
+            },
+        );
+        self.metrics.entries += 1;
+        self.metrics.bytes += size;
+        self.evict();
+    }
+
+    pub fn resolve_module(&mut self, rwasm_hash: &F254) -> Option<Arc<Module>> {
+        let result = self.touch(rwasm_hash);
+        if result.is_some() {
+            self.metrics.hits += 1;
+        } else {
+            self.metrics.misses += 1;
+        }
+        result
+    }
+
+    /// Removes module from the cache, returns `true` if module was cached.
+    pub fn invalidate(&mut self, rwasm_hash: &F254) -> bool {
+        let Some(cached_module) = self.modules.remove(rwasm_hash) else {
+            return false;
+        };
+        self.lru.remove(&cached_module.last_used);
+        self.metrics.entries -= 1;
+        self.metrics.bytes -= cached_module.size;
+        true
+    }
+
+    /// Returns code length and jump table of EVM bytecode by its hash, the bytecode is loaded with
+    /// `code` and analysed only if the jump table isn't cached yet. Jump tables are small, so only
+    /// their number is limited by `max_entries`, the least recently used ones are evicted.
+    pub fn resolve_jump_table(
+        &mut self,
+        code_hash: &[u8; 32],
+        code: impl FnOnce() -> Vec<u8>,
+    ) -> (usize, Arc<[u8]>) {
+        if let Some(cached_jump_table) = self.jump_tables.get_mut(code_hash) {
+            self.metrics.jump_table_hits += 1;
+            self.tick += 1;
+            self.jump_table_lru.remove(&cached_jump_table.last_used);
+            self.jump_table_lru.insert(self.tick, *code_hash);
+            cached_jump_table.last_used = self.tick;
+            return (
+                cached_jump_table.code_len,
+                cached_jump_table.jump_table.clone(),
+            );
+        }
+        self.metrics.jump_table_misses += 1;
+        let code = code();
+        let jump_table: Arc<[u8]> = Arc::from(evm_jump_table(&code));
+        // preimage might be unknown yet, so we can't cache empty bytecode
+        if code.is_empty() {
+            return (0, jump_table);
+        }
+        self.tick += 1;
+        self.jump_table_lru.insert(self.tick, *code_hash);
+        self.jump_tables.insert(
+            *code_hash,
+            CachedJumpTable {
+                code_len: code.len(),
+                jump_table: jump_table.clone(),
+                last_used: self.tick,
+            },
+        );
+        while self.jump_tables.len() > self.config.max_entries {
+            let Some((_, code_hash)) = self.jump_table_lru.pop_first() else {
+                break;
+            };
+            self.jump_tables.remove(&code_hash);
+        }
+        (code.len(), jump_table)
+    }
+
+    pub fn clear(&mut self) {
+        self.modules.clear();
+        self.jump_tables.clear();
+        self.lru.clear();
+        self.jump_table_lru.clear();
+        self.metrics.entries = 0;
+        self.metrics.bytes = 0;
+    }
+
+    pub fn set_config(&mut self, config: ModuleCacheConfig) {
+        self.config = config;
+        self.evict();
+    }
+
+    pub fn config(&self) -> ModuleCacheConfig {
+        self.config
+    }
+
+    pub fn metrics(&self) -> ModuleCacheMetrics {
+        self.metrics
+    }
+
+    fn touch(&mut self, rwasm_hash: &F254) -> Option<Arc<Module>> {
+        let cached_module = self.modules.get_mut(rwasm_hash)?;
+        self.tick += 1;
+        self.lru.remove(&cached_module.last_used);
+        self.lru.insert(self.tick, *rwasm_hash);
+        cached_module.last_used = self.tick;
+        Some(cached_module.module.clone())
+    }
+
+    fn evict(&mut self) {
+        while self.metrics.entries > self.config.max_entries
+            || self.metrics.bytes > self.config.max_bytes
+        {
+            let Some((_, rwasm_hash)) = self.lru.pop_first() else {
+                break;
+            };
+            if let Some(cached_module) = self.modules.remove(&rwasm_hash) {
+                self.metrics.entries -= 1;
+                self.metrics.bytes -= cached_module.size;
+                self.metrics.evictions += 1;
+            }
+        }
     }
 }
 
-thread_local! {
-    static CACHING_RUNTIME: RefCell<CachingRuntime> = RefCell::new(CachingRuntime::new());
+/// Returns bitmap of valid jump destinations of EVM bytecode (`JUMPDEST` opcodes that aren't a
+/// part of push data), bits are in LSB order like in revm's `JumpMap`.
+pub fn evm_jump_table(code: &[u8]) -> Vec<u8> {
+    let bytecode = to_analysed(Bytecode::new_raw(Bytes::copy_from_slice(code)));
+    let BytecodeState::Analysed { jump_map, .. } = bytecode.state else {
+        unreachable!("bytecode is analysed")
+    };
+    // analysed bytecode is padded, but padding has no jump destinations
+    let mut jump_table = jump_map.as_slice().to_vec();
+    jump_table.truncate((code.len() + 7) / 8);
+    jump_table
 }
 
 pub struct Runtime<DB: IJournaledTrie> {
     pub(crate) store: Store<RuntimeContext<DB>>,
     pub(crate) linker: Linker<RuntimeContext<DB>>,
+    // cached module resolved with the engine of the store
+    module: Option<Arc<Module>>,
 }
 
 impl Runtime<EmptyJournalTrie> {
@@ -343,18 +638,29 @@ impl<DB: IJournaledTrie> Runtime<DB> {
         runtime_context.bytecode = runtime_context.bytecode.with_resolved_hash();
 
         // use existing engine or create a new one
-        let engine = CACHING_RUNTIME.with_borrow_mut(|caching_runtime| {
-            let rwasm_hash = runtime_context.bytecode.resolve_hash();
-            caching_runtime
-                .resolve_module(&rwasm_hash)
-                .map(|module| module.engine.clone())
-                .unwrap_or_else(|| CachingRuntime::new_engine())
-        });
+        let rwasm_hash = runtime_context.bytecode.resolve_hash();
+        let module = if runtime_context.use_module_cache {
+            CachingRuntime::with_shared(|caching_runtime| {
+                caching_runtime.resolve_module(&rwasm_hash)
+            })
+        } else {
+            None
+        };
+        let engine = module
+            .as_ref()
+            .map(|module| module.engine.clone())
+            .unwrap_or_else(|| CachingRuntime::new_engine());
 
         // create new linker and store (it shares same engine resources)
         let mut store = Store::<RuntimeContext<DB>>::new(&engine, runtime_context);
         let mut linker = Linker::<RuntimeContext<DB>>::new(&engine);
 
+        // start new trace for this call
+        if store.data().is_tracing {
+            let depth = store.data().depth;
+            store.data_mut().trace = Some(RuntimeTrace::new(depth));
+        }
+
         // add fuel if limit is specified
         if store.data().fuel_limit > 0 {
             store.add_fuel(store.data().fuel_limit).unwrap();
@@ -367,55 +673,79 @@ impl<DB: IJournaledTrie> Runtime<DB> {
             runtime_register_shared_handlers(&mut linker, &mut store)
         }
 
-        Self { store, linker }
+        Self {
+            store,
+            linker,
+            module,
+        }
     }
 
-    pub fn call(&mut self) -> Result<ExecutionResult, RuntimeError> {
-        let instance = CACHING_RUNTIME.with_borrow_mut(|caching_runtime| {
-            let bytecode_repr = take(&mut self.store.data_mut().bytecode);
-
-            // resolve cached module or init it
-            let module = match &bytecode_repr {
-                BytecodeOrHash::Bytecode(bytecode, hash) => {
-                    let hash = hash.unwrap_or_else(|| F254::from(poseidon_hash(&bytecode)));
-                    // if we have cached module then use it, otherwise create new one and cache
-                    if let Some(module) = caching_runtime.resolve_module(&hash) {
-                        Ok(module)
-                    } else {
-                        caching_runtime.init_module(self.store.engine(), hash, &bytecode)
-                    }
-                }
-                BytecodeOrHash::Hash(hash) => {
-                    // if we have only hash then try to load module or fail fast
-                    match caching_runtime.resolve_module(hash) {
-                        Some(module) => Ok(module),
-                        None => {
-                            let rwasm_bytecode = self
-                                .store
-                                .data_mut()
-                                .jzkt
-                                .as_ref()
-                                .ok_or(RuntimeError::UnloadedModule(*hash))?
-                                .preimage(hash);
-                            caching_runtime.init_module(self.store.engine(), *hash, &rwasm_bytecode)
-                        }
-                    }
-                }
-            }?;
+    fn resolve_module(
+        &mut self,
+        bytecode_repr: &BytecodeOrHash,
+    ) -> Result<Arc<Module>, RuntimeError> {
+        // module is resolved from the cache in `new`, because store must use the same engine
+        if let Some(module) = self.module.take() {
+            return Ok(module);
+        }
+        let hash = match bytecode_repr {
+            BytecodeOrHash::Bytecode(bytecode, hash) => {
+                hash.unwrap_or_else(|| F254::from(poseidon_hash(&bytecode)))
+            }
+            BytecodeOrHash::Hash(hash) => *hash,
+        };
+        // compile module w/o holding the lock to not block other threads
+        let (module, size) = match bytecode_repr {
+            BytecodeOrHash::Bytecode(bytecode, _) => (
+                CachingRuntime::compile_module(self.store.engine(), bytecode)?,
+                bytecode.len(),
+            ),
+            BytecodeOrHash::Hash(hash) => {
+                // if we have only hash then try to load module or fail fast
+                let rwasm_bytecode = self
+                    .store
+                    .data_mut()
+                    .jzkt
+                    .as_ref()
+                    .ok_or(RuntimeError::UnloadedModule(*hash))?
+                    .preimage(hash);
+                (
+                    CachingRuntime::compile_module(self.store.engine(), &rwasm_bytecode)?,
+                    rwasm_bytecode.len(),
+                )
+            }
+        };
+        let module = Arc::new(module);
+        CachingRuntime::with_shared(|caching_runtime| {
+            caching_runtime.insert_module(hash, module.clone(), size)
+        });
+        Ok(module)
+    }
 
-            // return bytecode back
-            self.store.data_mut().bytecode = bytecode_repr;
+    pub fn call(&mut self) -> Result<ExecutionResult, RuntimeError> {
+        let execution_result = self.call_inner();
+        // transient storage lives until the end of the transaction, that is the root call
+        if self.store.data().depth == 0 {
+            if let Some(jzkt) = self.store.data().jzkt.as_ref() {
+                jzkt.clear_transient();
+            }
+        }
+        execution_result
+    }
 
-            // init instance
-            let instance = self
-                .linker
-                .instantiate(&mut self.store, &module)
-                .map_err(Into::<RuntimeError>::into)?
-                .start(&mut self.store)
-                .map_err(Into::<RuntimeError>::into)?;
+    fn call_inner(&mut self) -> Result<ExecutionResult, RuntimeError> {
+        let bytecode_repr = take(&mut self.store.data_mut().bytecode);
+        let module = self.resolve_module(&bytecode_repr);
+        // return bytecode back
+        self.store.data_mut().bytecode = bytecode_repr;
 
-            Ok::<Instance, RuntimeError>(instance)
-        })?;
+        // init instance
+        let instance = self
+            .linker
+            .instantiate(&mut self.store, &module?)
+            .map_err(Into::<RuntimeError>::into)?
+            .start(&mut self.store)
+            .map_err(Into::<RuntimeError>::into)?;
 
         let mut next_result = instance
             .get_func(&mut self.store, "main")
@@ -426,6 +756,7 @@ impl<DB: IJournaledTrie> Runtime<DB> {
             match next_result {
                 Ok(resumable) => match resumable {
                     ResumableCall::Finished => {
+                        self.finalize_trace();
                         let mut execution_result = self.store.data().execution_result.clone();
                         execution_result.fuel_consumed =
                             self.store.fuel_consumed().unwrap_or_default();
@@ -438,6 +769,7 @@ impl<DB: IJournaledTrie> Runtime<DB> {
                         {
                             // if we have exit code then just return it, somehow execution failed,
                             // maybe if was out of fuel
+                            self.finalize_trace();
                             let mut execution_result = self.store.data().execution_result.clone();
                             execution_result.exit_code = exit_code;
                             return Ok(execution_result);
@@ -467,6 +799,7 @@ impl<DB: IJournaledTrie> Runtime<DB> {
                     }
                 },
                 Err(err) => {
+                    self.finalize_trace();
                     let mut execution_result = self.store.data().execution_result.clone();
                     execution_result.fuel_consumed = self.store.fuel_consumed().unwrap_or_default();
                     execution_result.exit_code = Runtime::catch_trap(&err);
@@ -476,6 +809,14 @@ impl<DB: IJournaledTrie> Runtime<DB> {
         }
     }
 
+    fn finalize_trace(&mut self) {
+        let Some(mut trace) = self.store.data_mut().trace.take() else {
+            return;
+        };
+        trace.record_instructions(self.store.tracer());
+        self.store.data_mut().trace = Some(trace);
+    }
+
     pub fn store(&self) -> &Store<RuntimeContext<DB>> {
         &self.store
     }
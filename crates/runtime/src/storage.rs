@@ -18,6 +18,10 @@ pub trait TrieStorage {
 
     fn proof(&self, key: &[u8; 32]) -> Option<Vec<Vec<u8>>>;
 
+    /// Returns merkle proof of the key for the state with the specified root, the state must be
+    /// stored in the database.
+    fn proof_at(&self, root32: &[u8; 32], key: &[u8; 32]) -> Option<Vec<Vec<u8>>>;
+
     fn get_preimage(&mut self, key: &[u8]) -> Option<Bytes>;
 
     fn preimage_size(&mut self, key: &[u8]) -> u32 {
@@ -1,7 +1,21 @@
-use crate::{runtime::Runtime, DefaultEmptyRuntimeDatabase, RuntimeContext};
+use crate::{
+    instruction::{evm_to_rwasm::EvmToRwasm, sys_read_output::SysReadOutput},
+    runtime::Runtime,
+    CachingRuntime,
+    DefaultEmptyRuntimeDatabase,
+    ModuleCacheConfig,
+    RuntimeContext,
+};
 use fluentbase_types::{
     create_sovereign_import_linker,
+    ExitCode,
+    FuelConversion,
+    FuelRounding,
+    FuelSchedule,
+    HostFuelCost,
+    SysFuncIdx,
     SysFuncIdx::SYS_STATE,
+    F254,
     STATE_DEPLOY,
     STATE_MAIN,
 };
@@ -94,6 +108,58 @@ fn test_wrong_indirect_type() {
     assert_eq!(res.exit_code, -2008);
 }
 
+#[test]
+fn test_tracing() {
+    let rwasm_binary = wat2rwasm(
+        r#"
+(module
+  (type (;0;) (func (param i32 i32 i32)))
+  (type (;1;) (func))
+  (type (;2;) (func (param i32 i32)))
+  (import "fluentbase_v1alpha" "_crypto_keccak256" (func $_evm_keccak256 (type 0)))
+  (import "fluentbase_v1alpha" "_sys_write" (func $_evm_return (type 2)))
+  (func $main (type 1)
+    i32.const 0
+    i32.const 12
+    i32.const 50
+    call $_evm_keccak256
+    i32.const 50
+    i32.const 32
+    call $_evm_return
+    )
+  (memory (;0;) 100)
+  (data (;0;) (i32.const 0) "Hello, World")
+  (export "main" (func $main)))
+    "#,
+    );
+    let ctx = RuntimeContext::new(rwasm_binary)
+        .with_fuel_limit(1_000_000)
+        .with_tracing(true);
+    let mut runtime = Runtime::<DefaultEmptyRuntimeDatabase>::new(ctx);
+    let execution_result = runtime.call().unwrap();
+    assert_eq!(execution_result.exit_code, 0);
+    let trace = runtime.data().trace().unwrap();
+    assert!(!trace.instructions.is_empty());
+    let host_calls = trace
+        .host_calls
+        .iter()
+        .map(|host_call| host_call.func_idx)
+        .collect::<Vec<_>>();
+    // state router might call `_sys_state` before entrypoint
+    assert!(host_calls.ends_with(&[SysFuncIdx::CRYPTO_KECCAK256, SysFuncIdx::SYS_WRITE]));
+    // tracing is disabled by default
+    let ctx = RuntimeContext::new(wat2rwasm(
+        r#"
+(module
+  (func $main)
+  (export "main" (func $main)))
+    "#,
+    ));
+    let mut runtime = Runtime::<DefaultEmptyRuntimeDatabase>::new(ctx);
+    runtime.call().unwrap();
+    assert!(runtime.data().trace().is_none());
+}
+
 #[test]
 fn test_keccak256() {
     let rwasm_binary = wat2rwasm(
@@ -127,3 +193,226 @@ fn test_keccak256() {
         execution_result.output.as_slice()
     );
 }
+
+#[test]
+fn test_host_fuel_schedule() {
+    // hash 64kB of memory
+    let rwasm_binary = wat2rwasm(
+        r#"
+(module
+  (type (;0;) (func (param i32 i32 i32)))
+  (type (;1;) (func))
+  (import "fluentbase_v1alpha" "_crypto_keccak256" (func $_evm_keccak256 (type 0)))
+  (func $main (type 1)
+    i32.const 0
+    i32.const 65536
+    i32.const 0
+    call $_evm_keccak256
+    )
+  (memory (;0;) 1)
+  (export "main" (func $main)))
+    "#,
+    );
+    let exec = |fuel_schedule: FuelSchedule, fuel_limit: u64| {
+        let ctx = RuntimeContext::new(rwasm_binary.clone())
+            .with_fuel_limit(fuel_limit)
+            .with_fuel_schedule(fuel_schedule);
+        Runtime::<DefaultEmptyRuntimeDatabase>::run_with_context(ctx).unwrap()
+    };
+    let default_result = exec(FuelSchedule::default(), 1_000_000);
+    assert_eq!(default_result.exit_code, 0);
+    // 2048 words are charged on top of the base cost
+    let expensive_schedule = FuelSchedule {
+        crypto_keccak256: HostFuelCost::new(30, 100),
+        ..Default::default()
+    };
+    let expensive_result = exec(expensive_schedule.clone(), 1_000_000);
+    assert_eq!(expensive_result.exit_code, 0);
+    assert_eq!(
+        expensive_result.fuel_consumed - default_result.fuel_consumed,
+        (100 - 6) * 2048
+    );
+    // not enough fuel to pay for the hashing
+    let result = exec(expensive_schedule, 100_000);
+    assert_eq!(result.exit_code, ExitCode::OutOfFuel.into_i32());
+}
+
+#[test]
+fn test_fuel_conversion() {
+    let default = FuelConversion::default();
+    // gas limits above u32 aren't truncated anymore
+    let gas_limit = u32::MAX as u64 + 1;
+    assert_eq!(default.gas_to_fuel(gas_limit), gas_limit);
+    assert_eq!(default.remaining_gas(1_000, 300), 700);
+    // partially used gas unit is charged only when rounding up
+    let round_up = FuelConversion::new(1000, FuelRounding::Up);
+    assert_eq!(round_up.gas_to_fuel(21_000), 21_000_000);
+    assert_eq!(round_up.gas_to_fuel(u64::MAX), u64::MAX);
+    assert_eq!(round_up.fuel_to_gas(1_001), 2);
+    assert_eq!(round_up.remaining_gas(10, 9_001), 0);
+    let round_down = FuelConversion::new(1000, FuelRounding::Down);
+    assert_eq!(round_down.fuel_to_gas(1_999), 1);
+    assert_eq!(round_down.remaining_gas(10, 9_001), 1);
+    assert_eq!(
+        FuelConversion::from_bytes(&round_down.to_bytes()),
+        round_down
+    );
+}
+
+#[test]
+#[should_panic(expected = "fuel per gas must not be zero")]
+fn test_fuel_conversion_zero_fuel_per_gas() {
+    FuelConversion::new(0, FuelRounding::Up);
+}
+
+#[test]
+fn test_read_output_bounds() {
+    let mut ctx = RuntimeContext::<DefaultEmptyRuntimeDatabase>::new(vec![]);
+    ctx.execution_result.return_data = vec![1, 2, 3, 4];
+    assert_eq!(SysReadOutput::fn_impl(&ctx, 1, 3), Ok(vec![2, 3, 4]));
+    assert_eq!(SysReadOutput::fn_impl(&ctx, 4, 0), Ok(vec![]));
+    // reading out of the return data must fail like `RETURNDATACOPY` does
+    assert_eq!(
+        SysReadOutput::fn_impl(&ctx, 2, 3),
+        Err(ExitCode::OutputOverflow)
+    );
+    assert_eq!(
+        SysReadOutput::fn_impl(&ctx, u32::MAX, 2),
+        Err(ExitCode::OutputOverflow)
+    );
+}
+
+#[test]
+fn test_evm_to_rwasm_translator() {
+    fn translate(evm_bytecode: &[u8]) -> Result<Vec<u8>, ExitCode> {
+        match evm_bytecode.first() {
+            Some(0xfe) => Err(ExitCode::NotSupportedCall),
+            _ => Ok(evm_bytecode.iter().rev().copied().collect()),
+        }
+    }
+    // w/o translator the contract must be interpreted
+    let mut ctx = RuntimeContext::<DefaultEmptyRuntimeDatabase>::new(vec![]);
+    assert_eq!(
+        EvmToRwasm::fn_impl(&mut ctx, &[0x60, 0x01], 0),
+        Err(ExitCode::NotSupportedCall)
+    );
+    let mut ctx = ctx.with_evm_translator(Some(translate));
+    assert_eq!(
+        EvmToRwasm::fn_impl(&mut ctx, &[0x60, 0x01], 0),
+        Ok(vec![0x01, 0x60])
+    );
+    assert_eq!(ctx.execution_result.return_data, vec![0x01, 0x60]);
+    assert_eq!(
+        EvmToRwasm::fn_impl(&mut ctx, &[0x60, 0x01], 1),
+        Err(ExitCode::OutputOverflow)
+    );
+    assert_eq!(
+        EvmToRwasm::fn_impl(&mut ctx, &[0xfe], 0),
+        Err(ExitCode::NotSupportedCall)
+    );
+}
+
+#[test]
+fn test_module_cache_eviction() {
+    let engine = CachingRuntime::new_engine();
+    let mut caching_runtime = CachingRuntime::with_config(ModuleCacheConfig {
+        max_entries: 2,
+        max_bytes: 1024,
+    });
+    let (hash1, hash2, hash3) = (
+        F254::from([1u8; 32]),
+        F254::from([2u8; 32]),
+        F254::from([3u8; 32]),
+    );
+    caching_runtime.init_module(&engine, hash1, &[]).unwrap();
+    caching_runtime.init_module(&engine, hash2, &[]).unwrap();
+    // use first module, so second one becomes the least recently used
+    assert!(caching_runtime.resolve_module(&hash1).is_some());
+    caching_runtime.init_module(&engine, hash3, &[]).unwrap();
+    assert!(caching_runtime.resolve_module(&hash2).is_none());
+    assert!(caching_runtime.resolve_module(&hash1).is_some());
+    assert!(caching_runtime.resolve_module(&hash3).is_some());
+    let metrics = caching_runtime.metrics();
+    assert_eq!(metrics.entries, 2);
+    assert_eq!(metrics.evictions, 1);
+    // every `init_module` starts with lookup
+    assert_eq!(metrics.hits, 3);
+    assert_eq!(metrics.misses, 4);
+    // invalidate by code hash
+    assert!(caching_runtime.invalidate(&hash1));
+    assert!(!caching_runtime.invalidate(&hash1));
+    assert!(caching_runtime.resolve_module(&hash1).is_none());
+    assert_eq!(caching_runtime.metrics().entries, 1);
+}
+
+#[test]
+fn test_module_cache_byte_budget() {
+    let engine = CachingRuntime::new_engine();
+    let rwasm_binary = wat2rwasm(
+        r#"
+(module
+  (type (;0;) (func))
+  (func $main (type 0)
+    return
+    )
+  (export "main" (func $main)))
+    "#,
+    );
+    let mut caching_runtime = CachingRuntime::with_config(ModuleCacheConfig {
+        max_entries: 100,
+        max_bytes: rwasm_binary.len() * 2,
+    });
+    for i in 0..3u8 {
+        caching_runtime
+            .init_module(&engine, F254::from([i; 32]), &rwasm_binary)
+            .unwrap();
+    }
+    let metrics = caching_runtime.metrics();
+    assert_eq!(metrics.entries, 2);
+    assert_eq!(metrics.bytes, rwasm_binary.len() * 2);
+    assert_eq!(metrics.evictions, 1);
+    assert!(caching_runtime
+        .resolve_module(&F254::from([0u8; 32]))
+        .is_none());
+}
+
+#[test]
+fn test_evm_jump_table_cache() {
+    let mut caching_runtime = CachingRuntime::with_config(ModuleCacheConfig::default());
+    // PUSH1 0x5b JUMPDEST PUSH2 0x5b5b JUMPDEST
+    let code = vec![0x60, 0x5b, 0x5b, 0x61, 0x5b, 0x5b, 0x5b];
+    let (code_len, jump_table) = caching_runtime.resolve_jump_table(&[1u8; 32], || code.clone());
+    assert_eq!(code_len, code.len());
+    // JUMPDEST bytes inside PUSH data aren't marked
+    assert_eq!(&jump_table[..], &[0b0100_0100]);
+    // the second lookup doesn't load the code again
+    let (_, cached_jump_table) =
+        caching_runtime.resolve_jump_table(&[1u8; 32], || unreachable!("jump table is cached"));
+    assert_eq!(cached_jump_table, jump_table);
+    let metrics = caching_runtime.metrics();
+    assert_eq!(metrics.jump_table_hits, 1);
+    assert_eq!(metrics.jump_table_misses, 1);
+    // empty code might be an unknown preimage, so it's not cached
+    caching_runtime.resolve_jump_table(&[2u8; 32], Vec::new);
+    caching_runtime.resolve_jump_table(&[2u8; 32], Vec::new);
+    assert_eq!(caching_runtime.metrics().jump_table_misses, 3);
+}
+
+#[test]
+fn test_evm_jump_table_cache_eviction() {
+    let mut caching_runtime = CachingRuntime::with_config(ModuleCacheConfig {
+        max_entries: 2,
+        ..Default::default()
+    });
+    let code = || vec![0x5b];
+    caching_runtime.resolve_jump_table(&[1u8; 32], code);
+    caching_runtime.resolve_jump_table(&[2u8; 32], code);
+    // the first jump table is used again, so the second one is the least recently used
+    caching_runtime.resolve_jump_table(&[1u8; 32], || unreachable!("jump table is cached"));
+    caching_runtime.resolve_jump_table(&[3u8; 32], code);
+    caching_runtime.resolve_jump_table(&[1u8; 32], || unreachable!("jump table is cached"));
+    caching_runtime.resolve_jump_table(&[3u8; 32], || unreachable!("jump table is cached"));
+    let misses = caching_runtime.metrics().jump_table_misses;
+    caching_runtime.resolve_jump_table(&[2u8; 32], code);
+    assert_eq!(caching_runtime.metrics().jump_table_misses, misses + 1);
+}
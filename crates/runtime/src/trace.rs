@@ -0,0 +1,87 @@
+use fluentbase_types::SysFuncIdx;
+use rwasm::{
+    core::UntypedValue,
+    engine::{bytecode::Instruction, Tracer},
+};
+
+/// Memory region modified by an executed instruction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryWriteTrace {
+    pub offset: u32,
+    pub data: Vec<u8>,
+}
+
+/// State of the rWASM interpreter after the execution of one instruction.
+#[derive(Debug, Clone)]
+pub struct InstructionTrace {
+    pub pc: u32,
+    pub opcode: Instruction,
+    pub stack_top: Option<UntypedValue>,
+    pub fuel_consumed: u64,
+    pub memory_writes: Vec<MemoryWriteTrace>,
+}
+
+/// Host function invoked by the contract, `fuel_consumed` is the fuel spent by the contract
+/// before the call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostCallTrace {
+    pub func_idx: SysFuncIdx,
+    pub fuel_consumed: u64,
+}
+
+/// Execution trace of the rWASM contract including all nested `_sys_exec_hash` calls in the order
+/// they were executed.
+#[derive(Debug, Clone, Default)]
+pub struct RuntimeTrace {
+    pub depth: u32,
+    pub instructions: Vec<InstructionTrace>,
+    pub host_calls: Vec<HostCallTrace>,
+    pub nested_calls: Vec<RuntimeTrace>,
+}
+
+impl RuntimeTrace {
+    pub(crate) fn new(depth: u32) -> Self {
+        Self {
+            depth,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn record_host_call(&mut self, func_idx: SysFuncIdx, fuel_consumed: u64) {
+        self.host_calls.push(HostCallTrace {
+            func_idx,
+            fuel_consumed,
+        });
+    }
+
+    pub(crate) fn record_instructions(&mut self, tracer: &Tracer) {
+        self.instructions = tracer
+            .logs
+            .iter()
+            .map(|log| InstructionTrace {
+                pc: log.program_counter,
+                opcode: log.opcode,
+                stack_top: log.stack.last().copied(),
+                fuel_consumed: log.consumed_fuel,
+                memory_writes: log
+                    .memory_changes
+                    .iter()
+                    .map(|change| MemoryWriteTrace {
+                        offset: change.offset,
+                        data: change.memory.clone(),
+                    })
+                    .collect(),
+            })
+            .collect();
+    }
+
+    /// Total number of executed instructions including nested calls.
+    pub fn instructions_count(&self) -> usize {
+        self.instructions.len()
+            + self
+                .nested_calls
+                .iter()
+                .map(|trace| trace.instructions_count())
+                .sum::<usize>()
+    }
+}
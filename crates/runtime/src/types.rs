@@ -63,6 +63,8 @@ pub enum RuntimeError {
     StorageError(String),
     MissingEntrypoint,
     UnloadedModule(F254),
+    /// Stateless execution read a trie node or preimage that isn't a part of the witness
+    IncompleteWitness(Bytes),
 }
 
 impl From<BinaryFormatError> for RuntimeError {
@@ -0,0 +1,297 @@
+use crate::{
+    types::{RuntimeError, TrieDb},
+    zktrie::ZkTrieStateDb,
+    ExecutionResult,
+    JournaledTrie,
+    Runtime,
+    RuntimeContext,
+};
+use fluentbase_types::Bytes;
+use hashbrown::{HashMap, HashSet};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+/// Trie nodes and preimages (like contract bytecode) read during the execution of the transaction
+/// together with the state root they belong to. It's enough to re-execute the transaction w/o
+/// access to the full state.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateWitness {
+    pub root: [u8; 32],
+    pub nodes: BTreeMap<Bytes, Bytes>,
+    pub preimages: BTreeMap<Bytes, Bytes>,
+}
+
+#[derive(Default)]
+struct RecordedState {
+    nodes: BTreeMap<Bytes, Bytes>,
+    preimages: BTreeMap<Bytes, Bytes>,
+    written_nodes: HashSet<Bytes>,
+    written_preimages: HashSet<Bytes>,
+}
+
+/// Shared handle to the nodes and preimages recorded by [RecordingTrieDb], it stays available
+/// after the database is moved into the journal.
+#[derive(Default, Clone)]
+pub struct WitnessRecorder(Rc<RefCell<RecordedState>>);
+
+impl WitnessRecorder {
+    /// Builds a witness for the state with the specified root from the recorded reads.
+    pub fn witness(&self, root: &[u8; 32]) -> StateWitness {
+        let state = self.0.borrow();
+        StateWitness {
+            root: *root,
+            nodes: state.nodes.clone(),
+            preimages: state.preimages.clone(),
+        }
+    }
+}
+
+/// `TrieDb` wrapper that records all nodes and preimages read from the underlying database.
+/// Values written during the execution aren't recorded, because re-execution produces them
+/// again. Absent preimages are recorded as empty, since a missing preimage is the same as an
+/// empty one for the journal.
+pub struct RecordingTrieDb<DB: TrieDb> {
+    inner: DB,
+    recorder: WitnessRecorder,
+}
+
+impl<DB: TrieDb> RecordingTrieDb<DB> {
+    pub fn new(inner: DB) -> Self {
+        Self {
+            inner,
+            recorder: WitnessRecorder::default(),
+        }
+    }
+
+    pub fn recorder(&self) -> WitnessRecorder {
+        self.recorder.clone()
+    }
+}
+
+impl<DB: TrieDb> TrieDb for RecordingTrieDb<DB> {
+    fn get_node(&mut self, key: &[u8]) -> Option<Bytes> {
+        let value = self.inner.get_node(key);
+        let mut state = self.recorder.0.borrow_mut();
+        let key = Bytes::copy_from_slice(key);
+        if let Some(value) = value.as_ref() {
+            if !state.written_nodes.contains(&key) {
+                state.nodes.insert(key, value.clone());
+            }
+        }
+        value
+    }
+
+    fn update_node(&mut self, key: &[u8], value: Bytes) {
+        let key = Bytes::copy_from_slice(key);
+        let mut state = self.recorder.0.borrow_mut();
+        if !state.nodes.contains_key(&key) {
+            state.written_nodes.insert(key.clone());
+        }
+        self.inner.update_node(&key, value);
+    }
+
+    fn get_preimage(&mut self, key: &[u8]) -> Option<Bytes> {
+        let value = self.inner.get_preimage(key);
+        let mut state = self.recorder.0.borrow_mut();
+        let key = Bytes::copy_from_slice(key);
+        if !state.written_preimages.contains(&key) {
+            state
+                .preimages
+                .insert(key, value.clone().unwrap_or_default());
+        }
+        value
+    }
+
+    fn update_preimage(&mut self, key: &[u8], value: Bytes) {
+        let key = Bytes::copy_from_slice(key);
+        let mut state = self.recorder.0.borrow_mut();
+        if !state.preimages.contains_key(&key) {
+            state.written_preimages.insert(key.clone());
+        }
+        self.inner.update_preimage(&key, value);
+    }
+}
+
+/// `TrieDb` that serves reads only from the witness and values written during the execution.
+/// Reads of keys missing in the witness return nothing and are reported by [WitnessTrieDb::missing]
+/// handle, so the result of such execution can be rejected.
+pub struct WitnessTrieDb {
+    nodes: HashMap<Bytes, Bytes>,
+    preimages: HashMap<Bytes, Bytes>,
+    missing: Rc<RefCell<Vec<Bytes>>>,
+}
+
+impl WitnessTrieDb {
+    pub fn new(witness: &StateWitness) -> Self {
+        Self {
+            nodes: witness.nodes.clone().into_iter().collect(),
+            preimages: witness.preimages.clone().into_iter().collect(),
+            missing: Default::default(),
+        }
+    }
+
+    /// Returns a shared list of keys that were read, but are not a part of the witness.
+    pub fn missing(&self) -> Rc<RefCell<Vec<Bytes>>> {
+        self.missing.clone()
+    }
+
+    fn read(
+        values: &HashMap<Bytes, Bytes>,
+        missing: &RefCell<Vec<Bytes>>,
+        key: &[u8],
+    ) -> Option<Bytes> {
+        let key = Bytes::copy_from_slice(key);
+        let value = values.get(&key).cloned();
+        if value.is_none() {
+            missing.borrow_mut().push(key);
+        }
+        value
+    }
+}
+
+impl TrieDb for WitnessTrieDb {
+    fn get_node(&mut self, key: &[u8]) -> Option<Bytes> {
+        Self::read(&self.nodes, &self.missing, key)
+    }
+
+    fn update_node(&mut self, key: &[u8], value: Bytes) {
+        self.nodes.insert(Bytes::copy_from_slice(key), value);
+    }
+
+    fn get_preimage(&mut self, key: &[u8]) -> Option<Bytes> {
+        Self::read(&self.preimages, &self.missing, key)
+    }
+
+    fn update_preimage(&mut self, key: &[u8], value: Bytes) {
+        self.preimages.insert(Bytes::copy_from_slice(key), value);
+    }
+}
+
+pub type RecordingRuntimeDatabase<DB> = JournaledTrie<ZkTrieStateDb<RecordingTrieDb<DB>>>;
+
+pub type WitnessRuntimeDatabase = JournaledTrie<ZkTrieStateDb<WitnessTrieDb>>;
+
+/// Executes the context over the state with the specified root and returns the execution result
+/// with the witness of the state accessed by the call (including nested calls). Module cache is
+/// bypassed, so bytecode of all called contracts is loaded from the state and gets recorded.
+pub fn execute_with_witness<DB: TrieDb>(
+    db: DB,
+    root: &[u8; 32],
+    ctx: RuntimeContext<RecordingRuntimeDatabase<DB>>,
+) -> Result<(ExecutionResult, StateWitness), RuntimeError> {
+    let db = RecordingTrieDb::new(db);
+    let recorder = db.recorder();
+    let jzkt = JournaledTrie::new(ZkTrieStateDb::new_opened(db, root));
+    let ctx = ctx.with_jzkt(jzkt).with_module_cache(false);
+    let execution_result = Runtime::run_with_context(ctx)?;
+    Ok((execution_result, recorder.witness(root)))
+}
+
+/// Re-executes the context using only the state from the witness, fails if the execution reads
+/// any node or preimage that isn't a part of the witness.
+pub fn execute_stateless(
+    witness: &StateWitness,
+    ctx: RuntimeContext<WitnessRuntimeDatabase>,
+) -> Result<ExecutionResult, RuntimeError> {
+    let db = WitnessTrieDb::new(witness);
+    let missing = db.missing();
+    let jzkt = JournaledTrie::new(ZkTrieStateDb::new_opened(db, &witness.root));
+    let execution_result = Runtime::run_with_context(ctx.with_jzkt(jzkt))?;
+    if let Some(key) = missing.borrow().first() {
+        return Err(RuntimeError::IncompleteWitness(key.clone()));
+    }
+    Ok(execution_result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        storage::TrieStorage,
+        tests::wat2rwasm,
+        types::{InMemoryTrieDb, RuntimeError, TrieDb},
+        witness::{execute_stateless, execute_with_witness},
+        zktrie::ZkTrieStateDb,
+        RuntimeContext,
+    };
+    use fluentbase_types::Bytes;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Default, Clone)]
+    struct SharedTrieDb(Rc<RefCell<InMemoryTrieDb>>);
+
+    impl TrieDb for SharedTrieDb {
+        fn get_node(&mut self, key: &[u8]) -> Option<Bytes> {
+            self.0.borrow_mut().get_node(key)
+        }
+
+        fn update_node(&mut self, key: &[u8], value: Bytes) {
+            self.0.borrow_mut().update_node(key, value)
+        }
+
+        fn get_preimage(&mut self, key: &[u8]) -> Option<Bytes> {
+            self.0.borrow_mut().get_preimage(key)
+        }
+
+        fn update_preimage(&mut self, key: &[u8], value: Bytes) {
+            self.0.borrow_mut().update_preimage(key, value)
+        }
+    }
+
+    #[test]
+    fn test_stateless_execution() {
+        let db = SharedTrieDb::default();
+        let root = {
+            let mut zkt = ZkTrieStateDb::new_empty(db.clone());
+            zkt.update(&[1u8; 32], 0, &vec![[2u8; 32]]).unwrap();
+            zkt.update(&[3u8; 32], 0, &vec![[4u8; 32]]).unwrap();
+            zkt.compute_root()
+        };
+        // read the first field of the `0x0101..01` key and return it
+        let rwasm_binary = wat2rwasm(
+            r#"
+(module
+  (type (;0;) (func (param i32 i32 i32 i32) (result i32)))
+  (type (;1;) (func))
+  (type (;2;) (func (param i32 i32)))
+  (import "fluentbase_v1alpha" "_jzkt_get" (func $_jzkt_get (type 0)))
+  (import "fluentbase_v1alpha" "_sys_write" (func $_sys_write (type 2)))
+  (func $main (type 1)
+    i32.const 0
+    i32.const 0
+    i32.const 32
+    i32.const 0
+    call $_jzkt_get
+    drop
+    i32.const 32
+    i32.const 32
+    call $_sys_write
+    )
+  (memory (;0;) 1)
+  (data (;0;) (i32.const 0) "\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01")
+  (export "main" (func $main)))
+    "#,
+        );
+        let ctx = RuntimeContext::new(rwasm_binary.clone()).with_fuel_limit(1_000_000);
+        let (execution_result, witness) = execute_with_witness(db, &root, ctx).unwrap();
+        assert_eq!(execution_result.exit_code, 0);
+        assert_eq!(execution_result.output, vec![2u8; 32]);
+        assert_eq!(witness.root, root);
+        assert!(!witness.nodes.is_empty());
+        // re-execute the transaction using the witness only
+        let ctx = RuntimeContext::new(rwasm_binary.clone()).with_fuel_limit(1_000_000);
+        let stateless_result = execute_stateless(&witness, ctx).unwrap();
+        assert_eq!(stateless_result.output, execution_result.output);
+        assert_eq!(
+            stateless_result.fuel_consumed,
+            execution_result.fuel_consumed
+        );
+        // execution must fail if any of the accessed nodes is missing
+        let mut incomplete_witness = witness.clone();
+        let key = incomplete_witness.nodes.keys().last().cloned().unwrap();
+        incomplete_witness.nodes.remove(&key);
+        let ctx = RuntimeContext::new(rwasm_binary).with_fuel_limit(1_000_000);
+        assert!(matches!(
+            execute_stateless(&incomplete_witness, ctx),
+            Err(RuntimeError::IncompleteWitness(_))
+        ));
+    }
+}
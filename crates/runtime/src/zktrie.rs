@@ -1,5 +1,5 @@
 use crate::{storage::TrieStorage, types::TrieDb};
-use fluentbase_types::{Bytes, ExitCode, POSEIDON_EMPTY};
+use fluentbase_types::{Bytes, ExitCode};
 use fluentbase_zktrie::{
     Byte32,
     Database,
@@ -150,7 +150,9 @@ impl<DB: TrieDb> TrieStorage for ZkTrieStateDb<DB> {
     }
 
     fn remove(&mut self, key: &[u8]) -> Result<(), ExitCode> {
-        self.update(key, 0, &vec![POSEIDON_EMPTY.0])
+        let trie = self.trie.as_mut().unwrap();
+        trie.delete(&mut self.storage, key)
+            .map_err(|_| ExitCode::PersistentStorageError)
     }
 
     fn proof(&self, key: &[u8; 32]) -> Option<Vec<Vec<u8>>> {
@@ -161,6 +163,11 @@ impl<DB: TrieDb> TrieStorage for ZkTrieStateDb<DB> {
         }
     }
 
+    fn proof_at(&self, root32: &[u8; 32], key: &[u8; 32]) -> Option<Vec<Vec<u8>>> {
+        let trie = ZkTrie::<PoseidonHash>::new(MAX_LEVEL, Hash::from_bytes(root32));
+        trie.proof(&self.storage, &key[..]).ok()
+    }
+
     fn get_preimage(&mut self, key: &[u8]) -> Option<Bytes> {
         self.storage.0.borrow_mut().get_preimage(key)
     }
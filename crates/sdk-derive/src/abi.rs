@@ -0,0 +1,227 @@
+use crate::{rust_name_to_sol, rust_type_to_sol, RoutedMethod};
+use syn::{
+    bracketed,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    FnArg,
+    Ident,
+    LitInt,
+    Pat,
+    ReturnType,
+    Token,
+};
+
+/// Solidity event declaration passed to the router attribute, like
+/// `event Transfer(address indexed from, address indexed to, uint256 value);`.
+pub(crate) struct SolEvent {
+    name: Ident,
+    inputs: Vec<SolEventParam>,
+    anonymous: bool,
+}
+
+struct SolEventParam {
+    ty: String,
+    indexed: bool,
+    name: Option<Ident>,
+}
+
+/// List of events declared in the attribute of `derive_solidity_router`.
+pub(crate) struct SolEvents(pub(crate) Vec<SolEvent>);
+
+fn parse_sol_type(input: ParseStream) -> syn::Result<String> {
+    let mut ty = if input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        let elems = Punctuated::<SolType, Token![,]>::parse_terminated(&content)?;
+        let elems = elems.into_iter().map(|elem| elem.0).collect::<Vec<_>>();
+        format!("({})", elems.join(","))
+    } else {
+        let ident = Ident::parse_any(input)?.to_string();
+        match ident.as_str() {
+            "uint" => "uint256".to_string(),
+            "int" => "int256".to_string(),
+            _ => ident,
+        }
+    };
+    while input.peek(syn::token::Bracket) {
+        let content;
+        bracketed!(content in input);
+        if content.is_empty() {
+            ty.push_str("[]");
+        } else {
+            let len: LitInt = content.parse()?;
+            ty.push_str(&format!("[{}]", len.base10_parse::<usize>()?));
+        }
+    }
+    Ok(ty)
+}
+
+struct SolType(String);
+
+impl Parse for SolType {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        parse_sol_type(input).map(SolType)
+    }
+}
+
+impl Parse for SolEventParam {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = parse_sol_type(input)?;
+        let mut indexed = false;
+        if input.peek(Ident) && input.fork().parse::<Ident>()? == "indexed" {
+            input.parse::<Ident>()?;
+            indexed = true;
+        }
+        let name = if input.is_empty() || input.peek(Token![,]) {
+            None
+        } else {
+            Some(Ident::parse_any(input)?)
+        };
+        Ok(Self { ty, indexed, name })
+    }
+}
+
+impl Parse for SolEvent {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword: Ident = input.parse()?;
+        if keyword != "event" {
+            return Err(syn::Error::new(
+                keyword.span(),
+                "expected `event` declaration",
+            ));
+        }
+        let name: Ident = input.parse()?;
+        let content;
+        parenthesized!(content in input);
+        let inputs = Punctuated::<SolEventParam, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+        let mut anonymous = false;
+        if input.peek(Ident) {
+            let ident: Ident = input.parse()?;
+            if ident != "anonymous" {
+                return Err(syn::Error::new(ident.span(), "expected `anonymous` or `;`"));
+            }
+            anonymous = true;
+        }
+        Ok(Self {
+            name,
+            inputs,
+            anonymous,
+        })
+    }
+}
+
+impl Parse for SolEvents {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let events = Punctuated::<SolEvent, Token![;]>::parse_terminated(input)?;
+        Ok(Self(events.into_iter().collect()))
+    }
+}
+
+/// Splits tuple type like `(uint256,(address,bool)[])` into its components.
+fn split_tuple_components(ty: &str) -> Vec<&str> {
+    let mut components = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in ty.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                components.push(&ty[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < ty.len() {
+        components.push(&ty[start..]);
+    }
+    components
+}
+
+fn abi_param_json(name: &str, ty: &str, indexed: Option<bool>) -> String {
+    let mut json = format!("{{\"name\":\"{}\",", name);
+    if ty.starts_with('(') {
+        let end = ty.rfind(')').unwrap_or(ty.len() - 1);
+        let components = split_tuple_components(&ty[1..end])
+            .into_iter()
+            .map(|ty| abi_param_json("", ty, None))
+            .collect::<Vec<_>>();
+        json.push_str(&format!(
+            "\"type\":\"tuple{}\",\"components\":[{}]",
+            &ty[end + 1..],
+            components.join(",")
+        ));
+    } else {
+        json.push_str(&format!("\"type\":\"{}\"", ty));
+    }
+    if let Some(indexed) = indexed {
+        json.push_str(&format!(",\"indexed\":{}", indexed));
+    }
+    json.push('}');
+    json
+}
+
+fn function_abi_json(method: &RoutedMethod<'_>) -> syn::Result<String> {
+    let sig = &method.func.sig;
+    let mut inputs = Vec::new();
+    for param in sig.inputs.iter() {
+        let param = match param {
+            FnArg::Receiver(_) => continue,
+            FnArg::Typed(param) => param,
+        };
+        let name = match param.pat.as_ref() {
+            Pat::Ident(pat) => rust_name_to_sol(&pat.ident).to_string(),
+            _ => String::new(),
+        };
+        inputs.push(abi_param_json(&name, &rust_type_to_sol(&param.ty)?, None));
+    }
+    let outputs = match &sig.output {
+        ReturnType::Default => vec![],
+        ReturnType::Type(_, ty) => vec![abi_param_json("", &rust_type_to_sol(ty)?, None)],
+    };
+    Ok(format!(
+        "{{\"type\":\"function\",\"name\":\"{}\",\"inputs\":[{}],\"outputs\":[{}],\"stateMutability\":\"{}\"}}",
+        rust_name_to_sol(&sig.ident),
+        inputs.join(","),
+        outputs.join(","),
+        method.state_mutability,
+    ))
+}
+
+fn event_abi_json(event: &SolEvent) -> String {
+    let inputs = event
+        .inputs
+        .iter()
+        .map(|param| {
+            let name = param
+                .name
+                .as_ref()
+                .map(|name| name.to_string())
+                .unwrap_or_default();
+            abi_param_json(&name, &param.ty, Some(param.indexed))
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "{{\"type\":\"event\",\"name\":\"{}\",\"inputs\":[{}],\"anonymous\":{}}}",
+        event.name,
+        inputs.join(","),
+        event.anonymous,
+    )
+}
+
+/// Builds Ethereum ABI JSON for the routed methods and declared events.
+pub(crate) fn expand_abi_json(
+    methods: &[RoutedMethod<'_>],
+    events: &SolEvents,
+) -> syn::Result<String> {
+    let mut items = methods
+        .iter()
+        .map(function_abi_json)
+        .collect::<syn::Result<Vec<_>>>()?;
+    items.extend(events.0.iter().map(event_abi_json));
+    Ok(format!("[{}]", items.join(",")))
+}
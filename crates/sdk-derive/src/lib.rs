@@ -1,50 +1,125 @@
+use crate::abi::{expand_abi_json, SolEvents};
 use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
-use syn::{self, Expr, FnArg, Ident, ImplItem, Type, Visibility};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    self,
+    spanned::Spanned,
+    Expr,
+    FnArg,
+    GenericArgument,
+    Ident,
+    ImplItem,
+    ImplItemFn,
+    Lit,
+    PathArguments,
+    ReturnType,
+    Type,
+    Visibility,
+};
+
+mod abi;
 
 #[proc_macro]
 pub fn derive_keccak256_id(token: TokenStream) -> TokenStream {
+    let method_id: u32 = keccak256_id(&token.to_string());
+    TokenStream::from(quote! {
+        #method_id
+    })
+}
+
+fn keccak256_id(signature: &str) -> u32 {
     use crypto_hashes::{digest::Digest, sha3::Keccak256};
     let mut hash = Keccak256::new();
-    hash.update(token.to_string());
+    hash.update(signature);
     let mut dst = [0u8; 4];
     dst.copy_from_slice(hash.finalize().as_slice()[0..4].as_ref());
-    let method_id: u32 = u32::from_be_bytes(dst);
-    TokenStream::from(quote! {
-        #method_id
-    })
+    u32::from_be_bytes(dst)
 }
 
-fn rust_type_to_sol(ty: &Type) -> String {
-    let mut result = String::new();
-    match &ty {
+fn is_u8(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident("u8"),
+        _ => false,
+    }
+}
+
+/// Maps Rust type to Solidity type, byte arrays are mapped to `bytes`/`bytesN` like
+/// `alloy_sol_types` encodes them.
+fn rust_type_to_sol(ty: &Type) -> syn::Result<String> {
+    let result = match &ty {
         Type::Array(ty) => {
-            match &ty.len {
-                Expr::Const(val) => {
-                    unreachable!("how to extract int len? {:?}", val.to_token_stream());
-                }
-                _ => unreachable!("not supported expr: {:?}", ty.len.to_token_stream()),
+            let len = match &ty.len {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Int(len) => len.base10_parse::<usize>()?,
+                    _ => return Err(syn::Error::new(ty.len.span(), "not supported array length")),
+                },
+                _ => return Err(syn::Error::new(ty.len.span(), "not supported array length")),
+            };
+            if is_u8(&ty.elem) && (1..=32).contains(&len) {
+                format!("bytes{}", len)
+            } else {
+                format!("{}[{}]", rust_type_to_sol(&ty.elem)?, len)
             }
-            // result.extend(&rust_type_to_solidity(&ty.elem));
-            // result.extend("[]");
-        }
-        Type::Paren(ty) => {
-            result.extend(rust_type_to_sol(&ty.elem).chars());
-        }
-        Type::Slice(ty) => {
-            result.extend(rust_type_to_sol(&ty.elem).chars());
-            result.extend("[]".chars());
         }
+        Type::Paren(ty) => rust_type_to_sol(&ty.elem)?,
+        Type::Slice(ty) if is_u8(&ty.elem) => "bytes".to_string(),
+        Type::Slice(ty) => format!("{}[]", rust_type_to_sol(&ty.elem)?),
         Type::Tuple(ty) => {
-            for ty in ty.elems.iter() {
-                result.extend(rust_type_to_sol(&ty).chars());
-                result.extend(",".chars());
+            let elems = ty
+                .elems
+                .iter()
+                .map(rust_type_to_sol)
+                .collect::<syn::Result<Vec<_>>>()?;
+            format!("({})", elems.join(","))
+        }
+        Type::Path(path) => {
+            let segment = path
+                .path
+                .segments
+                .last()
+                .ok_or(syn::Error::new(path.span(), "empty type path"))?;
+            let ident = segment.ident.to_string();
+            match ident.as_str() {
+                "bool" => "bool".to_string(),
+                "u8" | "u16" | "u32" | "u64" | "u128" => format!("uint{}", &ident[1..]),
+                "i8" | "i16" | "i32" | "i64" | "i128" => format!("int{}", &ident[1..]),
+                "U256" => "uint256".to_string(),
+                "I256" => "int256".to_string(),
+                "Address" => "address".to_string(),
+                "B256" => "bytes32".to_string(),
+                "Bytes" => "bytes".to_string(),
+                "String" => "string".to_string(),
+                "Vec" => {
+                    let elem = match &segment.arguments {
+                        PathArguments::AngleBracketed(args) => match args.args.first() {
+                            Some(GenericArgument::Type(elem)) => elem,
+                            _ => return Err(syn::Error::new(segment.span(), "missing vec type")),
+                        },
+                        _ => return Err(syn::Error::new(segment.span(), "missing vec type")),
+                    };
+                    if is_u8(elem) {
+                        "bytes".to_string()
+                    } else {
+                        format!("{}[]", rust_type_to_sol(elem)?)
+                    }
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        ty.span(),
+                        format!("not supported type: {}", ty.to_token_stream()),
+                    ))
+                }
             }
         }
-        _ => unreachable!("not supported type: {:?}", ty.to_token_stream()),
-    }
-    result
+        _ => {
+            return Err(syn::Error::new(
+                ty.span(),
+                format!("not supported type: {}", ty.to_token_stream()),
+            ))
+        }
+    };
+    Ok(result)
 }
 
 fn rust_name_to_sol(ident: &Ident) -> Ident {
@@ -53,29 +128,320 @@ fn rust_name_to_sol(ident: &Ident) -> Ident {
     Ident::new(camel_name.as_str(), span)
 }
 
-#[proc_macro]
-pub fn derive_solidity_router(token: TokenStream) -> TokenStream {
-    let ast: syn::ItemImpl = syn::parse(token).unwrap();
+/// Routed method of the contract with its Solidity signature, like `transfer(address,uint256)`.
+struct RoutedMethod<'a> {
+    func: &'a ImplItemFn,
+    signature: String,
+    state_mutability: &'static str,
+}
+
+const STATE_MUTABILITY_ATTRS: [&str; 3] = ["view", "pure", "payable"];
+
+/// Returns state mutability of the method set by `#[view]`, `#[pure]` or `#[payable]` attribute.
+/// Storage can be modified w/o `&mut self`, so methods are `nonpayable` unless annotated.
+fn state_mutability(func: &ImplItemFn) -> syn::Result<&'static str> {
+    let mut state_mutability = None;
+    for attr in func.attrs.iter() {
+        let Some(name) = STATE_MUTABILITY_ATTRS
+            .iter()
+            .find(|name| attr.path().is_ident(name))
+        else {
+            continue;
+        };
+        attr.meta.require_path_only()?;
+        if state_mutability.is_some() {
+            return Err(syn::Error::new(
+                attr.span(),
+                "state mutability is already specified",
+            ));
+        }
+        state_mutability = Some(*name);
+    }
+    Ok(state_mutability.unwrap_or("nonpayable"))
+}
+
+/// Removes state mutability attributes, since they are not real attributes and can't be emitted.
+fn strip_state_mutability_attrs(ast: &mut syn::ItemImpl) {
+    for item in ast.items.iter_mut() {
+        if let ImplItem::Fn(func) = item {
+            func.attrs.retain(|attr| {
+                !STATE_MUTABILITY_ATTRS
+                    .iter()
+                    .any(|name| attr.path().is_ident(name))
+            });
+        }
+    }
+}
+
+fn routed_methods(ast: &syn::ItemImpl) -> syn::Result<Vec<RoutedMethod<'_>>> {
+    let mut methods = Vec::new();
     for item in &ast.items {
-        match item {
-            ImplItem::Fn(func) => {
-                match func.vis {
-                    Visibility::Public(_) => {}
-                    _ => continue,
+        let func = match item {
+            ImplItem::Fn(func) => func,
+            _ => continue,
+        };
+        match func.vis {
+            Visibility::Public(_) => {}
+            _ => continue,
+        }
+        let mut params = Vec::new();
+        for param in func.sig.inputs.iter() {
+            let param = match param {
+                FnArg::Receiver(_) => continue,
+                FnArg::Typed(param) => param,
+            };
+            params.push(rust_type_to_sol(&param.ty)?);
+        }
+        let signature = format!(
+            "{}({})",
+            rust_name_to_sol(&func.sig.ident),
+            params.join(",")
+        );
+        methods.push(RoutedMethod {
+            func,
+            signature,
+            state_mutability: state_mutability(func)?,
+        });
+    }
+    Ok(methods)
+}
+
+fn expand_router_dispatch(method: &RoutedMethod<'_>) -> syn::Result<proc_macro2::TokenStream> {
+    let sig = &method.func.sig;
+    let selector = keccak256_id(&method.signature);
+    let fn_name = &sig.ident;
+    let mut arg_names = Vec::new();
+    let mut arg_types = Vec::new();
+    for (i, param) in sig.inputs.iter().enumerate() {
+        let param = match param {
+            FnArg::Receiver(_) => continue,
+            FnArg::Typed(param) => param,
+        };
+        if let Type::Reference(_) = param.ty.as_ref() {
+            return Err(syn::Error::new(
+                param.ty.span(),
+                "references are not supported in routed methods",
+            ));
+        }
+        arg_names.push(format_ident!("arg{}", i));
+        arg_types.push(param.ty.as_ref().clone());
+    }
+    let call = match sig.receiver() {
+        Some(_) => quote! { self.#fn_name(#(#arg_names),*) },
+        None => quote! { Self::#fn_name(#(#arg_names),*) },
+    };
+    let decode = if arg_names.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let args = <(#(#arg_types,)*) as alloy_sol_types::SolValue>::abi_decode_params(
+                &input[4..],
+                true,
+            );
+            let (#(#arg_names,)*) = match args {
+                Ok(args) => args,
+                Err(_) => {
+                    LowLevelSDK::sys_halt(ExitCode::InputDecodeFailure.into_i32());
+                    return;
                 }
-                let ident = rust_name_to_sol(&func.sig.ident);
-                println!("{:?}", ident.to_string());
-                for param in func.sig.inputs.iter() {
-                    let param = match param {
-                        FnArg::Receiver(_) => continue,
-                        FnArg::Typed(param) => param,
-                    };
-                    let ty = rust_type_to_sol(&param.ty);
-                    println!("{:?}", ty.to_string());
+            };
+        }
+    };
+    // Solidity reverts calls with value to the methods that aren't payable
+    let value_check = if method.state_mutability == "payable" {
+        quote! {}
+    } else {
+        quote! {
+            if !ctx.contract_value().is_zero() {
+                LowLevelSDK::sys_halt(ExitCode::NotSupportedCall.into_i32());
+                return;
+            }
+        }
+    };
+    let result = match &sig.output {
+        ReturnType::Default => quote! {
+            #call;
+        },
+        ReturnType::Type(_, _) => quote! {
+            let output = #call;
+            LowLevelSDK::sys_write(&alloy_sol_types::SolValue::abi_encode_params(&(output,)));
+        },
+    };
+    Ok(quote! {
+        #selector => {
+            #value_check
+            #decode
+            #result
+        }
+    })
+}
+
+/// Generates a Solidity compatible router for public methods of the `impl` block.
+///
+/// A `main` method is added to the type, it reads contract input, matches the 4-byte Solidity
+/// selector of the method (camel case name with Solidity types of arguments), decodes ABI
+/// arguments, invokes the method and writes ABI encoded result into the output. Contract halts
+/// with an error exit code for unknown selectors, malformed input or value passed to the method
+/// that isn't payable.
+///
+/// Standard Ethereum ABI JSON of the routed methods is stored in the `ABI_JSON` constant. Methods
+/// are `nonpayable` unless they are annotated with `#[view]`, `#[pure]` or `#[payable]`. Events
+/// aren't part of the `impl` block, so they can be declared in the attribute using Solidity
+/// syntax:
+///
+/// ```ignore
+/// #[derive_solidity_router(
+///     event Transfer(address indexed from, address indexed to, uint256 value);
+/// )]
+/// impl<'a> ERC20<'a> {
+///     #[view]
+///     pub fn balance_of(&self, address: Address) -> U256 { ... }
+///
+///     pub fn transfer(&mut self, to: Address, value: U256) -> U256 { ... }
+/// }
+/// ```
+///
+/// Generated code relies on `alloy_sol_types` for ABI encoding, so the contract must have it as
+/// a dependency.
+#[proc_macro_attribute]
+pub fn derive_solidity_router(attr: TokenStream, token: TokenStream) -> TokenStream {
+    match expand_solidity_router(attr.into(), token.into()) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_solidity_router(
+    attr: proc_macro2::TokenStream,
+    token: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let events: SolEvents = syn::parse2(attr)?;
+    let ast: syn::ItemImpl = syn::parse2(token)?;
+    let methods = routed_methods(&ast)?;
+    let dispatches = methods
+        .iter()
+        .map(expand_router_dispatch)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let abi_json = expand_abi_json(&methods, &events)?;
+    let mut item_impl = ast.clone();
+    strip_state_mutability_attrs(&mut item_impl);
+    let (impl_generics, _, where_clause) = ast.generics.split_for_impl();
+    let self_ty = &ast.self_ty;
+    Ok(quote! {
+        #item_impl
+
+        impl #impl_generics #self_ty #where_clause {
+            /// Ethereum ABI JSON of the contract
+            pub const ABI_JSON: &'static str = #abi_json;
+
+            pub fn main(&mut self) {
+                use fluentbase_sdk::{ContextReader, ExitCode, LowLevelAPI, LowLevelSDK};
+                let ctx = fluentbase_sdk::ExecutionContext::default();
+                let input = ctx.contract_input();
+                if input.len() < 4 {
+                    LowLevelSDK::sys_halt(ExitCode::InputDecodeFailure.into_i32());
+                    return;
+                }
+                let selector = u32::from_be_bytes([input[0], input[1], input[2], input[3]]);
+                match selector {
+                    #(#dispatches)*
+                    _ => {
+                        LowLevelSDK::sys_halt(ExitCode::NotSupportedCall.into_i32());
+                    }
                 }
             }
-            _ => continue,
         }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn expand(attr: proc_macro2::TokenStream, item: proc_macro2::TokenStream) -> String {
+        expand_solidity_router(attr, item).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_selectors() {
+        assert_eq!(keccak256_id("transfer(address,uint256)"), 0xa9059cbb);
+        assert_eq!(keccak256_id("balanceOf(address)"), 0x70a08231);
+        assert_eq!(keccak256_id("totalSupply()"), 0x18160ddd);
+    }
+
+    #[test]
+    fn test_type_mapping() {
+        let cases: Vec<(Type, &str)> = vec![
+            (parse_quote!(U256), "uint256"),
+            (parse_quote!(u64), "uint64"),
+            (parse_quote!(Address), "address"),
+            (parse_quote!(Bytes), "bytes"),
+            (parse_quote!(Vec<u8>), "bytes"),
+            (parse_quote!([u8; 4]), "bytes4"),
+            (parse_quote!([u8; 32]), "bytes32"),
+            (parse_quote!(Vec<Vec<u8>>), "bytes[]"),
+            (parse_quote!([U256; 2]), "uint256[2]"),
+            (parse_quote!(Vec<Address>), "address[]"),
+            (parse_quote!((Address, bool)), "(address,bool)"),
+        ];
+        for (ty, expected) in cases {
+            assert_eq!(rust_type_to_sol(&ty).unwrap(), expected);
+        }
+        assert!(rust_type_to_sol(&parse_quote!(HashMap<U256, U256>)).is_err());
+    }
+
+    #[test]
+    fn test_router_dispatch() {
+        let result = expand(
+            quote! {},
+            quote! {
+                impl Token {
+                    pub fn transfer(&mut self, to: Address, value: U256) -> U256 { value }
+                    #[payable]
+                    pub fn deposit(&mut self) {}
+                    fn internal(&self) {}
+                }
+            },
+        );
+        // selectors of public methods only
+        assert!(result.contains(&format!("{}u32 =>", 0xa9059cbbu32)));
+        assert!(result.contains(&format!("{}u32 =>", keccak256_id("deposit()"))));
+        assert!(!result.contains(&format!("{}u32", keccak256_id("internal()"))));
+        // unknown selectors halt
+        assert!(result.contains("_ => { LowLevelSDK :: sys_halt (ExitCode :: NotSupportedCall"));
+        // only `transfer` rejects value, it isn't payable
+        assert_eq!(result.matches("contract_value ()").count(), 1);
+        // state mutability attributes are stripped
+        assert!(!result.contains("# [payable]"));
+    }
+
+    #[test]
+    fn test_abi_json() {
+        let methods = quote! {
+            impl Token {
+                #[view]
+                pub fn balance_of(&self, owner: Address) -> U256 { U256::ZERO }
+                #[payable]
+                pub fn store(&mut self, data: Vec<u8>, key: [u8; 32]) {}
+            }
+        };
+        let events = quote! {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+        };
+        let ast: syn::ItemImpl = syn::parse2(methods).unwrap();
+        let abi_json = expand_abi_json(
+            &routed_methods(&ast).unwrap(),
+            &syn::parse2(events).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            abi_json,
+            concat!(
+                r#"[{"type":"function","name":"balanceOf","inputs":[{"name":"owner","type":"address"}],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"view"},"#,
+                r#"{"type":"function","name":"store","inputs":[{"name":"data","type":"bytes"},{"name":"key","type":"bytes32"}],"outputs":[],"stateMutability":"payable"},"#,
+                r#"{"type":"event","name":"Transfer","inputs":[{"name":"from","type":"address","indexed":true},{"name":"to","type":"address","indexed":true},{"name":"value","type":"uint256","indexed":false}],"anonymous":false}]"#,
+            )
+        );
     }
-    TokenStream::from(quote! {})
 }
@@ -1,6 +1,10 @@
 use crate::{
     utils::{calc_create2_address, calc_create_address},
+    EvmCallMethodInput,
     EvmCallMethodOutput,
+    EvmCreateMethodInput,
+    EvmCreateMethodOutput,
+    EvmStepTrace,
     LowLevelAPI,
     LowLevelSDK,
 };
@@ -11,6 +15,7 @@ use fluentbase_types::{
     Bytes,
     Bytes32,
     ExitCode,
+    FuelConversion,
     B256,
     F254,
     KECCAK_EMPTY,
@@ -19,7 +24,7 @@ use fluentbase_types::{
     POSEIDON_EMPTY,
     U256,
 };
-use revm_primitives::AccountInfo;
+use revm_primitives::{AccountInfo, Bytecode};
 
 /// Number of fields
 pub const JZKT_ACCOUNT_FIELDS_COUNT: u32 = 6;
@@ -62,7 +67,7 @@ pub trait AccountManager {
         &self,
         hash32_offset: *const u8,
         input: &[u8],
-        fuel_offset: *mut u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> (Bytes, i32);
     fn inc_nonce(&self, account: &mut Account) -> Option<u64>;
@@ -75,6 +80,98 @@ pub trait AccountManager {
     fn write_transient_storage(&self, address: Address, index: U256, value: U256);
     fn transient_storage(&self, address: Address, index: U256) -> U256;
     fn mark_account_created(&self, address: Address);
+    /// Adds the refund to the transaction gas refund counter. The counter is used by translated
+    /// bytecode that can't return refunds through the interpreter, it's reverted together with
+    /// the state on rollback.
+    fn record_refund(&self, refund: i64);
+    /// Returns the transaction gas refund counter.
+    fn refund_counter(&self) -> i64;
+
+    /// Conversion policy between gas and fuel for the rWASM code executed with `exec_hash`.
+    #[inline(always)]
+    fn fuel_conversion(&self) -> FuelConversion {
+        FuelConversion::DEFAULT
+    }
+
+    /// Returns true if frames are inspected, otherwise the loader doesn't keep frame inputs for
+    /// [AccountManager::inspect_call_end] and [AccountManager::inspect_create_end] calls.
+    #[inline(always)]
+    fn is_inspecting(&self) -> bool {
+        false
+    }
+
+    /// Called by the loader before executing a call frame (both EVM and WASM).
+    ///
+    /// Returning `Some` skips the execution and uses the returned output instead, it
+    /// allows tracers and debuggers to override frame results.
+    #[inline(always)]
+    fn inspect_call(
+        &self,
+        _caller: Address,
+        _address: Address,
+        _is_static: bool,
+        _input: &mut EvmCallMethodInput,
+    ) -> Option<EvmCallMethodOutput> {
+        None
+    }
+
+    /// Called by the loader once call frame is finished, the returned output is used as a
+    /// result of the call.
+    #[inline(always)]
+    fn inspect_call_end(
+        &self,
+        _caller: Address,
+        _address: Address,
+        _is_static: bool,
+        _input: &EvmCallMethodInput,
+        output: EvmCallMethodOutput,
+    ) -> EvmCallMethodOutput {
+        output
+    }
+
+    /// Called by the loader before executing a create frame (both EVM and WASM).
+    #[inline(always)]
+    fn inspect_create(
+        &self,
+        _caller: Address,
+        _input: &mut EvmCreateMethodInput,
+    ) -> Option<EvmCreateMethodOutput> {
+        None
+    }
+
+    /// Called by the loader once create frame is finished.
+    #[inline(always)]
+    fn inspect_create_end(
+        &self,
+        _caller: Address,
+        _input: &EvmCreateMethodInput,
+        output: EvmCreateMethodOutput,
+    ) -> EvmCreateMethodOutput {
+        output
+    }
+
+    /// Returns true if EVM interpreter must report every executed opcode using
+    /// [AccountManager::inspect_step]. Step tracing is expensive, so it's disabled by default.
+    #[inline(always)]
+    fn is_step_tracing_enabled(&self) -> bool {
+        false
+    }
+
+    /// Called by the EVM interpreter after each executed opcode when step tracing is enabled.
+    #[inline(always)]
+    fn inspect_step(&self, _step: &EvmStepTrace) {}
+
+    /// Returns analysed EVM bytecode (with the jump table) cached by the previous calls, so hot
+    /// contracts aren't re-analysed on every call. Nothing is cached by default.
+    #[inline(always)]
+    fn analysed_bytecode(&self, _source_code_hash: &B256) -> Option<Bytecode> {
+        None
+    }
+
+    /// Called once EVM bytecode is analysed to let the account manager cache the result by the
+    /// source code hash.
+    #[inline(always)]
+    fn cache_analysed_bytecode(&self, _source_code_hash: &B256, _bytecode: &Bytecode) {}
 }
 
 #[derive(Debug, Clone)]
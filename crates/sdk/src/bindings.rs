@@ -37,7 +37,17 @@ extern "C" {
         fuel_offset: *mut u32,
         state: u32,
     ) -> i32;
+    pub fn _sys_exec_hash64(
+        code_hash32_offset: *const u8,
+        input_offset: *const u8,
+        input_len: u32,
+        return_offset: *mut u8,
+        return_len: u32,
+        fuel_offset: *mut u64,
+        state: u32,
+    ) -> i32;
     pub fn _sys_fuel(delta: u64) -> u64;
+    pub fn _sys_fuel_conversion(output9_offset: *mut u8);
 
     /// Journaled ZK Trie methods to work with blockchain state
     pub fn _jzkt_open(root32_ptr: *const u8);
@@ -73,6 +83,8 @@ extern "C" {
     pub fn _jzkt_rollback(checkpoint: u64);
     pub fn _jzkt_preimage_size(hash32_ptr: *const u8) -> u32;
     pub fn _jzkt_preimage_copy(hash32_ptr: *const u8, preimage_ptr: *mut u8);
+    pub fn _jzkt_tload(key32_ptr: *const u8, output32_ptr: *mut u8);
+    pub fn _jzkt_tstore(key32_ptr: *const u8, value32_ptr: *const u8);
 
     pub fn _wasm_to_rwasm_size(input_ptr: *const u8, input_len: u32) -> i32;
     pub fn _wasm_to_rwasm(
@@ -81,5 +93,13 @@ extern "C" {
         output_ptr: *mut u8,
         output_len: u32,
     ) -> i32;
+    pub fn _evm_to_rwasm_size(input_ptr: *const u8, input_len: u32) -> i32;
+    pub fn _evm_to_rwasm(
+        input_ptr: *const u8,
+        input_len: u32,
+        output_ptr: *mut u8,
+        output_len: u32,
+    ) -> i32;
+    pub fn _evm_jump_table(hash32_ptr: *const u8, output_ptr: *mut u8, output_len: u32) -> i32;
     pub fn _debug_log(msg_ptr: *const u8, msg_len: u32);
 }
@@ -15,7 +15,7 @@ use alloc::{vec, vec::Vec};
 use byteorder::{ByteOrder, LittleEndian};
 use fluentbase_codec::{BufferDecoder, Encoder};
 use fluentbase_codec_derive::Codec;
-use fluentbase_types::{Address, Bytes, Bytes32, B256, U256};
+use fluentbase_types::{Address, Bytes, Bytes32, ExitCode, B256, U256};
 
 pub trait ContextReader {
     fn journal_checkpoint(&self) -> u64;
@@ -26,6 +26,7 @@ pub trait ContextReader {
     fn block_difficulty(&self) -> u64;
     fn block_gas_limit(&self) -> u64;
     fn block_base_fee(&self) -> U256;
+    fn block_blob_base_fee(&self) -> U256;
     fn tx_gas_limit(&self) -> u64;
     fn tx_nonce(&self) -> u64;
     fn tx_gas_price(&self) -> U256;
@@ -40,6 +41,7 @@ pub trait ContextReader {
     fn contract_caller(&self) -> Address;
     fn contract_value(&self) -> U256;
     fn contract_is_static(&self) -> bool;
+    fn contract_depth(&self) -> u32;
     fn contract_input(&self) -> Bytes;
     fn contract_input_size(&self) -> (u32, u32);
 }
@@ -72,6 +74,10 @@ pub struct ContractInput {
     pub contract_value: U256,
     pub contract_is_static: bool,
     pub contract_input: Bytes,
+    // fields below are appended to the end, so contracts built before can still decode the
+    // fields above at the same offsets
+    pub block_blob_base_fee: U256,
+    pub contract_depth: u32,
 }
 
 impl ContextReader for ContractInput {
@@ -107,6 +113,10 @@ impl ContextReader for ContractInput {
         self.block_base_fee
     }
 
+    fn block_blob_base_fee(&self) -> U256 {
+        self.block_blob_base_fee
+    }
+
     fn tx_gas_limit(&self) -> u64 {
         self.tx_gas_limit
     }
@@ -151,6 +161,10 @@ impl ContextReader for ContractInput {
         self.contract_is_static
     }
 
+    fn contract_depth(&self) -> u32 {
+        self.contract_depth
+    }
+
     fn contract_input(&self) -> Bytes {
         self.contract_input.clone()
     }
@@ -256,6 +270,7 @@ impl ContextReader for ExecutionContext {
     impl_reader_func!(fn block_difficulty() -> u64, BlockDifficulty);
     impl_reader_func!(fn block_gas_limit() -> u64, BlockGasLimit);
     impl_reader_func!(fn block_base_fee() -> U256, BlockBaseFee);
+    impl_reader_func!(fn block_blob_base_fee() -> U256, BlockBlobBaseFee);
     // tx info
     impl_reader_func!(fn tx_gas_limit() -> u64, TxGasLimit);
     impl_reader_func!(fn tx_nonce() -> u64, TxNonce);
@@ -271,6 +286,7 @@ impl ContextReader for ExecutionContext {
     impl_reader_func!(fn contract_caller() -> Address, ContractCaller);
     impl_reader_func!(fn contract_value() -> U256, ContractValue);
     impl_reader_func!(fn contract_is_static() -> bool, ContractIsStatic);
+    impl_reader_func!(fn contract_depth() -> u32, ContractDepth);
     impl_reader_func!(@dynamic fn contract_input() -> Bytes, ContractInput);
 }
 
@@ -294,6 +310,30 @@ impl ExecutionContext {
         ContractInput::decode_body(&mut buffer_decoder, 0, &mut contract_input);
         contract_input
     }
+
+    /// Size of the data returned by the last nested call, it's reset on every new call.
+    pub fn return_data_size() -> u32 {
+        LowLevelSDK::sys_output_size()
+    }
+
+    pub fn return_data() -> Bytes {
+        let output_size = LowLevelSDK::sys_output_size();
+        let mut buffer = vec![0u8; output_size as usize];
+        LowLevelSDK::sys_read_output(buffer.as_mut_ptr(), 0, output_size);
+        buffer.into()
+    }
+
+    /// Reads a range of the return data, the same as `RETURNDATACOPY` reading out of the range
+    /// fails with `ExitCode::OutputOverflow`.
+    pub fn return_data_copy(offset: u32, length: u32) -> Result<Bytes, ExitCode> {
+        match offset.checked_add(length) {
+            Some(end) if end <= Self::return_data_size() => {}
+            _ => return Err(ExitCode::OutputOverflow),
+        }
+        let mut buffer = vec![0u8; length as usize];
+        LowLevelSDK::sys_read_output(buffer.as_mut_ptr(), offset, length);
+        Ok(buffer.into())
+    }
 }
 
 #[cfg(test)]
@@ -304,7 +344,7 @@ mod test {
     };
     use fluentbase_codec::{BufferDecoder, Encoder};
     use fluentbase_codec_derive::Codec;
-    use fluentbase_types::Bytes;
+    use fluentbase_types::{Bytes, U256};
 
     #[test]
     fn test_encode_decode() {
@@ -319,4 +359,20 @@ mod test {
         let input = ExecutionContext::default().contract_input();
         assert_eq!(input, contract_input.contract_input);
     }
+
+    #[test]
+    fn test_appended_fields() {
+        let contract_input = ContractInput {
+            contract_input: Bytes::from_static(&[0, 1, 2, 3]),
+            block_blob_base_fee: U256::from(7),
+            contract_depth: 3,
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        let ctx = ExecutionContext::default();
+        assert_eq!(ctx.block_blob_base_fee(), U256::from(7));
+        assert_eq!(ctx.contract_depth(), 3);
+        // dynamic fields are still found by their headers
+        assert_eq!(ctx.contract_input(), contract_input.contract_input);
+    }
 }
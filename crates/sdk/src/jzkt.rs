@@ -3,7 +3,10 @@ use crate::{
     Account,
     AccountCheckpoint,
     AccountManager,
+    ContextReader,
+    ContractInput,
     EvmCallMethodOutput,
+    ExecutionContext,
     LowLevelAPI,
     LowLevelSDK,
     JZKT_ACCOUNT_BALANCE_FIELD,
@@ -17,7 +20,23 @@ use crate::{
 };
 use alloc::vec;
 use byteorder::{ByteOrder, LittleEndian};
-use fluentbase_types::{Address, Bytes, Bytes32, ExitCode, B256, U256};
+use fluentbase_codec::Encoder;
+use fluentbase_types::{
+    address,
+    Address,
+    Bytes,
+    Bytes32,
+    ExitCode,
+    FuelConversion,
+    B256,
+    BLOCK_HASH_HISTORY_ADDRESS,
+    BLOCK_HASH_HISTORY_SIZE,
+    BLOCK_HASH_SERVE_WINDOW,
+    PRECOMPILE_ADDRESSES,
+    STATE_MAIN,
+    U256,
+};
+use revm_primitives::{Bytecode, BytecodeState, JumpMap};
 
 #[derive(Default)]
 pub struct JzktAccountManager;
@@ -84,7 +103,7 @@ impl AccountManager for JzktAccountManager {
             result.source_code_hash.as_mut_ptr(),
             false,
         );
-        (result, true)
+        (result, self.access_account(address))
     }
 
     #[inline(always)]
@@ -127,18 +146,23 @@ impl AccountManager for JzktAccountManager {
         // TODO(dmitry123): "what if account is newly created? then result value must be zero"
         let mut value = U256::ZERO;
         let storage_key = calc_storage_key(&address, slot.as_le_slice().as_ptr());
-        let is_cold = LowLevelSDK::jzkt_get(
+        LowLevelSDK::jzkt_get(
             storage_key.as_ptr(),
             0,
             unsafe { value.as_le_slice_mut().as_mut_ptr() },
             committed,
         );
+        // reading of the committed (original) value isn't an access, it's used for gas calculation
+        let is_cold = !committed && self.access_storage_key(&storage_key);
         (value, is_cold)
     }
 
     #[inline(always)]
     fn write_storage(&self, address: Address, slot: U256, value: U256) -> bool {
         let storage_key = calc_storage_key(&address, slot.as_le_slice().as_ptr());
+        if self.account_flags(address) & ACCOUNT_FLAG_CREATED != 0 {
+            self.track_created_storage_key(address, &storage_key);
+        }
         LowLevelSDK::jzkt_update(
             storage_key.as_ptr(),
             JZKT_STORAGE_COMPRESSION_FLAGS,
@@ -163,7 +187,7 @@ impl AccountManager for JzktAccountManager {
         &self,
         hash32_offset: *const u8,
         input: &[u8],
-        fuel_offset: *mut u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> (Bytes, i32) {
         let exit_code = LowLevelSDK::sys_exec_hash(
@@ -196,34 +220,539 @@ impl AccountManager for JzktAccountManager {
 
     fn precompile(
         &self,
-        _address: &Address,
-        _input: &Bytes,
-        _gas: u64,
+        address: &Address,
+        input: &Bytes,
+        gas: u64,
     ) -> Option<EvmCallMethodOutput> {
-        // in jzkt mode we don't support precompiles
-        None
+        if !self.is_precompile(address) {
+            return None;
+        }
+        // precompiles are rWASM contracts deployed in genesis, so we just execute its bytecode
+        let (account, _) = self.account(*address);
+        let contract_input = ContractInput {
+            journal_checkpoint: self.checkpoint(),
+            contract_gas_limit: gas,
+            contract_address: *address,
+            contract_input: input.clone(),
+            ..Default::default()
+        };
+        // precompiles are priced by their EIPs, so they charge the rest of their gas cost after
+        // the execution converting it into fuel like the consumed fuel is converted back here
+        let fuel_conversion = self.fuel_conversion();
+        let fuel_limit = fuel_conversion.gas_to_fuel(gas);
+        let mut fuel_remaining = fuel_limit;
+        let (output, exit_code) = self.exec_hash(
+            account.rwasm_code_hash.as_ptr(),
+            &contract_input.encode_to_vec(0),
+            &mut fuel_remaining as *mut u64,
+            STATE_MAIN,
+        );
+        Some(EvmCallMethodOutput {
+            output,
+            exit_code,
+            gas_remaining: fuel_conversion.remaining_gas(gas, fuel_limit - fuel_remaining),
+            gas_refund: 0,
+        })
+    }
+
+    fn is_precompile(&self, address: &Address) -> bool {
+        // precompiles that aren't deployed in genesis have no code, so executing them would
+        // succeed with an empty output, it's safer to treat them as ordinary accounts
+        PRECOMPILE_ADDRESSES.contains(address) && self.rwasm_code_size(address) > 0
+    }
+
+    fn self_destruct(&self, address: Address, target: Address) -> [bool; 4] {
+        let (mut account, _) = self.account(address);
+        let (mut target_account, is_cold) = self.account(target);
+        let had_value = account.balance != U256::ZERO;
+        let target_exists = target_account.is_not_empty();
+        let flags = self.account_flags(address);
+        let previously_destroyed = flags & ACCOUNT_FLAG_DESTROYED != 0;
+        // if target is the same account then balance is burned (only for the created accounts)
+        if address != target {
+            Account::transfer(&mut account, &mut target_account, account.balance)
+                .expect("balance can't overflow");
+            self.write_account(&target_account);
+        }
+        // since Cancun (EIP-6780) account is removed only if it's created in the same transaction,
+        // its storage can be written only in this transaction, so all the written slots are known
+        if flags & ACCOUNT_FLAG_CREATED != 0 {
+            LowLevelSDK::jzkt_remove(address.into_word().as_ptr());
+            self.wipe_created_storage(address);
+            self.write_account_flags(address, flags | ACCOUNT_FLAG_DESTROYED);
+        } else {
+            self.write_account(&account);
+        }
+        [had_value, target_exists, is_cold, previously_destroyed]
+    }
+
+    fn block_hash(&self, number: U256) -> B256 {
+        let block_number = ExecutionContext::DEFAULT.block_number();
+        let number = match u64::try_from(number) {
+            Ok(number) if number < block_number => number,
+            _ => return B256::ZERO,
+        };
+        if block_number - number > BLOCK_HASH_SERVE_WINDOW {
+            return B256::ZERO;
+        }
+        let (value, _) = self.storage(
+            BLOCK_HASH_HISTORY_ADDRESS,
+            U256::from(number % BLOCK_HASH_HISTORY_SIZE),
+            false,
+        );
+        B256::from(value.to_le_bytes::<32>())
+    }
+
+    fn write_transient_storage(&self, address: Address, index: U256, value: U256) {
+        let storage_key = calc_storage_key(&address, index.as_le_slice().as_ptr());
+        LowLevelSDK::jzkt_tstore(storage_key.as_ptr(), value.as_le_slice().as_ptr());
+    }
+
+    fn transient_storage(&self, address: Address, index: U256) -> U256 {
+        let mut value = U256::ZERO;
+        let storage_key = calc_storage_key(&address, index.as_le_slice().as_ptr());
+        LowLevelSDK::jzkt_tload(storage_key.as_ptr(), unsafe {
+            value.as_le_slice_mut().as_mut_ptr()
+        });
+        value
+    }
+
+    fn mark_account_created(&self, address: Address) {
+        let flags = self.account_flags(address);
+        self.write_account_flags(address, flags | ACCOUNT_FLAG_CREATED);
+    }
+
+    fn record_refund(&self, refund: i64) {
+        if refund == 0 {
+            return;
+        }
+        let mut value = [0u8; 32];
+        LittleEndian::write_i64(&mut value, self.refund_counter() + refund);
+        LowLevelSDK::jzkt_tstore(REFUND_COUNTER_KEY.as_ptr(), value.as_ptr());
+    }
+
+    fn refund_counter(&self) -> i64 {
+        let mut value = [0u8; 32];
+        LowLevelSDK::jzkt_tload(REFUND_COUNTER_KEY.as_ptr(), value.as_mut_ptr());
+        LittleEndian::read_i64(&value)
+    }
+
+    #[inline(always)]
+    fn fuel_conversion(&self) -> FuelConversion {
+        let mut fuel_conversion = [0u8; 9];
+        LowLevelSDK::sys_fuel_conversion(fuel_conversion.as_mut_ptr());
+        FuelConversion::from_bytes(&fuel_conversion)
+    }
+
+    fn analysed_bytecode(&self, source_code_hash: &B256) -> Option<Bytecode> {
+        let len = self.preimage_size(&source_code_hash.0) as usize;
+        if len == 0 {
+            return None;
+        }
+        // bytecode must be in the contract's memory to be executed, so it's copied once right
+        // into the buffer padded like `Bytecode::to_checked` does it
+        let mut bytecode = vec![0u8; len + 33];
+        LowLevelSDK::jzkt_preimage_copy(source_code_hash.as_ptr(), bytecode.as_mut_ptr());
+        // jump table is analysed and cached by the host, so we don't re-analyse hot contracts
+        let mut jump_table = vec![0u8; (len + 7) / 8];
+        LowLevelSDK::evm_jump_table(
+            source_code_hash.as_ptr(),
+            jump_table.as_mut_ptr(),
+            jump_table.len() as u32,
+        );
+        Some(Bytecode {
+            bytecode: bytecode.into(),
+            state: BytecodeState::Analysed {
+                len,
+                jump_map: JumpMap::from_slice(&jump_table),
+            },
+        })
+    }
+}
+
+/// Account is created in the current transaction
+const ACCOUNT_FLAG_CREATED: u8 = 0x01;
+/// Account is self-destructed in the current transaction
+const ACCOUNT_FLAG_DESTROYED: u8 = 0x02;
+/// Account is accessed in the current transaction (EIP-2929)
+const ACCOUNT_FLAG_WARM: u8 = 0x04;
+
+/// Storage keys written by the accounts created in the current transaction are linked in the
+/// transient storage of this account, so the storage can be wiped when the account is destroyed
+const CREATED_SLOTS_ADDRESS: Address = address!("0000000000000000000000000000000000006780");
+
+/// Warm storage slots are tracked in the transient storage of this account, it has no code, so
+/// nobody can overwrite them with `TSTORE`
+const WARM_SLOTS_ADDRESS: Address = address!("0000000000000000000000000000000000002929");
+
+/// Transient storage key of the flag that the transaction access list is already loaded, it's
+/// never an account or a storage key, because the first ones are padded and the last ones are
+/// hashed
+const ACCESS_LIST_LOADED_KEY: [u8; 32] = [0xff; 32];
+
+/// Transient storage key of the transaction gas refund counter, it can't collide with account or
+/// storage keys for the same reason as [ACCESS_LIST_LOADED_KEY]
+const REFUND_COUNTER_KEY: [u8; 32] = [0xfe; 32];
+
+impl JzktAccountManager {
+    /// Marks account as accessed and returns `true` if it's the first access in the transaction
+    /// (EIP-2929). Precompiles, the transaction sender, coinbase, the current contract with its
+    /// caller and accounts from the transaction access list are always warm.
+    pub fn access_account(&self, address: Address) -> bool {
+        self.warm_access_list();
+        let flags = self.account_flags(address);
+        if flags & ACCOUNT_FLAG_WARM != 0 {
+            return false;
+        }
+        self.write_account_flags(address, flags | ACCOUNT_FLAG_WARM);
+        let ctx = ExecutionContext::DEFAULT;
+        let is_warm = PRECOMPILE_ADDRESSES.contains(&address)
+            || address == ctx.tx_caller()
+            || address == ctx.block_coinbase()
+            || address == ctx.contract_address()
+            || address == ctx.contract_caller();
+        !is_warm
     }
 
-    fn is_precompile(&self, _address: &Address) -> bool {
-        // in jzkt mode we don't support precompiles
-        false
+    /// Marks storage slot as accessed and returns `true` if it's the first access in the
+    /// transaction, slots from the transaction access list are always warm.
+    pub fn access_storage(&self, address: Address, slot: U256) -> bool {
+        let storage_key = calc_storage_key(&address, slot.as_le_slice().as_ptr());
+        self.access_storage_key(&storage_key)
+    }
+
+    fn access_storage_key(&self, storage_key: &[u8; 32]) -> bool {
+        self.warm_access_list();
+        self.mark_storage_key_warm(storage_key)
+    }
+
+    /// Marks storage key as warm and returns `true` if it was cold.
+    fn mark_storage_key_warm(&self, storage_key: &[u8; 32]) -> bool {
+        let warm_key = calc_storage_key(&WARM_SLOTS_ADDRESS, storage_key.as_ptr());
+        let mut value = [0u8; 32];
+        LowLevelSDK::jzkt_tload(warm_key.as_ptr(), value.as_mut_ptr());
+        if value[0] != 0 {
+            return false;
+        }
+        value[0] = 1;
+        LowLevelSDK::jzkt_tstore(warm_key.as_ptr(), value.as_ptr());
+        true
+    }
+
+    /// Marks accounts and slots from the transaction access list as warm. The access list is
+    /// decoded only once per transaction, after that the warm flags are just read from the
+    /// transient storage. The loaded flag is reverted together with the warm flags, so it's safe
+    /// to load the access list inside the call that is rolled back later.
+    fn warm_access_list(&self) {
+        let mut value = [0u8; 32];
+        LowLevelSDK::jzkt_tload(ACCESS_LIST_LOADED_KEY.as_ptr(), value.as_mut_ptr());
+        if value[0] != 0 {
+            return;
+        }
+        value[0] = 1;
+        LowLevelSDK::jzkt_tstore(ACCESS_LIST_LOADED_KEY.as_ptr(), value.as_ptr());
+        for (address, slots) in ExecutionContext::DEFAULT.tx_access_list() {
+            let flags = self.account_flags(address);
+            self.write_account_flags(address, flags | ACCOUNT_FLAG_WARM);
+            for slot in slots {
+                let storage_key = calc_storage_key(&address, slot.as_le_slice().as_ptr());
+                self.mark_storage_key_warm(&storage_key);
+            }
+        }
+    }
+
+    /// Links the storage key into the list of the keys written by the created account. The head
+    /// of the list is stored under the padded address key and the list ends with the padded
+    /// address, it's never a storage key, because those are hashed.
+    fn track_created_storage_key(&self, address: Address, storage_key: &[u8; 32]) {
+        let link_key = calc_storage_key(&CREATED_SLOTS_ADDRESS, storage_key.as_ptr());
+        let mut next_key = [0u8; 32];
+        LowLevelSDK::jzkt_tload(link_key.as_ptr(), next_key.as_mut_ptr());
+        if next_key != [0u8; 32] {
+            return;
+        }
+        let head_key = calc_storage_key(&CREATED_SLOTS_ADDRESS, address.into_word().as_ptr());
+        LowLevelSDK::jzkt_tload(head_key.as_ptr(), next_key.as_mut_ptr());
+        if next_key == [0u8; 32] {
+            next_key = address.into_word().0;
+        }
+        LowLevelSDK::jzkt_tstore(link_key.as_ptr(), next_key.as_ptr());
+        LowLevelSDK::jzkt_tstore(head_key.as_ptr(), storage_key.as_ptr());
+    }
+
+    /// Removes all the storage slots written by the created account.
+    fn wipe_created_storage(&self, address: Address) {
+        let list_end = address.into_word().0;
+        let head_key = calc_storage_key(&CREATED_SLOTS_ADDRESS, list_end.as_ptr());
+        let mut storage_key = [0u8; 32];
+        LowLevelSDK::jzkt_tload(head_key.as_ptr(), storage_key.as_mut_ptr());
+        LowLevelSDK::jzkt_tstore(head_key.as_ptr(), [0u8; 32].as_ptr());
+        while storage_key != [0u8; 32] && storage_key != list_end {
+            LowLevelSDK::jzkt_update(
+                storage_key.as_ptr(),
+                JZKT_STORAGE_COMPRESSION_FLAGS,
+                &[0u8; 32] as *const [u8; 32],
+                32,
+            );
+            let link_key = calc_storage_key(&CREATED_SLOTS_ADDRESS, storage_key.as_ptr());
+            LowLevelSDK::jzkt_tload(link_key.as_ptr(), storage_key.as_mut_ptr());
+            LowLevelSDK::jzkt_tstore(link_key.as_ptr(), [0u8; 32].as_ptr());
+        }
+    }
+
+    /// Reads rWASM code size of the account without marking it as accessed.
+    fn rwasm_code_size(&self, address: &Address) -> u64 {
+        let mut buffer32 = Bytes32::default();
+        LowLevelSDK::jzkt_get(
+            address.into_word().as_ptr(),
+            JZKT_ACCOUNT_RWASM_CODE_SIZE_FIELD,
+            buffer32.as_mut_ptr(),
+            false,
+        );
+        LittleEndian::read_u64(&buffer32)
+    }
+
+    /// Account flags live in the transient storage under the padded address key, it never
+    /// collides with storage slots, because those are hashed.
+    fn account_flags(&self, address: Address) -> u8 {
+        let mut value = [0u8; 32];
+        LowLevelSDK::jzkt_tload(address.into_word().as_ptr(), value.as_mut_ptr());
+        value[0]
+    }
+
+    fn write_account_flags(&self, address: Address, flags: u8) {
+        let mut value = [0u8; 32];
+        value[0] = flags;
+        LowLevelSDK::jzkt_tstore(address.into_word().as_ptr(), value.as_ptr());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AccountManager, ContractInput, JzktAccountManager, LowLevelSDK};
+    use fluentbase_codec::Encoder;
+    use fluentbase_runtime::CachingRuntime;
+    use fluentbase_types::{
+        address,
+        Address,
+        Bytes,
+        FuelConversion,
+        FuelRounding,
+        B256,
+        PRECOMPILE_SHA256_ADDRESS,
+        U256,
+    };
+    use revm_primitives::{Bytecode, BytecodeState};
+
+    #[test]
+    fn test_access_list_tracking() {
+        let warm_address = address!("1000000000000000000000000000000000000001");
+        let contract_input = ContractInput {
+            contract_address: address!("1000000000000000000000000000000000000002"),
+            tx_access_list: vec![(warm_address, vec![U256::from(7)])],
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        let am = JzktAccountManager;
+        // only the first access of the account is cold
+        let cold_address = address!("1000000000000000000000000000000000000003");
+        assert!(am.access_account(cold_address));
+        assert!(!am.access_account(cold_address));
+        assert!(!am.account(cold_address).1);
+        // accounts from the access list and precompiles are warm from the beginning
+        assert!(!am.access_account(warm_address));
+        assert!(!am.access_account(contract_input.contract_address));
+        assert!(!am.access_account(PRECOMPILE_SHA256_ADDRESS));
+        // the same for storage slots, but reading the committed value isn't an access
+        assert!(!am.storage(warm_address, U256::from(7), false).1);
+        assert!(!am.storage(warm_address, U256::from(8), true).1);
+        assert!(am.storage(warm_address, U256::from(8), false).1);
+        assert!(!am.access_storage(warm_address, U256::from(8)));
+        // accesses are reverted together with the call
+        let checkpoint = am.checkpoint();
+        let reverted_address = address!("1000000000000000000000000000000000000004");
+        assert!(am.access_account(reverted_address));
+        assert!(am.access_storage(reverted_address, U256::from(1)));
+        am.rollback(checkpoint);
+        assert!(am.access_account(reverted_address));
+        assert!(am.access_storage(reverted_address, U256::from(1)));
     }
 
-    fn self_destruct(&self, _address: Address, _target: Address) -> [bool; 4] {
-        todo!("how we can support SELFDESTRUCT (?)")
+    #[test]
+    fn test_access_list_loaded_once() {
+        let warm_address = address!("1000000000000000000000000000000000000001");
+        let contract_input = ContractInput {
+            tx_access_list: vec![(warm_address, vec![U256::from(7)])],
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        let am = JzktAccountManager;
+        // loading of the access list inside the reverted call is reverted too
+        let checkpoint = am.checkpoint();
+        assert!(!am.access_account(warm_address));
+        am.rollback(checkpoint);
+        assert!(!am.access_storage(warm_address, U256::from(7)));
+        // the access list isn't decoded again, so the changed input doesn't affect warm accounts
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        assert!(!am.access_account(warm_address));
+        assert!(am.access_storage(warm_address, U256::from(8)));
+        assert!(am.access_account(address!("1000000000000000000000000000000000000002")));
     }
 
-    fn block_hash(&self, _number: U256) -> B256 {
-        todo!("how we can support BLOCKHASH (?)")
+    #[test]
+    fn test_refund_counter() {
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        let am = JzktAccountManager;
+        let refund_counter = am.refund_counter();
+        am.record_refund(4800);
+        am.record_refund(-2800);
+        assert_eq!(am.refund_counter(), refund_counter + 2000);
+        // refunds of the reverted call are reverted too
+        let checkpoint = am.checkpoint();
+        am.record_refund(19900);
+        am.rollback(checkpoint);
+        assert_eq!(am.refund_counter(), refund_counter + 2000);
     }
 
-    fn write_transient_storage(&self, _address: Address, _index: U256, _value: U256) {
-        todo!("how we can support TLOAD (?)")
+    fn create_account(am: &JzktAccountManager, address: Address, balance: u64) {
+        let (mut account, _) = am.account(address);
+        account.nonce = 1;
+        account.balance = U256::from(balance);
+        am.write_account(&account);
+        am.write_storage(address, U256::from(1), U256::from(100));
     }
 
-    fn transient_storage(&self, _address: Address, _index: U256) -> U256 {
-        todo!("how we can support TSTORE (?)")
+    #[test]
+    fn test_self_destruct_created_account() {
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        let am = JzktAccountManager;
+        let address = address!("1000000000000000000000000000000000000001");
+        let target = address!("1000000000000000000000000000000000000002");
+        am.mark_account_created(address);
+        create_account(&am, address, 10);
+        let [had_value, target_exists, _, previously_destroyed] = am.self_destruct(address, target);
+        assert!(had_value && !target_exists && !previously_destroyed);
+        // created account is removed together with its storage (EIP-6780)
+        let (account, _) = am.account(address);
+        assert_eq!(account.nonce, 0);
+        assert_eq!(account.balance, U256::ZERO);
+        assert_eq!(am.storage(address, U256::from(1), false).0, U256::ZERO);
+        assert_eq!(am.account(target).0.balance, U256::from(10));
+        let [.., previously_destroyed] = am.self_destruct(address, target);
+        assert!(previously_destroyed);
+        // redeployed account starts with the empty storage
+        am.mark_account_created(address);
+        create_account(&am, address, 0);
+        am.self_destruct(address, address);
+        assert_eq!(am.storage(address, U256::from(1), false).0, U256::ZERO);
     }
 
-    fn mark_account_created(&self, _address: Address) {}
+    #[test]
+    fn test_self_destruct_existing_account() {
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        let am = JzktAccountManager;
+        let address = address!("1000000000000000000000000000000000000001");
+        let target = address!("1000000000000000000000000000000000000002");
+        create_account(&am, address, 10);
+        am.self_destruct(address, target);
+        // since Cancun only the balance is transferred for the existing accounts
+        let (account, _) = am.account(address);
+        assert_eq!(account.nonce, 1);
+        assert_eq!(account.balance, U256::ZERO);
+        assert_eq!(am.storage(address, U256::from(1), false).0, U256::from(100));
+        assert_eq!(am.account(target).0.balance, U256::from(10));
+    }
+
+    #[test]
+    fn test_block_hash() {
+        let contract_input = ContractInput {
+            block_number: 300,
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        let jzkt = LowLevelSDK::with_default_jzkt();
+        for number in [43, 44, 299, 300] {
+            jzkt.update_block_hash(number, &B256::with_last_byte(number as u8));
+        }
+        let am = JzktAccountManager;
+        // only hashes of the last 256 blocks are available
+        assert_eq!(
+            am.block_hash(U256::from(299)),
+            B256::with_last_byte(299u64 as u8)
+        );
+        assert_eq!(am.block_hash(U256::from(44)), B256::with_last_byte(44));
+        assert_eq!(am.block_hash(U256::from(43)), B256::ZERO);
+        assert_eq!(am.block_hash(U256::from(300)), B256::ZERO);
+        assert_eq!(am.block_hash(U256::from(301)), B256::ZERO);
+        assert_eq!(am.block_hash(U256::MAX), B256::ZERO);
+    }
+
+    #[test]
+    fn test_precompile_without_code() {
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        let am = JzktAccountManager;
+        // precompile that isn't deployed is an ordinary account, but it's still warm
+        assert!(!am.is_precompile(&PRECOMPILE_SHA256_ADDRESS));
+        assert!(am
+            .precompile(&PRECOMPILE_SHA256_ADDRESS, &Bytes::new(), 100)
+            .is_none());
+        assert!(!am.access_account(PRECOMPILE_SHA256_ADDRESS));
+        let (mut account, _) = am.account(PRECOMPILE_SHA256_ADDRESS);
+        account.rwasm_code_size = 1;
+        am.write_account(&account);
+        assert!(am.is_precompile(&PRECOMPILE_SHA256_ADDRESS));
+        // only precompile addresses are routed to their code
+        let address = address!("1000000000000000000000000000000000000001");
+        let (mut account, _) = am.account(address);
+        account.rwasm_code_size = 1;
+        am.write_account(&account);
+        assert!(!am.is_precompile(&address));
+    }
+
+    #[test]
+    fn test_fuel_conversion() {
+        let am = JzktAccountManager;
+        assert_eq!(am.fuel_conversion(), FuelConversion::DEFAULT);
+        // the policy is taken from the runtime context, so it matches the host's one
+        let fuel_conversion = FuelConversion::new(3, FuelRounding::Down);
+        LowLevelSDK::with_test_fuel_conversion(fuel_conversion);
+        assert_eq!(am.fuel_conversion(), fuel_conversion);
+        assert_eq!(am.fuel_conversion().gas_to_fuel(10), 30);
+        LowLevelSDK::with_test_fuel_conversion(FuelConversion::DEFAULT);
+    }
+
+    #[test]
+    fn test_analysed_bytecode_cache() {
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        let am = JzktAccountManager;
+        let address = address!("1000000000000000000000000000000000000001");
+        let (mut account, _) = am.account(address);
+        // PUSH1 0x5b JUMPDEST PUSH2 0x5b5b JUMPDEST
+        let bytecode = Bytes::from_static(&[0x60, 0x5b, 0x5b, 0x61, 0x5b, 0x5b, 0x5b]);
+        account.update_bytecode(&am, &bytecode, None, &Bytes::new(), None);
+        let hits = || CachingRuntime::with_shared(|cache| cache.metrics().jump_table_hits);
+        let analysed = am.analysed_bytecode(&account.source_code_hash).unwrap();
+        let BytecodeState::Analysed { len, jump_map } = analysed.state else {
+            panic!("bytecode is not analysed");
+        };
+        assert_eq!(len, bytecode.len());
+        assert_eq!(&analysed.bytecode[..len], &bytecode[..]);
+        // padding is the same as for the bytecode analysed by revm
+        assert_eq!(
+            analysed.bytecode,
+            Bytecode::new_raw(bytecode.clone()).to_checked().bytecode
+        );
+        // JUMPDEST bytes inside PUSH data aren't valid jump destinations
+        let valid = (0..len)
+            .filter(|pc| jump_map.is_valid(*pc))
+            .collect::<Vec<_>>();
+        assert_eq!(valid, vec![2, 6]);
+        // the second call reuses the jump table cached by the host
+        let hits_before = hits();
+        let analysed_again = am.analysed_bytecode(&account.source_code_hash).unwrap();
+        assert!(hits() > hits_before);
+        assert_eq!(analysed_again.bytecode, analysed.bytecode);
+        // unknown code isn't analysed
+        assert!(am.analysed_bytecode(&B256::with_last_byte(1)).is_none());
+    }
 }
@@ -7,6 +7,9 @@ use fluentbase_runtime::{
         crypto_poseidon::CryptoPoseidon,
         crypto_poseidon2::CryptoPoseidon2,
         debug_log::DebugLog,
+        evm_jump_table::EvmJumpTable,
+        evm_to_rwasm::EvmToRwasm,
+        evm_to_rwasm_size::EvmToRwasmSize,
         jzkt_checkpoint::JzktCheckpoint,
         jzkt_commit::JzktCommit,
         jzkt_compute_root::JzktComputeRoot,
@@ -17,11 +20,14 @@ use fluentbase_runtime::{
         jzkt_preimage_size::JzktPreimageSize,
         jzkt_remove::JzktRemove,
         jzkt_rollback::JzktRollback,
+        jzkt_tload::JzktTload,
+        jzkt_tstore::JzktTstore,
         jzkt_update::JzktUpdate,
         jzkt_update_preimage::JzktUpdatePreimage,
         sys_exec_hash::SysExecHash,
         sys_forward_output::SysForwardOutput,
         sys_fuel::SysFuel,
+        sys_fuel_conversion::SysFuelConversion,
         sys_halt::SysHalt,
         sys_input_size::SysInputSize,
         sys_output_size::SysOutputSize,
@@ -37,7 +43,7 @@ use fluentbase_runtime::{
     DefaultEmptyRuntimeDatabase,
     RuntimeContext,
 };
-use fluentbase_types::{Address, Bytes, ExitCode, JournalCheckpoint, B256};
+use fluentbase_types::{Address, Bytes, ExitCode, FuelConversion, JournalCheckpoint, B256};
 use std::ptr;
 
 type Context = RuntimeContext<DefaultEmptyRuntimeDatabase>;
@@ -161,7 +167,7 @@ impl LowLevelAPI for LowLevelSDK {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *mut u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32 {
         let bytecode_hash32 = unsafe { &*ptr::slice_from_raw_parts(bytecode_hash32_offset, 32) };
@@ -169,23 +175,17 @@ impl LowLevelAPI for LowLevelSDK {
             unsafe { &*ptr::slice_from_raw_parts(input_offset, input_len as usize) }.to_vec();
         let fuel = unsafe { *fuel_offset };
         with_context_mut(move |ctx| {
-            match SysExecHash::fn_impl(
-                ctx,
-                bytecode_hash32.try_into().unwrap(),
-                input.clone(),
-                return_len,
-                fuel as u64,
-                state,
-            ) {
+            let result =
+                SysExecHash::fn_impl(ctx, bytecode_hash32.try_into().unwrap(), input, fuel, state);
+            if return_len > 0 {
+                let return_data = ctx.return_data();
+                let len = return_data.len().min(return_len as usize);
+                unsafe { ptr::copy(return_data.as_ptr(), return_offset, len) }
+            }
+            match result {
                 Ok(remaining_fuel) => {
-                    if return_len > 0 {
-                        let return_data = ctx.return_data();
-                        unsafe {
-                            ptr::copy(return_data.as_ptr(), return_offset, return_len as usize)
-                        }
-                    }
                     unsafe {
-                        *fuel_offset = remaining_fuel as u32;
+                        *fuel_offset = remaining_fuel;
                     }
                     0
                 }
@@ -198,6 +198,11 @@ impl LowLevelAPI for LowLevelSDK {
         with_context_mut(|ctx| SysFuel::fn_impl(ctx, delta))
     }
 
+    fn sys_fuel_conversion(output9_offset: *mut u8) {
+        let fuel_conversion = with_context(|ctx| SysFuelConversion::fn_impl(ctx));
+        unsafe { ptr::copy(fuel_conversion.to_bytes().as_ptr(), output9_offset, 9) }
+    }
+
     fn jzkt_open(root32_ptr: *const u8) {
         let root = unsafe { &*ptr::slice_from_raw_parts(root32_ptr, 32) };
         with_context_mut(|ctx| JzktOpen::fn_impl(ctx, root).unwrap());
@@ -286,6 +291,16 @@ impl LowLevelAPI for LowLevelSDK {
             unsafe { &mut *ptr::slice_from_raw_parts_mut(preimage_ptr, preimage_copy.len()) };
         dest.copy_from_slice(&preimage_copy);
     }
+    fn jzkt_tload(key32_ptr: *const u8, output32_ptr: *mut u8) {
+        let key = unsafe { &*ptr::slice_from_raw_parts(key32_ptr, 32) };
+        let value = with_context_mut(|ctx| JzktTload::fn_impl(ctx, key).unwrap());
+        unsafe { ptr::copy(value.as_ptr(), output32_ptr, 32) }
+    }
+    fn jzkt_tstore(key32_ptr: *const u8, value32_ptr: *const u8) {
+        let key = unsafe { &*ptr::slice_from_raw_parts(key32_ptr, 32) };
+        let value = unsafe { &*ptr::slice_from_raw_parts(value32_ptr, 32) };
+        with_context_mut(|ctx| JzktTstore::fn_impl(ctx, key, value).unwrap());
+    }
     fn wasm_to_rwasm_size(input_ptr: *const u8, input_len: u32) -> i32 {
         let wasm_binary = unsafe { &*ptr::slice_from_raw_parts(input_ptr, input_len as usize) };
         with_context_mut(|ctx| WasmToRwasmSize::fn_impl(ctx, wasm_binary).map_err(|v| v.into_i32()))
@@ -312,6 +327,40 @@ impl LowLevelAPI for LowLevelSDK {
         }
         ExitCode::Ok.into_i32()
     }
+    fn evm_to_rwasm_size(input_ptr: *const u8, input_len: u32) -> i32 {
+        let evm_bytecode = unsafe { &*ptr::slice_from_raw_parts(input_ptr, input_len as usize) };
+        with_context_mut(|ctx| EvmToRwasmSize::fn_impl(ctx, evm_bytecode))
+            .unwrap_or_else(|err| err.into_i32())
+    }
+    fn evm_to_rwasm(
+        input_ptr: *const u8,
+        input_len: u32,
+        output_ptr: *mut u8,
+        output_len: u32,
+    ) -> i32 {
+        let evm_bytecode = unsafe { &*ptr::slice_from_raw_parts(input_ptr, input_len as usize) };
+        let rwasm_binary =
+            with_context_mut(|ctx| EvmToRwasm::fn_impl(ctx, evm_bytecode, output_len));
+        let rwasm_binary = match rwasm_binary {
+            Ok(rwasm_binary) => rwasm_binary,
+            Err(err) => return err.into_i32(),
+        };
+        if output_len > 0 {
+            let dest =
+                unsafe { &mut *ptr::slice_from_raw_parts_mut(output_ptr, output_len as usize) };
+            dest.copy_from_slice(&rwasm_binary[..output_len as usize]);
+        }
+        ExitCode::Ok.into_i32()
+    }
+    fn evm_jump_table(hash32_ptr: *const u8, output_ptr: *mut u8, output_len: u32) -> i32 {
+        let hash: [u8; 32] = unsafe { &*ptr::slice_from_raw_parts(hash32_ptr, 32) }
+            .try_into()
+            .unwrap();
+        let (_, jump_table) = with_context_mut(|ctx| EvmJumpTable::fn_impl(ctx, &hash));
+        let len = jump_table.len().min(output_len as usize);
+        unsafe { ptr::copy(jump_table.as_ptr(), output_ptr, len) }
+        jump_table.len() as i32
+    }
     fn debug_log(msg_ptr: *const u8, msg_len: u32) {
         let msg = unsafe { &*ptr::slice_from_raw_parts(msg_ptr, msg_len as usize) };
         DebugLog::fn_impl(msg)
@@ -325,6 +374,12 @@ impl LowLevelSDK {
         });
     }
 
+    pub fn with_test_fuel_conversion(fuel_conversion: FuelConversion) {
+        with_context_mut(|ctx| {
+            ctx.change_fuel_conversion(fuel_conversion);
+        });
+    }
+
     pub fn get_test_output() -> Vec<u8> {
         with_context_mut(|ctx| {
             let output = ctx.output().clone();
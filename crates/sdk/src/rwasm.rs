@@ -5,6 +5,9 @@ use crate::{
         _crypto_poseidon,
         _crypto_poseidon2,
         _debug_log,
+        _evm_jump_table,
+        _evm_to_rwasm,
+        _evm_to_rwasm_size,
         _jzkt_checkpoint,
         _jzkt_commit,
         _jzkt_compute_root,
@@ -15,11 +18,14 @@ use crate::{
         _jzkt_preimage_size,
         _jzkt_remove,
         _jzkt_rollback,
+        _jzkt_tload,
+        _jzkt_tstore,
         _jzkt_update,
         _jzkt_update_preimage,
-        _sys_exec_hash,
+        _sys_exec_hash64,
         _sys_forward_output,
         _sys_fuel,
+        _sys_fuel_conversion,
         _sys_halt,
         _sys_input_size,
         _sys_output_size,
@@ -82,11 +88,11 @@ impl LowLevelAPI for LowLevelSDK {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *mut u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32 {
         unsafe {
-            _sys_exec_hash(
+            _sys_exec_hash64(
                 code_hash32_offset,
                 input_offset,
                 input_len,
@@ -103,6 +109,11 @@ impl LowLevelAPI for LowLevelSDK {
         unsafe { _sys_fuel(delta) }
     }
 
+    #[inline(always)]
+    fn sys_fuel_conversion(output9_offset: *mut u8) {
+        unsafe { _sys_fuel_conversion(output9_offset) }
+    }
+
     #[inline(always)]
     fn crypto_keccak256(data_offset: *const u8, data_len: u32, output32_offset: *mut u8) {
         unsafe { _crypto_keccak256(data_offset, data_len, output32_offset) }
@@ -208,6 +219,14 @@ impl LowLevelAPI for LowLevelSDK {
         unsafe { _jzkt_preimage_copy(hash32_ptr, preimage_ptr) }
     }
     #[inline(always)]
+    fn jzkt_tload(key32_ptr: *const u8, output32_ptr: *mut u8) {
+        unsafe { _jzkt_tload(key32_ptr, output32_ptr) }
+    }
+    #[inline(always)]
+    fn jzkt_tstore(key32_ptr: *const u8, value32_ptr: *const u8) {
+        unsafe { _jzkt_tstore(key32_ptr, value32_ptr) }
+    }
+    #[inline(always)]
     fn wasm_to_rwasm_size(input_ptr: *const u8, input_len: u32) -> i32 {
         unsafe { _wasm_to_rwasm_size(input_ptr, input_len) }
     }
@@ -221,6 +240,23 @@ impl LowLevelAPI for LowLevelSDK {
         unsafe { _wasm_to_rwasm(input_ptr, input_len, output_ptr, output_len) }
     }
     #[inline(always)]
+    fn evm_to_rwasm_size(input_ptr: *const u8, input_len: u32) -> i32 {
+        unsafe { _evm_to_rwasm_size(input_ptr, input_len) }
+    }
+    #[inline(always)]
+    fn evm_to_rwasm(
+        input_ptr: *const u8,
+        input_len: u32,
+        output_ptr: *mut u8,
+        output_len: u32,
+    ) -> i32 {
+        unsafe { _evm_to_rwasm(input_ptr, input_len, output_ptr, output_len) }
+    }
+    #[inline(always)]
+    fn evm_jump_table(hash32_ptr: *const u8, output_ptr: *mut u8, output_len: u32) -> i32 {
+        unsafe { _evm_jump_table(hash32_ptr, output_ptr, output_len) }
+    }
+    #[inline(always)]
     fn debug_log(msg_ptr: *const u8, msg_len: u32) {
         unsafe { _debug_log(msg_ptr, msg_len) }
     }
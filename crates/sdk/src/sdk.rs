@@ -23,6 +23,7 @@ pub trait LowLevelAPI {
     fn sys_read_output(target: *mut u8, offset: u32, length: u32);
     fn sys_state() -> u32;
     fn sys_fuel(delta: u64) -> u64;
+    fn sys_fuel_conversion(output9_offset: *mut u8);
 
     fn sys_exec_hash(
         code_hash32_offset: *const u8,
@@ -30,7 +31,7 @@ pub trait LowLevelAPI {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *mut u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32;
 
@@ -62,6 +63,8 @@ pub trait LowLevelAPI {
     fn jzkt_rollback(checkpoint: u64);
     fn jzkt_preimage_size(hash32_ptr: *const u8) -> u32;
     fn jzkt_preimage_copy(hash32_ptr: *const u8, preimage_ptr: *mut u8);
+    fn jzkt_tload(key32_ptr: *const u8, output32_ptr: *mut u8);
+    fn jzkt_tstore(key32_ptr: *const u8, value32_ptr: *const u8);
     fn wasm_to_rwasm_size(input_ptr: *const u8, input_len: u32) -> i32;
     fn wasm_to_rwasm(
         input_ptr: *const u8,
@@ -69,5 +72,13 @@ pub trait LowLevelAPI {
         output_ptr: *mut u8,
         output_len: u32,
     ) -> i32;
+    fn evm_to_rwasm_size(input_ptr: *const u8, input_len: u32) -> i32;
+    fn evm_to_rwasm(
+        input_ptr: *const u8,
+        input_len: u32,
+        output_ptr: *mut u8,
+        output_len: u32,
+    ) -> i32;
+    fn evm_jump_table(hash32_ptr: *const u8, output_ptr: *mut u8, output_len: u32) -> i32;
     fn debug_log(msg_ptr: *const u8, msg_len: u32);
 }
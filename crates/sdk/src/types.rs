@@ -79,6 +79,18 @@ pub struct EvmCallMethodInput {
     pub input: Bytes,
     pub gas_limit: u64,
     pub depth: u32,
+    /// Scheme of the call, it's reported to inspectors only since the call context (address,
+    /// caller and value) is managed by context reader.
+    pub scheme: EvmCallScheme,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Codec)]
+pub enum EvmCallScheme {
+    #[default]
+    Call,
+    CallCode,
+    DelegateCall,
+    StaticCall,
 }
 
 #[derive(Default, Debug, Clone, Codec)]
@@ -122,3 +134,19 @@ pub const WASM_CALL_METHOD_ID: u32 =
 
 pub type WasmCallMethodInput = EvmCallMethodInput;
 pub type WasmCallMethodOutput = EvmCallMethodOutput;
+
+/// Snapshot of the EVM interpreter state for one executed opcode, used for
+/// [EIP-3155](https://eips.ethereum.org/EIPS/eip-3155) step tracing. Stack, memory size and
+/// gas are taken before the opcode is executed, `gas_cost` is the gas charged by the opcode.
+/// `depth` is 1 for the top-level frame like in revm's journal.
+#[derive(Default, Debug, Clone)]
+pub struct EvmStepTrace {
+    pub pc: usize,
+    pub opcode: u8,
+    pub gas_remaining: u64,
+    pub gas_cost: u64,
+    pub gas_refund: i64,
+    pub stack: Vec<U256>,
+    pub memory_size: usize,
+    pub depth: u32,
+}
@@ -0,0 +1,199 @@
+use crate::SysFuncIdx;
+
+/// Fuel cost of the host function call, it consists of a base cost charged for every call and
+/// a cost for every 32-byte word of the input processed by the function.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HostFuelCost {
+    pub base: u64,
+    pub word: u64,
+}
+
+impl HostFuelCost {
+    pub const fn new(base: u64, word: u64) -> Self {
+        Self { base, word }
+    }
+
+    pub const fn fixed(base: u64) -> Self {
+        Self { base, word: 0 }
+    }
+
+    /// Returns fuel required to process input of `len` bytes, `None` on overflow.
+    pub fn cost(&self, len: u64) -> Option<u64> {
+        let words = len.checked_add(31)? / 32;
+        self.base.checked_add(self.word.checked_mul(words)?)
+    }
+}
+
+/// Fuel schedule for host functions. Costs are derived from the execution and proving time of
+/// the corresponding functions, so expensive calls (like hashing of large inputs or wasm
+/// translation) can't be used to DoS the network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuelSchedule {
+    pub crypto_keccak256: HostFuelCost,
+    pub crypto_poseidon: HostFuelCost,
+    pub crypto_poseidon2: HostFuelCost,
+    pub crypto_ecrecover: HostFuelCost,
+    pub jzkt_get: HostFuelCost,
+    pub jzkt_update: HostFuelCost,
+    pub jzkt_update_preimage: HostFuelCost,
+    pub jzkt_remove: HostFuelCost,
+    pub jzkt_compute_root: HostFuelCost,
+    pub jzkt_emit_log: HostFuelCost,
+    pub jzkt_commit: HostFuelCost,
+    pub jzkt_preimage_copy: HostFuelCost,
+    pub wasm_to_rwasm: HostFuelCost,
+    pub evm_to_rwasm: HostFuelCost,
+    pub evm_jump_table: HostFuelCost,
+}
+
+impl FuelSchedule {
+    pub const DEFAULT: Self = Self {
+        crypto_keccak256: HostFuelCost::new(30, 6),
+        crypto_poseidon: HostFuelCost::new(60, 12),
+        crypto_poseidon2: HostFuelCost::fixed(60),
+        crypto_ecrecover: HostFuelCost::fixed(3000),
+        jzkt_get: HostFuelCost::fixed(100),
+        jzkt_update: HostFuelCost::new(100, 20),
+        jzkt_update_preimage: HostFuelCost::new(100, 6),
+        jzkt_remove: HostFuelCost::fixed(100),
+        jzkt_compute_root: HostFuelCost::fixed(1000),
+        jzkt_emit_log: HostFuelCost::new(375, 256),
+        jzkt_commit: HostFuelCost::fixed(1000),
+        jzkt_preimage_copy: HostFuelCost::new(10, 3),
+        wasm_to_rwasm: HostFuelCost::new(10_000, 200),
+        evm_to_rwasm: HostFuelCost::new(10_000, 200),
+        evm_jump_table: HostFuelCost::new(100, 1),
+    };
+
+    /// Returns fuel cost of the host function by its index. Functions that aren't listed in the
+    /// schedule are charged only by the fuel of rWASM instructions.
+    pub fn host_fuel_cost(&self, func_idx: SysFuncIdx) -> HostFuelCost {
+        match func_idx {
+            SysFuncIdx::CRYPTO_KECCAK256 => self.crypto_keccak256,
+            SysFuncIdx::CRYPTO_POSEIDON => self.crypto_poseidon,
+            SysFuncIdx::CRYPTO_POSEIDON2 => self.crypto_poseidon2,
+            SysFuncIdx::CRYPTO_ECRECOVER => self.crypto_ecrecover,
+            SysFuncIdx::JZKT_GET => self.jzkt_get,
+            SysFuncIdx::JZKT_UPDATE => self.jzkt_update,
+            SysFuncIdx::JZKT_UPDATE_PREIMAGE => self.jzkt_update_preimage,
+            SysFuncIdx::JZKT_REMOVE => self.jzkt_remove,
+            SysFuncIdx::JZKT_COMPUTE_ROOT => self.jzkt_compute_root,
+            SysFuncIdx::JZKT_EMIT_LOG => self.jzkt_emit_log,
+            SysFuncIdx::JZKT_COMMIT => self.jzkt_commit,
+            SysFuncIdx::JZKT_PREIMAGE_COPY => self.jzkt_preimage_copy,
+            SysFuncIdx::WASM_TO_RWASM | SysFuncIdx::WASM_TO_RWASM_SIZE => self.wasm_to_rwasm,
+            SysFuncIdx::EVM_TO_RWASM | SysFuncIdx::EVM_TO_RWASM_SIZE => self.evm_to_rwasm,
+            SysFuncIdx::EVM_JUMP_TABLE => self.evm_jump_table,
+            _ => HostFuelCost::default(),
+        }
+    }
+
+    /// Returns fuel required to call the host function with input of `len` bytes.
+    pub fn fuel_cost(&self, func_idx: SysFuncIdx, len: u64) -> Option<u64> {
+        self.host_fuel_cost(func_idx).cost(len)
+    }
+}
+
+impl Default for FuelSchedule {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Rounding mode for the fuel consumed by rWASM execution that doesn't make a whole unit of gas.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FuelRounding {
+    /// Partially used gas unit is charged, so the execution can't be cheaper than its fuel
+    #[default]
+    Up,
+    /// Partially used gas unit is free
+    Down,
+}
+
+/// Conversion policy between EVM gas and rWASM fuel. It's applied every time gas limit is passed
+/// into the rWASM runtime and fuel consumed by the runtime is charged back as gas, so WASM and EVM
+/// contracts are priced against each other by the same ratio.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FuelConversion {
+    /// Amount of fuel that is given for one unit of gas, it's never zero
+    fuel_per_gas: u64,
+    rounding: FuelRounding,
+}
+
+impl FuelConversion {
+    pub const DEFAULT: Self = Self {
+        fuel_per_gas: 1,
+        rounding: FuelRounding::Up,
+    };
+
+    /// Creates the conversion with `fuel_per_gas` units of fuel for one unit of gas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fuel_per_gas` is zero, because fuel can't be converted back into gas then.
+    pub const fn new(fuel_per_gas: u64, rounding: FuelRounding) -> Self {
+        assert!(fuel_per_gas > 0, "fuel per gas must not be zero");
+        Self {
+            fuel_per_gas,
+            rounding,
+        }
+    }
+
+    pub const fn fuel_per_gas(&self) -> u64 {
+        self.fuel_per_gas
+    }
+
+    pub const fn rounding(&self) -> FuelRounding {
+        self.rounding
+    }
+
+    /// Returns fuel limit for the gas limit, it saturates at `u64::MAX`.
+    pub fn gas_to_fuel(&self, gas: u64) -> u64 {
+        gas.saturating_mul(self.fuel_per_gas)
+    }
+
+    /// Returns gas that must be charged for the consumed fuel.
+    pub fn fuel_to_gas(&self, fuel: u64) -> u64 {
+        let gas = fuel / self.fuel_per_gas;
+        match self.rounding {
+            FuelRounding::Up if fuel % self.fuel_per_gas != 0 => gas + 1,
+            _ => gas,
+        }
+    }
+
+    /// Returns gas left after the execution that was started with `gas_limit` and consumed
+    /// `fuel_consumed` units of fuel.
+    pub fn remaining_gas(&self, gas_limit: u64, fuel_consumed: u64) -> u64 {
+        gas_limit.saturating_sub(self.fuel_to_gas(fuel_consumed))
+    }
+
+    /// Encodes the conversion to pass it into contracts, it's `fuel_per_gas` in little-endian
+    /// followed by the rounding mode.
+    pub fn to_bytes(&self) -> [u8; 9] {
+        let mut bytes = [0u8; 9];
+        bytes[..8].copy_from_slice(&self.fuel_per_gas.to_le_bytes());
+        bytes[8] = match self.rounding {
+            FuelRounding::Up => 0,
+            FuelRounding::Down => 1,
+        };
+        bytes
+    }
+
+    /// Decodes the conversion encoded by `to_bytes`, it panics if `fuel_per_gas` is zero like
+    /// `new` does.
+    pub fn from_bytes(bytes: &[u8; 9]) -> Self {
+        let mut fuel_per_gas = [0u8; 8];
+        fuel_per_gas.copy_from_slice(&bytes[..8]);
+        let rounding = match bytes[8] {
+            0 => FuelRounding::Up,
+            _ => FuelRounding::Down,
+        };
+        Self::new(u64::from_le_bytes(fuel_per_gas), rounding)
+    }
+}
+
+impl Default for FuelConversion {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
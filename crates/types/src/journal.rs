@@ -14,6 +14,12 @@ pub enum JournalEvent {
         key: [u8; 32],
         prev_state: Option<usize>,
     },
+    /// Transient storage (EIP-1153) change, it's never written into the trie
+    TransientChanged {
+        key: [u8; 32],
+        value: [u8; 32],
+        prev_value: Option<[u8; 32]>,
+    },
 }
 
 impl JournalEvent {
@@ -21,6 +27,7 @@ impl JournalEvent {
         match self {
             JournalEvent::ItemChanged { key, .. } => key,
             JournalEvent::ItemRemoved { key, .. } => key,
+            JournalEvent::TransientChanged { key, .. } => key,
         }
     }
 
@@ -28,6 +35,14 @@ impl JournalEvent {
         match self {
             JournalEvent::ItemChanged { .. } => false,
             JournalEvent::ItemRemoved { .. } => true,
+            JournalEvent::TransientChanged { .. } => false,
+        }
+    }
+
+    pub fn is_transient(&self) -> bool {
+        match self {
+            JournalEvent::TransientChanged { .. } => true,
+            _ => false,
         }
     }
 
@@ -39,6 +54,7 @@ impl JournalEvent {
                 ..
             } => Some((value.clone(), *flags)),
             JournalEvent::ItemRemoved { .. } => None,
+            JournalEvent::TransientChanged { .. } => None,
         }
     }
 
@@ -46,6 +62,7 @@ impl JournalEvent {
         match self {
             JournalEvent::ItemChanged { prev_state, .. } => *prev_state,
             JournalEvent::ItemRemoved { prev_state, .. } => *prev_state,
+            JournalEvent::TransientChanged { .. } => None,
         }
     }
 }
@@ -93,7 +110,16 @@ pub trait IJournaledTrie {
     fn get(&self, key: &[u8; 32], committed: bool) -> Option<(Vec<[u8; 32]>, u32, bool)>;
     fn update(&self, key: &[u8; 32], value: &Vec<[u8; 32]>, flags: u32);
     fn remove(&self, key: &[u8; 32]);
+    /// Returns root of the state including uncommitted changes.
+    ///
+    /// Returns zero root if the implementation doesn't keep the trie, like revm's journal, the
+    /// root of such state must be computed once the block is finished.
     fn compute_root(&self) -> [u8; 32];
+    /// Returns merkle proof of the key for the committed state with the specified root.
+    ///
+    /// Returns `None` if the implementation doesn't keep the trie, like revm's journal that only
+    /// tracks state changes of the block.
+    fn proof(&self, root: &[u8; 32], key: &[u8; 32]) -> Option<Vec<Vec<u8>>>;
     fn emit_log(&self, address: Address, topics: Vec<B256>, data: Bytes);
     fn commit(&self) -> Result<([u8; 32], Vec<JournalLog>), ExitCode>;
     fn rollback(&self, checkpoint: JournalCheckpoint);
@@ -101,6 +127,12 @@ pub trait IJournaledTrie {
     fn preimage(&self, hash: &[u8; 32]) -> Vec<u8>;
     fn preimage_size(&self, hash: &[u8; 32]) -> u32;
     fn journal(&self) -> Vec<JournalEvent>;
+    /// Returns value of the transient storage (EIP-1153) key, zero if the key is not set.
+    fn transient(&self, key: &[u8; 32]) -> [u8; 32];
+    /// Updates transient storage, changes are reverted on rollback, but never committed.
+    fn update_transient(&self, key: &[u8; 32], value: &[u8; 32]);
+    /// Clears transient storage, it must be called once the transaction is finished.
+    fn clear_transient(&self);
 }
 
 #[derive(Default, Clone)]
@@ -128,6 +160,10 @@ impl IJournaledTrie for EmptyJournalTrie {
         todo!()
     }
 
+    fn proof(&self, root: &[u8; 32], key: &[u8; 32]) -> Option<Vec<Vec<u8>>> {
+        None
+    }
+
     fn emit_log(&self, address: Address, topics: Vec<B256>, data: Bytes) {
         todo!()
     }
@@ -155,4 +191,12 @@ impl IJournaledTrie for EmptyJournalTrie {
     fn journal(&self) -> Vec<JournalEvent> {
         todo!()
     }
+
+    fn transient(&self, key: &[u8; 32]) -> [u8; 32] {
+        [0u8; 32]
+    }
+
+    fn update_transient(&self, key: &[u8; 32], value: &[u8; 32]) {}
+
+    fn clear_transient(&self) {}
 }
@@ -7,6 +7,8 @@ extern crate core;
 
 mod bytecode;
 pub use bytecode::*;
+mod fuel;
+pub use fuel::*;
 mod journal;
 pub use journal::*;
 mod linker;
@@ -28,5 +30,45 @@ pub const NATIVE_TRANSFER_KECCAK: B256 =
     b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
 pub const NATIVE_TRANSFER_ADDRESS: Address = address!("0000000000000000000000000000000000000000");
 
+// precompiles, addresses match the Ethereum mainnet
+pub const PRECOMPILE_SECP256K1_ADDRESS: Address =
+    address!("0000000000000000000000000000000000000001");
+pub const PRECOMPILE_SHA256_ADDRESS: Address = address!("0000000000000000000000000000000000000002");
+pub const PRECOMPILE_RIPEMD160_ADDRESS: Address =
+    address!("0000000000000000000000000000000000000003");
+pub const PRECOMPILE_IDENTITY_ADDRESS: Address =
+    address!("0000000000000000000000000000000000000004");
+pub const PRECOMPILE_MODEXP_ADDRESS: Address = address!("0000000000000000000000000000000000000005");
+pub const PRECOMPILE_BN128_ADD_ADDRESS: Address =
+    address!("0000000000000000000000000000000000000006");
+pub const PRECOMPILE_BN128_MUL_ADDRESS: Address =
+    address!("0000000000000000000000000000000000000007");
+pub const PRECOMPILE_BN128_PAIR_ADDRESS: Address =
+    address!("0000000000000000000000000000000000000008");
+pub const PRECOMPILE_BLAKE2_ADDRESS: Address = address!("0000000000000000000000000000000000000009");
+pub const PRECOMPILE_KZG_POINT_EVALUATION_ADDRESS: Address =
+    address!("000000000000000000000000000000000000000a");
+
+pub const PRECOMPILE_ADDRESSES: [Address; 10] = [
+    PRECOMPILE_SECP256K1_ADDRESS,
+    PRECOMPILE_SHA256_ADDRESS,
+    PRECOMPILE_RIPEMD160_ADDRESS,
+    PRECOMPILE_IDENTITY_ADDRESS,
+    PRECOMPILE_MODEXP_ADDRESS,
+    PRECOMPILE_BN128_ADD_ADDRESS,
+    PRECOMPILE_BN128_MUL_ADDRESS,
+    PRECOMPILE_BN128_PAIR_ADDRESS,
+    PRECOMPILE_BLAKE2_ADDRESS,
+    PRECOMPILE_KZG_POINT_EVALUATION_ADDRESS,
+];
+
+/// System account storing hashes of the recent blocks (EIP-2935), the hash of the block `n` is
+/// stored in the storage slot `n % BLOCK_HASH_HISTORY_SIZE`
+pub const BLOCK_HASH_HISTORY_ADDRESS: Address =
+    address!("0000f90827f1c53a10cb7a02335b175320002935");
+pub const BLOCK_HASH_HISTORY_SIZE: u64 = 8191;
+/// Number of the recent blocks available with `BLOCKHASH` opcode
+pub const BLOCK_HASH_SERVE_WINDOW: u64 = 256;
+
 pub const STATE_MAIN: u32 = 0;
 pub const STATE_DEPLOY: u32 = 1;
@@ -9,7 +9,7 @@ macro_rules! import_func {
     };
 }
 
-const SHARED_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 22] = [
+const SHARED_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 24] = [
     import_func!("_crypto_keccak256", CRYPTO_KECCAK256),
     import_func!("_crypto_poseidon", CRYPTO_KECCAK256),
     import_func!("_crypto_poseidon2", CRYPTO_POSEIDON2),
@@ -23,7 +23,9 @@ const SHARED_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 22] = [
     import_func!("_sys_forward_output", SYS_FORWARD_OUTPUT),
     import_func!("_sys_state", SYS_STATE),
     import_func!("_sys_exec_hash", SYS_EXEC_HASH),
+    import_func!("_sys_exec_hash64", SYS_EXEC_HASH64),
     import_func!("_sys_fuel", SYS_FUEL),
+    import_func!("_sys_fuel_conversion", SYS_FUEL_CONVERSION),
     // import_func!("_jzkt_open", JZKT_OPEN),
     // import_func!("_jzkt_checkpoint", JZKT_CHECKPOINT),
     import_func!("_jzkt_get", JZKT_GET),
@@ -41,11 +43,11 @@ const SHARED_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 22] = [
     import_func!("_debug_log", DEBUG_LOG),
 ];
 
-pub fn create_shared_import_linker<F: From<[(&'static str, &'static str, u32, u32); 22]>>() -> F {
+pub fn create_shared_import_linker<F: From<[(&'static str, &'static str, u32, u32); 24]>>() -> F {
     F::from(SHARED_IMPORT_LINKER)
 }
 
-const SOVEREIGN_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 29] = [
+const SOVEREIGN_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 36] = [
     import_func!("_crypto_keccak256", CRYPTO_KECCAK256),
     import_func!("_crypto_poseidon", CRYPTO_KECCAK256),
     import_func!("_crypto_poseidon2", CRYPTO_POSEIDON2),
@@ -59,7 +61,9 @@ const SOVEREIGN_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 29] = [
     import_func!("_sys_forward_output", SYS_FORWARD_OUTPUT),
     import_func!("_sys_state", SYS_STATE),
     import_func!("_sys_exec_hash", SYS_EXEC_HASH),
+    import_func!("_sys_exec_hash64", SYS_EXEC_HASH64),
     import_func!("_sys_fuel", SYS_FUEL),
+    import_func!("_sys_fuel_conversion", SYS_FUEL_CONVERSION),
     import_func!("_jzkt_open", JZKT_OPEN),
     import_func!("_jzkt_checkpoint", JZKT_CHECKPOINT),
     import_func!("_jzkt_get", JZKT_GET),
@@ -72,12 +76,17 @@ const SOVEREIGN_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 29] = [
     import_func!("_jzkt_rollback", JZKT_ROLLBACK),
     import_func!("_jzkt_preimage_size", JZKT_PREIMAGE_SIZE),
     import_func!("_jzkt_preimage_copy", JZKT_PREIMAGE_COPY),
+    import_func!("_jzkt_tload", JZKT_TLOAD),
+    import_func!("_jzkt_tstore", JZKT_TSTORE),
     import_func!("_wasm_to_rwasm_size", WASM_TO_RWASM_SIZE),
     import_func!("_wasm_to_rwasm", WASM_TO_RWASM),
+    import_func!("_evm_to_rwasm_size", EVM_TO_RWASM_SIZE),
+    import_func!("_evm_to_rwasm", EVM_TO_RWASM),
+    import_func!("_evm_jump_table", EVM_JUMP_TABLE),
     import_func!("_debug_log", DEBUG_LOG),
 ];
 
-pub fn create_sovereign_import_linker<F: From<[(&'static str, &'static str, u32, u32); 29]>>() -> F
+pub fn create_sovereign_import_linker<F: From<[(&'static str, &'static str, u32, u32); 36]>>() -> F
 {
     F::from(SOVEREIGN_IMPORT_LINKER)
 }
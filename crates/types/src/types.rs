@@ -158,6 +158,8 @@ pub enum SysFuncIdx {
     SYS_EXEC_HASH = 0x0009,
     SYS_FORWARD_OUTPUT = 0x000a,
     SYS_FUEL = 0x000b,
+    SYS_EXEC_HASH64 = 0x000c,
+    SYS_FUEL_CONVERSION = 0x000d,
 
     // jzkt
     JZKT_OPEN = 0x0701,
@@ -172,37 +174,19 @@ pub enum SysFuncIdx {
     JZKT_ROLLBACK = 0x070A,
     JZKT_PREIMAGE_SIZE = 0x070D,
     JZKT_PREIMAGE_COPY = 0x070E,
+    JZKT_TLOAD = 0x070F,
+    JZKT_TSTORE = 0x0710,
 
     // rwasm
     WASM_TO_RWASM_SIZE = 0x0801,
     WASM_TO_RWASM = 0x0802,
+    EVM_TO_RWASM_SIZE = 0x0803,
+    EVM_TO_RWASM = 0x0804,
+    EVM_JUMP_TABLE = 0x0805,
 
     DEBUG_LOG = 0x0901,
 }
 
-impl SysFuncIdx {
-    pub fn fuel_cost(&self) -> u32 {
-        match self {
-            SysFuncIdx::SYS_HALT => 1,
-            SysFuncIdx::SYS_STATE => 1,
-            SysFuncIdx::SYS_READ => 1,
-            SysFuncIdx::SYS_INPUT_SIZE => 1,
-            SysFuncIdx::SYS_WRITE => 1,
-            SysFuncIdx::CRYPTO_KECCAK256 => 1,
-            SysFuncIdx::CRYPTO_POSEIDON => 1,
-            SysFuncIdx::CRYPTO_POSEIDON2 => 1,
-            SysFuncIdx::CRYPTO_ECRECOVER => 1,
-            SysFuncIdx::JZKT_OPEN => 1,
-            SysFuncIdx::JZKT_UPDATE => 1,
-            SysFuncIdx::JZKT_GET => 1,
-            SysFuncIdx::JZKT_COMPUTE_ROOT => 1,
-            SysFuncIdx::JZKT_ROLLBACK => 1,
-            SysFuncIdx::JZKT_COMMIT => 1,
-            _ => 1, //unreachable!("not configured fuel for opcode: {:?}", self),
-        }
-    }
-}
-
 impl From<u32> for SysFuncIdx {
     fn from(value: u32) -> Self {
         match value {
@@ -224,6 +208,8 @@ impl From<u32> for SysFuncIdx {
             0x0007 => Self::SYS_READ_OUTPUT,
             0x0009 => Self::SYS_EXEC_HASH,
             0x000a => Self::SYS_FORWARD_OUTPUT,
+            0x000c => Self::SYS_EXEC_HASH64,
+            0x000d => Self::SYS_FUEL_CONVERSION,
 
             // jzkt
             0x0701 => Self::JZKT_OPEN,
@@ -238,9 +224,14 @@ impl From<u32> for SysFuncIdx {
             0x070A => Self::JZKT_ROLLBACK,
             0x070D => Self::JZKT_PREIMAGE_SIZE,
             0x070E => Self::JZKT_PREIMAGE_COPY,
+            0x070F => Self::JZKT_TLOAD,
+            0x0710 => Self::JZKT_TSTORE,
 
             0x0801 => Self::WASM_TO_RWASM_SIZE,
             0x0802 => Self::WASM_TO_RWASM,
+            0x0803 => Self::EVM_TO_RWASM_SIZE,
+            0x0804 => Self::EVM_TO_RWASM,
+            0x0805 => Self::EVM_JUMP_TABLE,
 
             0x0901 => Self::DEBUG_LOG,
 
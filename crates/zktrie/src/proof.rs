@@ -1,5 +1,5 @@
-use crate::{Error, HashScheme, Node};
-use std::prelude::v1::*;
+use crate::{test_bit, to_secure_key, Error, Hash, HashScheme, Node, NodeValue, TrieData};
+use std::{prelude::v1::*, sync::Arc};
 
 lazy_static::lazy_static! {
     pub static ref MAGIC_HASH: &'static [u8] = b"THIS IS THE MAGIC INDEX FOR ZKTRIE";
@@ -12,3 +12,49 @@ pub fn decode_smt_proofs<H: HashScheme>(buf: &[u8]) -> Result<Option<Node<H>>, E
     }
     Ok(Some(<Node<H>>::from_bytes(buf)?))
 }
+
+// verify_proof checks merkle proof generated by `ZkTrie::proof` against the trie root. Proof
+// nodes are hashed again, so the proof can come from an untrusted source.
+//
+// If the proof ends with the leaf of the key, then the leaf is returned (inclusion proof),
+// if it ends with an empty node or a leaf of another key, then `TrieData::NotFound` is
+// returned (exclusion proof). Any other proof, including one with extra nodes, is invalid.
+pub fn verify_proof<H: HashScheme>(
+    root: &Hash,
+    key: &[u8],
+    proof: &[Vec<u8>],
+) -> Result<TrieData<H>, Error> {
+    let k = to_secure_key::<H>(key)?;
+    let node_key: Hash = k.into();
+    let mut expected_hash = *root;
+    for (level, buf) in proof.iter().enumerate() {
+        let node = decode_smt_proofs::<H>(buf)?.ok_or(Error::InvalidProof)?;
+        if node.hash() != &expected_hash {
+            return Err(Error::InvalidProof);
+        }
+        if let NodeValue::Branch(branch) = node.value() {
+            if level >= node_key.raw_bytes().len() * 8 {
+                return Err(Error::ReachedMaxLevel);
+            }
+            expected_hash = if test_bit(node_key.raw_bytes(), level) {
+                *branch.right.hash()
+            } else {
+                *branch.left.hash()
+            };
+            continue;
+        }
+        // leaf of another key or empty node proves that the key doesn't exist
+        let result = if node.match_leaf_key(&node_key) {
+            TrieData::Node(Arc::new(node))
+        } else {
+            TrieData::NotFound
+        };
+        // terminal node can only be followed by the magic bytes
+        return match &proof[level + 1..] {
+            [] => Ok(result),
+            [buf] if buf.as_slice() == *MAGIC_SMT_BYTES => Ok(result),
+            _ => Err(Error::InvalidProof),
+        };
+    }
+    Err(Error::InvalidProof)
+}
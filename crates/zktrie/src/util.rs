@@ -137,4 +137,5 @@ pub enum Error {
     InvalidNodeFound(u8),
     NotInField(String),
     ExpectedLeafNode,
+    InvalidProof,
 }
@@ -3,6 +3,7 @@ use crate::{
     copy_truncated,
     decode_smt_proofs,
     to_secure_key,
+    verify_proof,
     Byte32,
     Database,
     Error,
@@ -676,6 +677,62 @@ fn test_zktrie_statedb() {
     assert_eq!(zktrie.hash(), &root);
 }
 
+#[test]
+fn test_verify_proof() {
+    type H = PoseidonHash;
+    let mut db = <MemDB<H>>::new();
+    let mut zktrie = <ZkTrie<H>>::new(248, Hash::default());
+
+    // exclusion proof for the empty trie
+    let proof = zktrie.proof(&db, &[1u8; 20]).unwrap();
+    assert_eq!(
+        verify_proof::<H>(zktrie.hash(), &[1u8; 20], &proof),
+        Ok(TrieData::NotFound)
+    );
+
+    for i in 1..=16u8 {
+        zktrie
+            .update(&mut db, &[i; 20], 1, vec![byte32_from_byte(i)])
+            .unwrap();
+    }
+    let root = *zktrie.hash();
+
+    // inclusion proof
+    let proof = zktrie.proof(&db, &[7u8; 20]).unwrap();
+    match verify_proof::<H>(&root, &[7u8; 20], &proof).unwrap() {
+        TrieData::Node(node) => assert_eq!(node.data(), byte32_from_byte(7).bytes()),
+        TrieData::NotFound => panic!("key must exist"),
+    }
+    // proof of one key can't be used for another key
+    assert_eq!(
+        verify_proof::<H>(&root, &[8u8; 20], &proof),
+        Err(Error::InvalidProof)
+    );
+
+    // exclusion proof
+    let proof = zktrie.proof(&db, &[17u8; 20]).unwrap();
+    assert_eq!(
+        verify_proof::<H>(&root, &[17u8; 20], &proof),
+        Ok(TrieData::NotFound)
+    );
+
+    // tampered proofs
+    let mut proof = zktrie.proof(&db, &[7u8; 20]).unwrap();
+    // flip a byte of the leaf value
+    let leaf_index = proof.len() - 2;
+    proof[leaf_index][40] ^= 1;
+    assert!(verify_proof::<H>(&root, &[7u8; 20], &proof).is_err());
+    let proof = zktrie.proof(&db, &[7u8; 20]).unwrap();
+    assert_eq!(
+        verify_proof::<H>(&root, &[7u8; 20], &proof[1..]),
+        Err(Error::InvalidProof)
+    );
+    assert_eq!(
+        verify_proof::<H>(&root, &[7u8; 20], &proof[..proof.len() - 2]),
+        Err(Error::InvalidProof)
+    );
+}
+
 fn hex_root(d: &str) -> Result<Hash, String> {
     let mut data = hex::decode(d.as_bytes()).map_err(|d| format!("{:?}", d))?;
     data.reverse();
@@ -1,5 +1,7 @@
-use alloy_sol_types::{sol, SolCall, SolEvent, SolType, SolValue};
+use alloy_sol_types::{sol, SolValue};
 use fluentbase_sdk::{
+    Address,
+    Bytes,
     ContextReader,
     ExecutionContext,
     JzktAccountManager,
@@ -54,25 +56,33 @@ pub fn deploy() {
 
 struct ERC20<'a>(&'a mut ExecutionContext);
 
-#[derive_solidity_router]
+#[derive_solidity_router(
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+)]
 impl<'a> ERC20<'a> {
-    fn name(&self) -> Bytes {
+    #[view]
+    pub fn name(&self) -> Bytes {
         Bytes::from("Token")
     }
 
-    fn symbol(&self) -> Bytes {
+    #[view]
+    pub fn symbol(&self) -> Bytes {
         Bytes::from("TOK")
     }
 
-    fn decimals(&self) -> U256 {
+    #[view]
+    pub fn decimals(&self) -> U256 {
         U256::from(18)
     }
 
-    fn total_supply(&self) -> U256 {
+    #[view]
+    pub fn total_supply(&self) -> U256 {
         U256::from(0)
     }
 
-    fn balance_of(&self, address: Address) -> U256 {
+    #[view]
+    pub fn balance_of(&self, address: Address) -> U256 {
         let mut balance = U256::from(0);
         let storage_key = storage_mapping_key(&STORAGE_BALANCES, address.abi_encode().as_slice());
         unsafe {
@@ -81,7 +91,7 @@ impl<'a> ERC20<'a> {
         balance
     }
 
-    fn transfer(&mut self, to: Address, value: U256) -> U256 {
+    pub fn transfer(&mut self, to: Address, value: U256) -> U256 {
         // sender is a caller
         let from = self.0.contract_caller();
         // check from/to addresses
@@ -128,58 +138,16 @@ impl<'a> ERC20<'a> {
     }
 }
 
-macro_rules! forward_evm_call {
-    ($func_type:ty, $input:expr, $self:ident, $fn_name:ident, 0) => {{
-        let output = $self.$fn_name();
-        output.abi_encode()
-    }};
-    ($func_type:ty, $input:expr, $self:ident, $fn_name:ident, 1) => {{
-        let input =
-            <<$func_type as SolCall>::Parameters<'_> as SolType>::abi_decode(&$input[4..], false)
-                .unwrap();
-        let output = $self.$fn_name(input.0);
-        output.abi_encode()
-    }};
-    ($func_type:ty, $input:expr, $self:ident, $fn_name:ident, 2) => {{
-        let input =
-            <<$func_type as SolCall>::Parameters<'_> as SolType>::abi_decode(&$input[4..], false)
-                .unwrap();
-        let output = $self.$fn_name(input.0, input.1);
-        output.abi_encode()
-    }};
-}
-
 pub fn main() {
     let mut ctx = ExecutionContext::default();
-    let input = ctx.contract_input().clone();
-    let mut selector: [u8; 4] = [0; 4];
-    selector.copy_from_slice(&input[0..4]);
-    // max number of inputs is 3 for ERC20 contract
-    let mut erc20_handler = ERC20(&mut ctx);
-    let output = match selector {
-        nameCall::SELECTOR => forward_evm_call!(nameCall, input, erc20_handler, name, 0),
-        symbolCall::SELECTOR => forward_evm_call!(symbolCall, input, erc20_handler, symbol, 0),
-        decimalsCall::SELECTOR => {
-            forward_evm_call!(decimalsCall, input, erc20_handler, decimals, 0)
-        }
-        totalSupplyCall::SELECTOR => {
-            forward_evm_call!(totalSupplyCall, input, erc20_handler, total_supply, 0)
-        }
-        balanceOfCall::SELECTOR => {
-            forward_evm_call!(balanceOfCall, input, erc20_handler, balance_of, 1)
-        }
-        transferCall::SELECTOR => {
-            forward_evm_call!(transferCall, input, erc20_handler, transfer, 2)
-        }
-        _ => panic!("unknown method"),
-    };
-    LowLevelSDK::sys_write(&output);
+    ERC20(&mut ctx).main();
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use alloc::{string::ToString, vec};
+    use alloy_sol_types::SolCall;
     use fluentbase_codec::Encoder;
     use fluentbase_sdk::{Address, Bytes, ContractInput, LowLevelSDK, U256};
     use hex_literal::hex;
@@ -254,4 +222,19 @@ mod test {
         assert_eq!(get_balance(from).to_string(), "999900000000000000000000");
         assert_eq!(get_balance(to).to_string(), "100000000000000000000");
     }
+
+    #[test]
+    pub fn test_abi_json() {
+        let abi = ERC20::ABI_JSON;
+        assert!(abi.starts_with('['));
+        assert!(abi.contains(
+            r#"{"type":"function","name":"balanceOf","inputs":[{"name":"address","type":"address"}],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"view"}"#
+        ));
+        assert!(abi.contains(
+            r#"{"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"value","type":"uint256"}],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"nonpayable"}"#
+        ));
+        assert!(abi.contains(
+            r#"{"type":"event","name":"Transfer","inputs":[{"name":"from","type":"address","indexed":true},{"name":"to","type":"address","indexed":true},{"name":"value","type":"uint256","indexed":false}],"anonymous":false}"#
+        ));
+    }
 }
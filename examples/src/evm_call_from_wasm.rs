@@ -2,7 +2,7 @@ use alloc::vec;
 use fluentbase_codec::Encoder;
 use fluentbase_sdk::Account;
 use fluentbase_sdk::{ContextReader, ContractInput, ExecutionContext, LowLevelAPI, LowLevelSDK};
-use fluentbase_types::{ExitCode, STATE_MAIN};
+use fluentbase_types::{ExitCode, FuelConversion, STATE_MAIN};
 
 pub fn deploy() {}
 
@@ -10,10 +10,13 @@ pub fn main() {
     let ctx = ExecutionContext::default();
     let contract_input = ExecutionContext::DEFAULT.contract_input();
     let evm_contract_address = ExecutionContext::DEFAULT.contract_address();
-    let mut gas_limit: u32 = ExecutionContext::DEFAULT.contract_gas_limit() as u32;
+    let gas_limit = ExecutionContext::DEFAULT.contract_gas_limit();
+    let mut fuel_conversion = [0u8; 9];
+    LowLevelSDK::sys_fuel_conversion(fuel_conversion.as_mut_ptr());
+    let mut fuel_limit = FuelConversion::from_bytes(&fuel_conversion).gas_to_fuel(gas_limit);
     let contract_input = ContractInput {
         journal_checkpoint: ExecutionContext::DEFAULT.journal_checkpoint().into(),
-        contract_gas_limit: gas_limit as u64,
+        contract_gas_limit: gas_limit,
         contract_address: evm_contract_address,
         contract_caller: ExecutionContext::DEFAULT.contract_caller(),
         contract_input,
@@ -30,7 +33,7 @@ pub fn main() {
         contract_input_vec.len() as u32,
         core::ptr::null_mut(),
         0,
-        &mut gas_limit,
+        &mut fuel_limit,
         STATE_MAIN,
     );
     if exit_code != ExitCode::Ok.into_i32() {
@@ -40,8 +40,8 @@ macro_rules! export_and_forward {
         pub extern "C" fn $fn_name() {
             #[cfg(feature = "cairo")]
             cairo::$fn_name();
-            // #[cfg(feature = "erc20")]
-            // erc20::$fn_name();
+            #[cfg(feature = "erc20")]
+            erc20::$fn_name();
             #[cfg(feature = "greeting")]
             greeting::$fn_name();
             #[cfg(feature = "contract_input_check_recode")]